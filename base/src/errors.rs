@@ -1,4 +1,5 @@
 use std::any::Any;
+use std::borrow::Cow;
 use std::fmt::{Debug, Display};
 use std::time::Duration;
 use thiserror::Error;
@@ -13,6 +14,36 @@ impl<T: Debug + Display + Send + Sync + Any> TaskError for T {
     }
 }
 
+/// The canonical failure kinds produced by ChronoGrapher itself (as opposed to the errors of the
+/// user-defined TaskFrames), so error handlers can tell them apart.
+///
+/// TaskFrames which have to produce an error of the generic error type of the Task (such as the
+/// default error of [`TimeoutTaskFrame`](crate::task::TimeoutTaskFrame) or of [`DependencyUnresolveFail`](crate::task::DependencyUnresolveFail))
+/// produce one of these variants when the error type is ``ChronographerErrors`` itself, a boxed
+/// [`TaskError`] or (with their features enabled) an ``anyhow::Error`` / ``eyre::Report``, which
+/// can then be downcast back to [`ChronographerErrors`].
+///
+/// TaskFrames with an error type of their own (such as [`BlockingTaskFrame`](crate::task::BlockingTaskFrame))
+/// carry these variants in it instead of duplicating them, while [`TimeoutTaskFrameError`] converts into them.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChronographerErrors {
+    #[error("TaskFrame has exceeded its maximum duration")]
+    TimedOut,
+
+    #[error("TaskFrame has been cancelled before completing")]
+    Cancelled,
+
+    #[error("TaskFrame dependencies have not been resolved")]
+    DependenciesUnresolved,
+
+    #[error("TaskFrame has been disabled by a kill switch")]
+    Disabled,
+
+    #[error("TaskFrame has not been started as the deadline of its chain has passed")]
+    DeadlineExceeded,
+}
+
 #[derive(Error, Debug)]
 pub enum ConditionalTaskFrameError<T1: TaskError, T2: TaskError> {
     #[error(
@@ -40,6 +71,26 @@ pub enum TimeoutTaskFrameError<T: TaskError> {
     Timeout(Duration),
 }
 
+impl From<TimeoutTaskFrameError<ChronographerErrors>> for ChronographerErrors {
+    fn from(error: TimeoutTaskFrameError<ChronographerErrors>) -> Self {
+        match error {
+            TimeoutTaskFrameError::Inner(inner) => inner,
+            TimeoutTaskFrameError::Timeout(_) => ChronographerErrors::TimedOut,
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum DeadlineTaskFrameError<T: TaskError> {
+    #[error(
+        "DeadlineTaskFrame has failed, with the error originating from inner TaskFrame's failure:\n\t{0}"
+    )]
+    Inner(T),
+
+    #[error("DeadlineTaskFrame has failed, {0}")]
+    Chronographer(#[from] ChronographerErrors),
+}
+
 #[derive(Error, Debug)]
 pub enum ThresholdTaskFrameError<T: TaskError> {
     #[error(
@@ -66,6 +117,46 @@ pub enum DependencyTaskFrameError<T: TaskError> {
     DependenciesInvalidated(Box<dyn TaskError>),
 }
 
+#[derive(Error, Debug)]
+pub enum BlockingTaskFrameError<T: TaskError> {
+    #[error(
+        "BlockingTaskFrame has failed, with the error originating from the blocking closure's failure:\n\t{0}"
+    )]
+    Inner(T),
+
+    #[error("BlockingTaskFrame has failed, the blocking closure panicked with '{0}'")]
+    Panicked(String),
+
+    #[error("BlockingTaskFrame has failed, {0}")]
+    Chronographer(#[from] ChronographerErrors),
+}
+
+#[derive(Error, Debug)]
+pub enum PreconditionTaskFrameError<T: TaskError> {
+    #[error(
+        "PreconditionTaskFrame has failed, with the error originating from inner TaskFrame's failure:\n\t{0}"
+    )]
+    Inner(T),
+
+    #[error("PreconditionTaskFrame has failed, the preconditions {0:?} were not met")]
+    PreconditionFailed(Vec<Cow<'static, str>>),
+}
+
+#[derive(Error, Debug)]
+pub enum GateOnHealthTaskFrameError<T: TaskError> {
+    #[error(
+        "GateOnHealthTaskFrame has failed, with the error originating from inner TaskFrame's failure:\n\t{0}"
+    )]
+    Inner(T),
+
+    #[error("GateOnHealthTaskFrame has failed, the health metric `{metric}` is at {health} (below the threshold of {threshold})")]
+    HealthGateOpen {
+        metric: Cow<'static, str>,
+        health: f64,
+        threshold: f64,
+    },
+}
+
 #[derive(Error, Debug, PartialEq, Eq)]
 #[error(
     "Task frame index `{index}` is out of bounds for `{src}` with task frame size `{size}` element(s)"
@@ -76,6 +167,32 @@ pub struct TaskSelectionIndexOutOfBounds {
     pub size: usize,
 }
 
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error(
+    "Quorum of `{required}` successful task frame(s) is unreachable, `{failed}` out of `{size}` task frame(s) have failed"
+)]
+pub struct QuorumUnreachable {
+    pub required: usize,
+    pub failed: usize,
+    pub size: usize,
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Weighted selection requires at least one task frame with a positive weight")]
+pub struct SelectionWeightsAllZero;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Task barrier is broken, the task(s) with instance id(s) {0:?} expired before completing")]
+pub struct TaskBarrierBroken(pub Vec<usize>);
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Occurrence range is inverted (expected from <= to)")]
+pub struct OccurrenceRangeInverted;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Scheduler hosting the task has been dropped")]
+pub struct SchedulerDropped;
+
 #[derive(Error, Debug, PartialEq, Eq)]
 #[error("Dependencies have not been resolved (errored due to the use of 'DependentFailBehavior')")]
 pub struct TaskDependenciesUnresolved;
@@ -88,3 +205,43 @@ pub struct IntervalTimeDeltaOutOfRange;
 #[derive(Error, Debug, PartialEq, Eq)]
 #[error("Floating-based seconds supplied is out of range")]
 pub struct IntervalSecondsOutOfRange;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Daily window supplied is out of range (expected start < end <= 24 hours)")]
+pub struct RandomWindowOutOfRange;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Smoothing factor supplied is out of range (expected 0 < alpha <= 1)")]
+pub struct EmaAlphaOutOfRange;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Coordinates supplied are out of range (expected -90 <= latitude <= 90 and -180 <= longitude <= 180)")]
+pub struct SolarCoordinatesOutOfRange;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("No {event} occurs at the supplied coordinates within a year of the current time")]
+pub struct SolarEventUnreachable {
+    pub event: &'static str,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Error, Debug)]
+pub enum TaskSpecError {
+    #[error("TaskSpec '{0}' has an empty TaskFrame chain")]
+    EmptyFrameChain(String),
+
+    #[error("No TaskFrame factory has been registered under the name '{0}'")]
+    UnknownFactory(String),
+
+    #[error("TaskFrame factory '{name}' has failed to build its TaskFrame:\n\t{error}")]
+    FactoryFailed {
+        name: String,
+        error: Box<dyn std::error::Error + Send + Sync>,
+    },
+
+    #[error("TaskSpec has an invalid schedule:\n\t{0}")]
+    InvalidSchedule(Box<dyn std::error::Error + Send + Sync>),
+
+    #[error("No TaskSchedule factory has been registered under the name '{0}'")]
+    UnknownScheduleFactory(String),
+}
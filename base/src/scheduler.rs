@@ -3,8 +3,14 @@ pub mod engine; // skipcq: RS-D1001
 pub mod task_dispatcher; // skipcq: RS-D1001
 pub mod task_store; // skipcq: RS-D1001
 pub mod impls; // skipcq: RS-D1001
+pub mod metrics; // skipcq: RS-D1001
+pub mod diagnostics; // skipcq: RS-D1001
+pub mod handle; // skipcq: RS-D1001
 
 pub use impls::*;
+pub use metrics::SchedulerMetrics;
+pub use diagnostics::{SchedulerDiagnostics, TaskDiagnostics};
+pub use handle::TaskHandle;
 
 use crate::errors::TaskError;
 use crate::scheduler::clock::*;
@@ -16,10 +22,28 @@ use std::any::Any;
 use std::error::Error;
 use std::marker::PhantomData;
 use std::sync::Arc;
-use crate::task::{Task, TaskFrame};
+use std::time::{Duration, SystemTime};
+use crate::task::dynamicframe::DynamicTaskFrame;
+use crate::task::{
+    ErasedTask, Task, TaskFrame, TaskFrameContext, TaskScheduleAt, TaskScheduleCron,
+    TaskScheduleInterval,
+};
+use std::str::FromStr;
 
 pub type SchedulerKey<C> = <<C as SchedulerConfig>::SchedulerTaskStore as SchedulerTaskStore<C>>::Key;
 
+/// The handle of a Task scheduled via [`Scheduler::schedule_with_completion`] alongside the receiver
+/// resolved with the outcome of its next completed execution.
+pub type ScheduleWithCompletion<H, E> =
+    Result<(H, tokio::sync::oneshot::Receiver<Result<(), E>>), Box<dyn Error + Send + Sync>>;
+
+/// Every Task hosted on a scheduler (or stored in a [`SchedulerTaskStore`]) alongside its handle, as returned by
+/// [`Scheduler::list`] and [`SchedulerTaskStore::list`].
+pub type TaskListing<H, E> = Vec<(H, Arc<ErasedTask<E>>)>;
+
+/// The handle of the Task executed by a single [`Scheduler::tick`] alongside the result of the execution.
+pub type TickOutcome<H, E> = Option<(H, Result<(), E>)>;
+
 pub(crate) type SchedulerHandlePayload = (Arc<dyn Any + Send + Sync>, SchedulerHandleInstructions);
 
 pub trait SchedulerConfig: Sized + 'static {
@@ -61,12 +85,28 @@ pub enum FailoverPolicy {
     #[default]
     Terminate,
     Deallocate,
-    ShutdownScheduler
+    ShutdownScheduler,
+
+    // Computes the next fire time via the schedule of the Task, just as after a successful execution
+    Reschedule
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SchedulerShutdownSummary {
+    pub drained: usize,
+    pub aborted: usize,
+    pub timed_out: bool,
 }
 
 pub trait Scheduler<C: SchedulerConfig>: Sync + Send + 'static {
-    type Handle: Into<SchedulerKey<C>>;
+    type Handle: Into<SchedulerKey<C>> + Clone;
 
+    /// Starts the scheduler, resolving only once it is initialized (its store, dispatcher and engine are
+    /// initialized and its loops are spawned). From then on [`Scheduler::has_started`] holds and scheduled
+    /// Tasks are picked up, Tasks scheduled beforehand are queued and picked up once it starts.
+    ///
+    /// Starting an already started scheduler does nothing, concurrent calls start it once and every one of
+    /// them resolves only after it has started.
     fn start(&self) -> impl Future<Output = ()> + Send;
     fn has_started(&self) -> impl Future<Output = bool> + Send;
     fn abort(&self) -> impl Future<Output = ()> + Send;
@@ -78,7 +118,207 @@ pub trait Scheduler<C: SchedulerConfig>: Sync + Send + 'static {
         task: Task<T>,
     ) -> impl Future<Output = Result<Self::Handle, Box<dyn Error + Send + Sync>>>;
 
+    /// Schedules the Task just as [`Scheduler::schedule`] does, but wraps its handle in a [`TaskHandle`]
+    /// which operates on the Task directly (holding the scheduler weakly).
+    fn schedule_with_handle<T: TaskFrame<Args = (), Error = C::TaskError>>(
+        self: &Arc<Self>,
+        task: Task<T>,
+    ) -> impl Future<Output = Result<TaskHandle<C, Self>, Box<dyn Error + Send + Sync>>>
+    where
+        Self: Sized,
+    {
+        async move {
+            let key = self.schedule(task).await?;
+            Ok(TaskHandle::new(key, self))
+        }
+    }
+
+    /// Schedules the Task just as [`Scheduler::schedule`] does, alongside its handle a receiver is returned
+    /// which resolves with the outcome of its **next** completed execution only (see [`Task::next_completion`]).
+    fn schedule_with_completion<T: TaskFrame<Args = (), Error = C::TaskError>>(
+        &self,
+        task: Task<T>,
+    ) -> impl Future<Output = ScheduleWithCompletion<Self::Handle, C::TaskError>>
+    where
+        C::TaskError: Clone,
+    {
+        async move {
+            let completion = task.next_completion().await;
+            let key = self.schedule(task).await?;
+            Ok((key, completion))
+        }
+    }
+
+    /// Schedules ``func`` to execute every ``interval`` (see [`TaskScheduleInterval::duration`]), a shorthand
+    /// for wrapping it in a [`DynamicTaskFrame`] and a [`Task`] before [scheduling](Scheduler::schedule) it.
+    fn every<F, Fut>(
+        &self,
+        interval: Duration,
+        func: F,
+    ) -> impl Future<Output = Result<Self::Handle, Box<dyn Error + Send + Sync>>>
+    where
+        Self: Sized,
+        F: Fn(&TaskFrameContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), C::TaskError>> + Send + 'static,
+    {
+        let frame = DynamicTaskFrame::new(move |ctx: &TaskFrameContext, _args: &()| func(ctx));
+        self.schedule(Task::new(frame, TaskScheduleInterval::duration(interval)))
+    }
+
+    /// Schedules ``func`` to execute once at ``time`` (right away if it already passed), after which the Task
+    /// removes itself from the scheduler. A shorthand the same way [`Scheduler::every`] is, over [`TaskScheduleAt`].
+    fn at<F, Fut>(
+        &self,
+        time: SystemTime,
+        func: F,
+    ) -> impl Future<Output = Result<Self::Handle, Box<dyn Error + Send + Sync>>>
+    where
+        Self: Sized,
+        F: Fn(&TaskFrameContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), C::TaskError>> + Send + 'static,
+    {
+        let frame = DynamicTaskFrame::new(move |ctx: &TaskFrameContext, _args: &()| {
+            ctx.cancel_self();
+            func(ctx)
+        });
+
+        self.schedule(Task::new(frame, TaskScheduleAt::new(time)))
+    }
+
+    /// Schedules ``func`` to execute on every match of the CRON ``expression`` (see [`TaskScheduleCron`]).
+    /// A shorthand the same way [`Scheduler::every`] is, failing when the expression doesn't parse.
+    fn cron<F, Fut>(
+        &self,
+        expression: &str,
+        func: F,
+    ) -> impl Future<Output = Result<Self::Handle, Box<dyn Error + Send + Sync>>>
+    where
+        Self: Sized,
+        F: Fn(&TaskFrameContext) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), C::TaskError>> + Send + 'static,
+    {
+        let schedule = TaskScheduleCron::from_str(expression);
+        async move {
+            let frame = DynamicTaskFrame::new(move |ctx: &TaskFrameContext, _args: &()| func(ctx));
+            self.schedule(Task::new(frame, schedule?)).await
+        }
+    }
+
     fn remove(&self, key: &Self::Handle) -> impl Future<Output = ()> + Send;
 
+    /// Lists every Task currently hosted on the scheduler alongside its handle, for introspection
+    /// (e.g. surfacing the [description](Task::description) and [owner](Task::owner) of each Task).
+    fn list(&self) -> impl Future<Output = TaskListing<Self::Handle, C::TaskError>> + Send;
+
+    /// Recomputes the next fire time of the Task from the current clock time and moves it to
+    /// that position, superseding the previously scheduled one.
+    ///
+    /// This does not run the Task, it only reschedules it. Unknown keys are ignored.
+    fn refresh(&self, key: &Self::Handle) -> impl Future<Output = ()> + Send;
+
+    /// Makes the next fire of the Task happen at ``time`` instead of the time its [`TaskSchedule`](crate::task::TaskSchedule)
+    /// computes, once. The fire after it is computed by the schedule again (from ``time``), so unlike replacing
+    /// the schedule this is a one-time nudge (e.g. "run the next occurrence 10 minutes earlier").
+    ///
+    /// A Task which is currently idling toward its original fire time is moved to ``time`` right away,
+    /// superseding the original fire (it is **not** fired as well), while a Task currently executing picks
+    /// up the override once it finishes. Overriding again before the override fires replaces it. Unknown
+    /// keys are ignored.
+    fn override_next_fire(&self, key: &Self::Handle, time: SystemTime) -> impl Future<Output = ()> + Send;
+
+    /// Fires the Task right away, as an [override](Scheduler::override_next_fire) to the current clock time.
+    /// The fire after it is computed by the schedule from then on. Unknown keys are ignored.
+    fn run_now(&self, key: &Self::Handle) -> impl Future<Output = ()> + Send;
+
+    /// The next time the Task is scheduled to fire at, ``None`` for unknown keys or when the Task
+    /// hasn't been handed a fire time yet.
+    fn next_fire(&self, key: &Self::Handle) -> impl Future<Output = Option<SystemTime>> + Send;
+
+    /// Pauses the Task, its fires are held back (neither dispatched nor rescheduled) until [`Scheduler::resume`]
+    /// is called, while an execution already in flight is left to complete. Pausing a paused Task is a no-op.
+    fn pause(&self, key: &Self::Handle) -> impl Future<Output = ()> + Send;
+
+    /// Resumes a [paused](Scheduler::pause) Task, rescheduling it from the current clock time (the fires missed in
+    /// the meantime are handled by its [`CatchUpPolicy`](crate::task::CatchUpPolicy)). Resuming a Task which isn't
+    /// paused is a no-op.
+    fn resume(&self, key: &Self::Handle) -> impl Future<Output = ()> + Send;
+
+    /// Whether the Task is currently paused, see [`Scheduler::pause`].
+    fn is_paused(&self, key: &Self::Handle) -> impl Future<Output = bool> + Send;
+
     fn clear(&self) -> impl Future<Output = ()> + Send;
+
+    /// Puts the scheduler in drain mode, a gentler alternative to [`Scheduler::abort`] (for example
+    /// during rolling deploys). Fires which come due after this call are held back, while executions
+    /// already handed to the workers are left to complete, after which the scheduler sits idle.
+    ///
+    /// Rescheduling is paused as well, Tasks which finish executing (or get refreshed / newly scheduled)
+    /// don't compute their next fire time until [`Scheduler::leave_drain`] is called. Entering drain mode
+    /// while already draining is a no-op.
+    fn enter_drain(&self) -> impl Future<Output = ()> + Send;
+
+    /// Whether the scheduler is currently in drain mode, see [`Scheduler::enter_drain`].
+    fn is_draining(&self) -> impl Future<Output = bool> + Send;
+
+    /// Leaves drain mode, every Task held back while draining is rescheduled from the current clock
+    /// time (the fires missed in the meantime are handled by its [`CatchUpPolicy`](crate::task::CatchUpPolicy)).
+    fn leave_drain(&self) -> impl Future<Output = ()> + Send;
+
+    /// Opens the readiness gate of the scheduler, signalling that the global resources Tasks depend on
+    /// (a database pool, a warmed cache... etc.) are initialized. Tasks marked via
+    /// [`Task::with_readiness_gate`] are held back (neither scheduled nor dispatched) until then, after
+    /// which they are scheduled from the current clock time.
+    ///
+    /// Tasks which aren't marked are unaffected by the gate and run normally before readiness. The gate
+    /// starts closed and once opened it stays open, opening it again is a no-op.
+    fn set_ready(&self) -> impl Future<Output = ()> + Send;
+
+    /// Whether the readiness gate has been opened, see [`Scheduler::set_ready`].
+    fn is_ready(&self) -> impl Future<Output = bool> + Send;
+
+    /// Snapshots the scheduling latency of the scheduler (how late Tasks were dispatched compared to
+    /// their scheduled fire time), see [`SchedulerMetrics`].
+    fn metrics(&self) -> impl Future<Output = SchedulerMetrics> + Send;
+
+    /// Dumps the state of the scheduler and every Task it hosts, see [`SchedulerDiagnostics`].
+    fn diagnostics(&self) -> impl Future<Output = SchedulerDiagnostics> + Send;
+
+    /// Waits until the scheduler is idle, meaning no Task is due and nothing is running. Precisely, a Task
+    /// is **due** when its fire time (or a pending [override](Scheduler::override_next_fire)) has been reached
+    /// by the clock, or it hasn't been handed a fire time yet, while **running** covers in-flight executions
+    /// as well as work queued up for the workers. Tasks held back by [drain mode](Scheduler::enter_drain) or
+    /// the [readiness gate](Scheduler::set_ready) don't count as due.
+    ///
+    /// Idleness has to be observed twice in a row (a short interval apart) before resolving, so the brief
+    /// gaps between an execution finishing and its Task being rescheduled aren't mistaken for idleness. Tasks
+    /// which are always due (e.g. an immediate schedule) keep the scheduler from ever being idle, the same
+    /// goes for a Task whose fire time couldn't be recomputed and is left in the past (see [`FailoverPolicy`]).
+    fn wait_idle(&self) -> impl Future<Output = ()> + Send;
+
+    /// Waits until the scheduler is empty, meaning no Task is stored and no execution is in flight, resolving
+    /// immediately if it already is. This is meant for batch workflows, where every Task eventually removes
+    /// itself (e.g. via [`TaskFrameContext::cancel_self`](crate::task::TaskFrameContext::cancel_self), an expiry
+    /// or the [`FailoverPolicy::Deallocate`] policy) and the caller waits for all of them to drain.
+    ///
+    /// An execution which is still running after its Task was removed keeps the scheduler from being empty
+    /// until it finishes, while the work queued up for removed Tasks doesn't count (it is discarded). Unlike
+    /// [`Scheduler::wait_idle`], Tasks which merely have nothing due keep the scheduler from being empty.
+    fn on_empty(&self) -> impl Future<Output = ()> + Send;
+
+    /// Drives the scheduler by a single step instead of [starting](Scheduler::start) it, for step-by-step tests
+    /// (typically paired with a [`VirtualClock`](crate::scheduler::clock::VirtualClock)). The earliest due Task
+    /// is picked, the clock idles until its fire time, then the Task is executed and rescheduled. Its handle is
+    /// returned alongside the result of the execution, or ``None`` when no Task has a pending fire time.
+    ///
+    /// Ticking and starting are **mutually exclusive** modes, a scheduler is either started or ticked throughout
+    /// its lifetime. Ticking a started scheduler does nothing and returns ``None``, while starting a ticked one
+    /// leaves the fire times handed out by ticking behind.
+    fn tick(&self) -> impl Future<Output = TickOutcome<Self::Handle, C::TaskError>> + Send;
+
+    /// Stops the scheduler from issuing new executions, waits up to ``timeout`` for the in-flight
+    /// executions to finish, then aborts whatever remains and clears every stored Task.
+    ///
+    /// Calling it on a scheduler which hasn't started is a no-op (besides clearing the store)
+    /// and returns an empty [`SchedulerShutdownSummary`].
+    fn stop_and_clear(&self, timeout: Duration) -> impl Future<Output = SchedulerShutdownSummary> + Send;
 }
\ No newline at end of file
@@ -2,7 +2,10 @@ pub mod progressive_clock; // skipcq: RS-D1001
 
 pub mod virtual_clock; // skipcq: RS-D1001
 
-pub use progressive_clock::ProgressiveClock;
+pub mod replay_clock; // skipcq: RS-D1001
+
+pub use progressive_clock::{DEFAULT_CLOCK_RESOLUTION, ProgressiveClock};
+pub use replay_clock::ReplayClock;
 pub use virtual_clock::VirtualClock;
 
 use std::time::{Duration, SystemTime};
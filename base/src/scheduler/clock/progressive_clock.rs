@@ -1,27 +1,106 @@
 use crate::scheduler::clock::SchedulerClock;
 use std::sync::Arc;
-use std::time::{Duration, SystemTime};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::sync::Notify;
 
-pub struct ProgressiveClock(Arc<Notify>);
+/// The resolution of [`ProgressiveClock::default`], matching the granularity of the scheduling engine.
+pub const DEFAULT_CLOCK_RESOLUTION: Duration = Duration::from_millis(1);
+
+/// [`ProgressiveClock`] is the default [`SchedulerClock`], following the real (system) time.
+///
+/// # Resolution
+/// The clock wakes up once per **resolution** (1ms by default) to advance the engine, and rounds the times
+/// it idles to up onto a grid of the same resolution, so wakeups falling within the same window coalesce
+/// into one. A coarser resolution (via [`ProgressiveClock::with_resolution`]) trades precision for CPU time,
+/// which matters with many high-frequency Tasks (e.g. sub-millisecond intervals):
+/// - Fires may happen up to one resolution late, never early.
+/// - Fires within the same window of the resolution are handed out together.
+///
+/// The engine counts time in milliseconds regardless, a coarser resolution only batches its progress
+/// (every wakeup advances it by all the milliseconds which elapsed), it doesn't make it drift.
+///
+/// # Example(s)
+/// ```
+/// use chronographer::scheduler::clock::ProgressiveClock;
+/// use chronographer::scheduler::engine::DefaultSchedulerEngine;
+/// use chronographer::scheduler::DefaultLiveScheduler;
+/// use std::time::Duration;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let scheduler = DefaultLiveScheduler::<String>::builder()
+///     .engine(DefaultSchedulerEngine::new(ProgressiveClock::with_resolution(Duration::from_millis(5))))
+///     .store(Default::default())
+///     .dispatcher(Default::default())
+///     .build();
+/// # }
+/// ```
+///
+/// # See Also
+/// - [`VirtualClock`](crate::scheduler::clock::VirtualClock) - For manually advancing simulated time.
+/// - [`SchedulerClock`] - The direct implementor of this trait.
+pub struct ProgressiveClock {
+    notify: Arc<Notify>,
+    elapsed: Arc<AtomicU64>,
+    ticked: AtomicU64,
+    resolution: Duration,
+}
 
 impl Default for ProgressiveClock {
     fn default() -> Self {
+        Self::with_resolution(DEFAULT_CLOCK_RESOLUTION)
+    }
+}
+
+impl ProgressiveClock {
+    /// Constructs a [`ProgressiveClock`] waking up once per ``resolution``, see the resolution section of
+    /// [`ProgressiveClock`] for the precision impact.
+    ///
+    /// # Panics
+    /// Panics if ``resolution`` is shorter than a millisecond (the granularity of the engine).
+    pub fn with_resolution(resolution: Duration) -> Self {
+        assert!(
+            resolution >= Duration::from_millis(1),
+            "the resolution of ProgressiveClock must be at least a millisecond"
+        );
+
         let notify = Arc::new(Notify::new());
-        let notify_clone = notify.clone();
+        let elapsed = Arc::new(AtomicU64::new(0));
+        let (notify_clone, elapsed_clone) = (notify.clone(), elapsed.clone());
 
         tokio::spawn(async move {
-            let mut interval = tokio::time::interval(Duration::from_millis(1));
+            let start = tokio::time::Instant::now();
+            let mut interval = tokio::time::interval(resolution);
 
             interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
 
             loop {
                 interval.tick().await;
+                elapsed_clone.store(start.elapsed().as_millis() as u64, Ordering::Release);
                 notify_clone.notify_waiters();
             }
         });
 
-        Self(notify)
+        Self {
+            notify,
+            elapsed,
+            ticked: AtomicU64::new(0),
+            resolution,
+        }
+    }
+
+    pub fn resolution(&self) -> Duration {
+        self.resolution
+    }
+
+    // Rounds ``to`` up onto the grid of the resolution, so nearby wakeups coalesce
+    fn coalesce(&self, to: SystemTime) -> SystemTime {
+        let resolution = self.resolution.as_nanos();
+        let since_epoch = to.duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos();
+        let rounded = since_epoch.div_ceil(resolution) * resolution;
+
+        UNIX_EPOCH + Duration::from_nanos(rounded as u64)
     }
 }
 
@@ -32,12 +111,31 @@ impl SchedulerClock for ProgressiveClock {
 
     fn idle_to(&self, to: SystemTime) -> impl Future<Output = ()> + Send {
         let now = SystemTime::now();
-        let duration = to.duration_since(now).unwrap_or(Duration::ZERO);
+        let duration = match to > now {
+            true => self.coalesce(to).duration_since(now).unwrap_or(Duration::ZERO),
+            false => Duration::ZERO,
+        };
 
         tokio::time::sleep(duration)
     }
 
-    fn tick(&self) -> impl Future<Output = ()> + Send {
-        self.0.notified()
+    /*
+        Every tick advances the engine by a millisecond, a wakeup hands out as many ticks as milliseconds
+        elapsed since the previous one (the engine is the only one ticking, so the counter isn't contended)
+     */
+    async fn tick(&self) {
+        loop {
+            let notified = self.notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            let ticked = self.ticked.load(Ordering::Acquire);
+            if ticked < self.elapsed.load(Ordering::Acquire) {
+                self.ticked.store(ticked + 1, Ordering::Release);
+                return;
+            }
+
+            notified.await;
+        }
     }
 }
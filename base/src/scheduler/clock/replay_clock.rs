@@ -0,0 +1,127 @@
+use crate::scheduler::clock::SchedulerClock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+struct ReplayState {
+    sequence: Box<dyn Iterator<Item = SystemTime> + Send>,
+    current: SystemTime,
+    exhausted: bool,
+}
+
+impl ReplayState {
+    fn advance(&mut self) -> bool {
+        if self.exhausted {
+            return false;
+        }
+
+        match self.sequence.next() {
+            Some(time) => {
+                /*
+                    Time never flows backwards for the scheduler, out of order entries
+                    are clamped to the latest time seen
+                */
+                self.current = self.current.max(time);
+                true
+            }
+
+            None => {
+                self.exhausted = true;
+                false
+            }
+        }
+    }
+}
+
+/// [`ReplayClock`] is a [`SchedulerClock`] driven by an external sequence of timestamps
+/// (such as a recorded production timeline) instead of real or virtual time. Every call to
+/// [`SchedulerClock::now`] hands out the current timestamp and moves onto the next one in the
+/// sequence, which makes replaying hours of scheduling a matter of milliseconds while staying
+/// fully deterministic.
+///
+/// # Semantics
+/// - [`SchedulerClock::now`] returns the current timestamp, then advances by one entry.
+/// - [`SchedulerClock::idle_to`] consumes entries until the current timestamp reaches the target.
+/// - [`SchedulerClock::tick`] consumes exactly one entry.
+///
+/// Entries earlier than the current timestamp are clamped to it, as time can't flow backwards.
+///
+/// # Exhaustion
+/// Once the sequence runs out, [`SchedulerClock::now`] keeps returning the last timestamp
+/// and [`SchedulerClock::idle_to`] resolves **immediately** by jumping the clock to the target,
+/// so pending Tasks are still dispatched instead of waiting forever. [`SchedulerClock::tick`]
+/// on the other hand never resolves again, preventing the engine from busy-looping on a clock
+/// that no longer moves.
+///
+/// # Example(s)
+/// ```rust
+/// use chronographer::scheduler::clock::{ReplayClock, SchedulerClock};
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let clock = ReplayClock::new(
+///     (0..3).map(|secs| UNIX_EPOCH + Duration::from_secs(secs))
+/// );
+///
+/// assert_eq!(clock.now(), UNIX_EPOCH);
+/// assert_eq!(clock.now(), UNIX_EPOCH + Duration::from_secs(1));
+/// ```
+///
+/// # See Also
+/// - [`VirtualClock`](crate::scheduler::clock::VirtualClock) - For manually advancing simulated time.
+/// - [`SchedulerClock`] - The direct implementor of this trait.
+pub struct ReplayClock(parking_lot::Mutex<ReplayState>);
+
+impl ReplayClock {
+    pub fn new<I>(sequence: I) -> Self
+    where
+        I: IntoIterator<Item = SystemTime>,
+        I::IntoIter: Send + 'static,
+    {
+        let mut state = ReplayState {
+            sequence: Box::new(sequence.into_iter()),
+            current: UNIX_EPOCH,
+            exhausted: false,
+        };
+
+        if let Some(first) = state.sequence.next() {
+            state.current = first;
+        } else {
+            state.exhausted = true;
+        }
+
+        Self(parking_lot::Mutex::new(state))
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.0.lock().exhausted
+    }
+}
+
+impl Default for ReplayClock {
+    fn default() -> Self {
+        Self::new(std::iter::empty())
+    }
+}
+
+impl SchedulerClock for ReplayClock {
+    fn now(&self) -> SystemTime {
+        let mut state = self.0.lock();
+        let now = state.current;
+        state.advance();
+        now
+    }
+
+    async fn idle_to(&self, to: SystemTime) {
+        let mut state = self.0.lock();
+        while state.current < to {
+            if !state.advance() {
+                state.current = to;
+            }
+        }
+    }
+
+    async fn tick(&self) {
+        let advanced = self.0.lock().advance();
+        if !advanced {
+            std::future::pending::<()>().await;
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::scheduler::SchedulerMetrics;
+use crate::task::{RunRecord, TaskPriority};
+use std::time::SystemTime;
+
+/// The state of a single Task within [`SchedulerDiagnostics`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaskDiagnostics {
+    pub id: usize,
+    pub description: String,
+    pub owner: String,
+    pub priority: TaskPriority,
+    pub next_fire: Option<SystemTime>,
+    pub running: usize,
+
+    /// Only recorded for Tasks keeping a history (see [`Task::with_history`](crate::task::Task::with_history)).
+    pub last_run: Option<RunRecord>,
+}
+
+/// [`SchedulerDiagnostics`] is a point-in-time dump of everything a [Scheduler](crate::scheduler::Scheduler)
+/// knows about its state, meant to be logged (or serialized with the ``serde`` feature) when something
+/// goes wrong.
+///
+/// Gathering it copies the state of every stored Task but never waits on the Tasks themselves, so it is
+/// cheap enough to be called periodically. As the pieces are read one after the other, they may be
+/// slightly inconsistent with one another on a busy scheduler.
+///
+/// # See Also
+/// - [`Scheduler::diagnostics`](crate::scheduler::Scheduler::diagnostics) - Where this dump is obtained from.
+/// - [`SchedulerMetrics`] - The scheduling latency part of the dump.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchedulerDiagnostics {
+    pub tasks: Vec<TaskDiagnostics>,
+    pub in_flight: usize,
+
+    /// The amount of work queued up for the workers but not picked up yet.
+    pub backlog: usize,
+    pub draining: bool,
+    pub ready: bool,
+    pub metrics: SchedulerMetrics,
+}
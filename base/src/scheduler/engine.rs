@@ -3,6 +3,7 @@ pub mod default;
 pub use default::DefaultSchedulerEngine;
 
 use crate::scheduler::{SchedulerConfig, SchedulerKey};
+use crate::task::TaskPriority;
 use std::error::Error;
 use std::time::SystemTime;
 
@@ -19,7 +20,12 @@ pub trait SchedulerEngine<C: SchedulerConfig>: 'static + Send + Sync {
         &self,
         id: &SchedulerKey<C>,
         time: SystemTime,
+        priority: TaskPriority,
     ) -> impl Future<Output = Result<(), Box<dyn Error + Send + Sync>>> + Send;
     
+    fn remove(&self, _id: &SchedulerKey<C>) -> impl Future<Output = ()> + Send {
+        std::future::ready(())
+    }
+
     fn clear(&self) -> impl Future<Output = ()> + Send;
 }
@@ -1,7 +1,10 @@
 use crate::scheduler::{SchedulerConfig, SchedulerKey};
 use crate::scheduler::clock::SchedulerClock;
 use crate::scheduler::engine::SchedulerEngine;
+use crate::task::TaskPriority;
 use crate::utils::hierarchical_timing_wheel::HierarchicalTimingWheel;
+use std::cmp::Reverse;
+use std::collections::HashMap;
 use crossbeam::queue::SegQueue;
 use std::error::Error;
 use std::sync::Arc;
@@ -9,15 +12,20 @@ use std::time::{Duration, SystemTime};
 use tokio::sync::Notify;
 
 enum WheelCommand<C: SchedulerConfig> {
-    Insert(SchedulerKey<C>, Duration),
+    Insert(SchedulerKey<C>, SystemTime, Duration, TaskPriority),
+    Remove(SchedulerKey<C>),
     Clear,
 }
 
 type ResultQueue<C> = (SegQueue<Vec<SchedulerKey<C>>>, Notify);
 
+// The fire time, priority and generation of every key pending in the wheel, mirrored for diagnostics
+type PendingView<C> = parking_lot::Mutex<HashMap<SchedulerKey<C>, (SystemTime, TaskPriority, u64)>>;
+
 pub struct DefaultSchedulerEngine<C: SchedulerConfig> {
     command_batch: Arc<SegQueue<WheelCommand<C>>>,
     get_result_queue: Arc<ResultQueue<C>>,
+    pending: Arc<PendingView<C>>,
     clock: Arc<C::SchedulerClock>,
 }
 
@@ -26,14 +34,26 @@ where
     C::SchedulerClock: Default,
 {
     fn default() -> Self {
-        let clock = Arc::new(C::SchedulerClock::default());
+        Self::new(C::SchedulerClock::default())
+    }
+}
+
+impl<C: SchedulerConfig> DefaultSchedulerEngine<C> {
+    /// Constructs a [`DefaultSchedulerEngine`] driven by ``clock``, for instance a
+    /// [`ProgressiveClock`](crate::scheduler::clock::ProgressiveClock) with a coarser resolution.
+    pub fn new(clock: C::SchedulerClock) -> Self {
+        let clock = Arc::new(clock);
 
         let mut hierarchical_wheel =
-            HierarchicalTimingWheel::<SchedulerKey<C>>::default();
+            HierarchicalTimingWheel::<(TaskPriority, u64, SchedulerKey<C>)>::default();
+        let mut generations = HashMap::<SchedulerKey<C>, u64>::new();
+        let mut next_generation = 0u64;
 
-        let command_batch = Arc::new(SegQueue::new());
+        let command_batch = Arc::new(SegQueue::<WheelCommand<C>>::new());
         let get_result_queue = Arc::new((SegQueue::new(), Notify::new()));
+        let pending = Arc::new(PendingView::<C>::default());
 
+        let pending_clone = pending.clone();
         let clock_clone = clock.clone();
         let batch_clone = command_batch.clone();
         let get_result_queue_clone = get_result_queue.clone();
@@ -42,14 +62,53 @@ where
                 clock_clone.tick().await;
                 while let Some(command) = batch_clone.pop() {
                     match command {
-                        WheelCommand::Insert(val, pos) => {
-                            hierarchical_wheel.insert(val, pos);
+                        WheelCommand::Insert(val, time, pos, priority) => {
+                            next_generation += 1;
+                            generations.insert(val.clone(), next_generation);
+                            pending_clone.lock().insert(val.clone(), (time, priority, next_generation));
+                            hierarchical_wheel.insert((priority, next_generation, val), pos);
+                        }
+
+                        WheelCommand::Remove(val) => {
+                            generations.remove(&val);
+                            pending_clone.lock().remove(&val);
                         }
 
-                        WheelCommand::Clear => hierarchical_wheel.clear(),
+                        WheelCommand::Clear => {
+                            hierarchical_wheel.clear();
+                            generations.clear();
+                            pending_clone.lock().clear();
+                        }
+                    }
+                }
+
+                /*
+                    Scheduling a key which is already pending supersedes its previous entry,
+                    stale (superseded or removed) entries remain in the wheel but are skipped
+                    once they expire.
+
+                    Tasks expiring on the same tick are handed out by descending priority,
+                    the sort is stable so equal priorities retain their insertion order
+                 */
+                let mut expired = hierarchical_wheel.tick();
+                expired.retain(|(_, generation, key)| {
+                    if generations.get(key) != Some(generation) {
+                        return false;
+                    }
+
+                    generations.remove(key);
+                    true
+                });
+
+                if !expired.is_empty() {
+                    let mut pending = pending_clone.lock();
+                    for (_, _, key) in &expired {
+                        pending.remove(key);
                     }
                 }
-                get_result_queue_clone.0.push(hierarchical_wheel.tick());
+
+                expired.sort_by_key(|(priority, _, _)| Reverse(*priority));
+                get_result_queue_clone.0.push(expired.into_iter().map(|(_, _, key)| key).collect());
                 get_result_queue_clone.1.notify_waiters()
             }
         });
@@ -58,8 +117,30 @@ where
             clock,
             command_batch,
             get_result_queue,
+            pending,
         }
     }
+
+    /// Snapshots the next ``k`` keys pending in the engine alongside their fire times, in the order they
+    /// are handed out (by fire time, then descending priority, then scheduling order), for diagnosing why
+    /// one Task ran before another.
+    ///
+    /// This is purely diagnostic, the wheel itself is never touched. The snapshot reflects the keys the
+    /// engine has already taken in (scheduling is picked up on the next tick of the clock), and the fire
+    /// times are the requested ones, the wheel fires them with a granularity of a millisecond. Removed
+    /// keys are dropped from the snapshot right away.
+    pub fn peek_next(&self, k: usize) -> Vec<(SchedulerKey<C>, SystemTime)> {
+        let mut pending = self
+            .pending
+            .lock()
+            .iter()
+            .map(|(key, (time, priority, generation))| (*time, Reverse(*priority), *generation, key.clone()))
+            .collect::<Vec<_>>();
+
+        pending.sort_unstable_by_key(|(time, priority, generation, _)| (*time, *priority, *generation));
+        pending.truncate(k);
+        pending.into_iter().map(|(time, _, _, key)| (key, time)).collect()
+    }
 }
 
 impl<C: SchedulerConfig> SchedulerEngine<C> for DefaultSchedulerEngine<C> {
@@ -80,15 +161,28 @@ impl<C: SchedulerConfig> SchedulerEngine<C> for DefaultSchedulerEngine<C> {
         &self,
         id: &SchedulerKey<C>,
         time: SystemTime,
+        priority: TaskPriority,
     ) -> impl Future<Output = Result<(), Box<dyn Error + Send + Sync>>> + Send {
         let now = self.clock.now();
+
+        // Instants already in the past (missed fires being caught up on) are due right away, the wheel
+        // expires a zero delay on the very tick that takes the key in rather than a rotation later
         self.command_batch.push(WheelCommand::Insert(
             id.clone(),
+            time,
             time.duration_since(now).unwrap_or(Duration::ZERO),
+            priority,
         ));
         std::future::ready(Ok(()))
     }
 
+    fn remove(&self, id: &SchedulerKey<C>) -> impl Future<Output = ()> + Send {
+        // The mirror is updated right away so a removed key never shows up in peek_next
+        self.pending.lock().remove(id);
+        self.command_batch.push(WheelCommand::Remove(id.clone()));
+        std::future::ready(())
+    }
+
     fn clear(&self) -> impl Future<Output = ()> + Send {
         self.command_batch.push(WheelCommand::Clear);
         std::future::ready(())
@@ -0,0 +1,112 @@
+use crate::errors::SchedulerDropped;
+use crate::scheduler::{Scheduler, SchedulerConfig};
+use std::marker::PhantomData;
+use std::sync::{Arc, Weak};
+use std::time::SystemTime;
+
+/// [`TaskHandle`] bundles the handle of a scheduled Task with a weak reference to the [`Scheduler`]
+/// hosting it, so the Task can be operated on directly instead of passing its handle back to the
+/// scheduler every time. It is obtained via [`Scheduler::schedule_with_handle`].
+///
+/// Every method forwards to the handle-based method of the [`Scheduler`] it is named after. As the
+/// reference is weak, a [`TaskHandle`] doesn't keep the scheduler alive, once the scheduler is dropped
+/// every method fails with [`SchedulerDropped`].
+///
+/// # Example(s)
+/// ```
+/// use chronographer::scheduler::{DefaultLiveScheduler, Scheduler};
+/// use std::sync::Arc;
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::TaskScheduleInterval;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// # let task = Task::new(
+/// #     DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }),
+/// #     TaskScheduleInterval::from_secs(3600),
+/// # );
+///
+/// let scheduler = Arc::new(DefaultLiveScheduler::<String>::default());
+/// let handle = scheduler.schedule_with_handle(task).await?;
+///
+/// handle.pause().await?;
+/// handle.run_now().await?;
+/// handle.cancel().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct TaskHandle<C: SchedulerConfig, S: Scheduler<C>> {
+    key: S::Handle,
+    scheduler: Weak<S>,
+    _config: PhantomData<fn() -> C>,
+}
+
+impl<C: SchedulerConfig, S: Scheduler<C>> Clone for TaskHandle<C, S> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            scheduler: self.scheduler.clone(),
+            _config: PhantomData,
+        }
+    }
+}
+
+impl<C: SchedulerConfig, S: Scheduler<C>> TaskHandle<C, S> {
+    pub fn new(key: S::Handle, scheduler: &Arc<S>) -> Self {
+        Self {
+            key,
+            scheduler: Arc::downgrade(scheduler),
+            _config: PhantomData,
+        }
+    }
+
+    /// The handle the Task is known by to the scheduler, for use with the handle-based methods.
+    pub fn key(&self) -> &S::Handle {
+        &self.key
+    }
+
+    fn scheduler(&self) -> Result<Arc<S>, SchedulerDropped> {
+        self.scheduler.upgrade().ok_or(SchedulerDropped)
+    }
+
+    /// Removes the Task from the scheduler, see [`Scheduler::remove`].
+    pub async fn cancel(&self) -> Result<(), SchedulerDropped> {
+        self.scheduler()?.remove(&self.key).await;
+        Ok(())
+    }
+
+    /// See [`Scheduler::pause`].
+    pub async fn pause(&self) -> Result<(), SchedulerDropped> {
+        self.scheduler()?.pause(&self.key).await;
+        Ok(())
+    }
+
+    /// See [`Scheduler::resume`].
+    pub async fn resume(&self) -> Result<(), SchedulerDropped> {
+        self.scheduler()?.resume(&self.key).await;
+        Ok(())
+    }
+
+    /// See [`Scheduler::is_paused`].
+    pub async fn is_paused(&self) -> Result<bool, SchedulerDropped> {
+        Ok(self.scheduler()?.is_paused(&self.key).await)
+    }
+
+    /// See [`Scheduler::next_fire`].
+    pub async fn next_fire(&self) -> Result<Option<SystemTime>, SchedulerDropped> {
+        Ok(self.scheduler()?.next_fire(&self.key).await)
+    }
+
+    /// See [`Scheduler::run_now`].
+    pub async fn run_now(&self) -> Result<(), SchedulerDropped> {
+        self.scheduler()?.run_now(&self.key).await;
+        Ok(())
+    }
+
+    /// Whether the Task is still hosted on the scheduler, ``false`` as well when the scheduler was dropped.
+    pub async fn exists(&self) -> bool {
+        match self.scheduler.upgrade() {
+            Some(scheduler) => scheduler.exists(&self.key).await,
+            None => false,
+        }
+    }
+}
@@ -2,18 +2,24 @@ use crate::errors::TaskError;
 use crate::scheduler::clock::SchedulerClock;
 use crate::scheduler::engine::SchedulerEngine;
 use crate::scheduler::impls::utils::*;
+use crate::scheduler::metrics::SchedulerLatencyHistogram;
 use crate::scheduler::task_dispatcher::SchedulerTaskDispatcher;
 use crate::scheduler::task_store::SchedulerTaskStore;
 use crate::scheduler::{
-    DefaultSchedulerConfig, FailoverPolicy, Scheduler, SchedulerConfig, SchedulerHandlePayload,
-    SchedulerKey,
+    DefaultSchedulerConfig, FailoverPolicy, Scheduler, SchedulerConfig, SchedulerDiagnostics,
+    SchedulerHandlePayload, SchedulerKey, SchedulerMetrics, SchedulerShutdownSummary, TaskDiagnostics, TaskListing,
+    TickOutcome,
 };
-use crate::task::{Task, TaskFrame};
+use crate::task::{CatchUpPolicy, ErasedTask, MAX_OCCURRENCES, OnTaskExpired, OnTaskReschedule, OnTimeout, Task, TaskFrame};
+use crate::utils::{RandomSource, ThreadRandomSource};
 use crossbeam::deque::{Injector, Steal, Stealer, Worker};
 use crossbeam::queue::SegQueue;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime};
 use crossbeam::utils::CachePadded;
 use tokio::join;
 use tokio::sync::Notify;
@@ -28,6 +34,8 @@ pub type DefaultLiveAnyhowScheduler = DefaultLiveScheduler<anyhow::Error>;
 #[cfg(feature = "eyre")]
 pub type DefaultLiveEyreScheduler = DefaultLiveScheduler<eyre::Error>;
 
+type DefaultTaskTimeout<E> = Arc<(Duration, Box<dyn Fn() -> E + Send + Sync>)>;
+
 #[derive(Debug)]
 #[repr(u8)]
 pub enum SchedulerWork {
@@ -46,6 +54,286 @@ pub(crate) struct SchedulerWorkerCold<C: SchedulerConfig> {
     pub pending: CachePadded<AtomicUsize>
 }
 
+pub(crate) struct SchedulerSharedState {
+    pub in_flight: CachePadded<AtomicUsize>,
+    pub idle_notify: Notify,
+    pub load_notify: Notify,
+    pub removed_notify: Notify,
+    pub high_water_mark: Option<usize>,
+    pub halted: AtomicBool,
+    pub latency: SchedulerLatencyHistogram,
+}
+
+impl Default for SchedulerSharedState {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl SchedulerSharedState {
+    pub fn new(high_water_mark: Option<usize>) -> Self {
+        Self {
+            in_flight: CachePadded::new(AtomicUsize::new(0)),
+            idle_notify: Notify::new(),
+            load_notify: Notify::new(),
+            removed_notify: Notify::new(),
+            high_water_mark: high_water_mark.map(|mark| mark.max(1)),
+            halted: AtomicBool::new(false),
+            latency: SchedulerLatencyHistogram::default(),
+        }
+    }
+
+    pub fn enter_dispatch(&self) -> InFlightGuard<'_> {
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        InFlightGuard(self)
+    }
+
+    pub fn is_saturated(&self) -> bool {
+        self.high_water_mark
+            .is_some_and(|mark| self.in_flight.load(Ordering::SeqCst) >= mark)
+    }
+
+    pub async fn wait_in_flight_drained(&self) {
+        loop {
+            let notified = self.idle_notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            if self.in_flight.load(Ordering::SeqCst) == 0 {
+                return;
+            }
+
+            notified.await;
+        }
+    }
+
+    // Wakes up the waiters of Scheduler::on_empty, to be called after Tasks are removed from the store
+    pub fn notify_removed(&self) {
+        self.removed_notify.notify_waiters();
+    }
+
+    pub async fn wait_unsaturated(&self) {
+        loop {
+            let notified = self.load_notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            if !self.is_saturated() {
+                return;
+            }
+
+            notified.await;
+        }
+    }
+}
+
+/*
+    While draining, keys which would be fired or rescheduled are parked instead, the atomic flag keeps
+    the hot path lock-free outside of draining while the lock makes parking and leaving race-free
+ */
+pub(crate) struct SchedulerDrainState<C: SchedulerConfig> {
+    draining: AtomicBool,
+    parked: parking_lot::Mutex<Option<Vec<SchedulerKey<C>>>>,
+}
+
+impl<C: SchedulerConfig> Default for SchedulerDrainState<C> {
+    fn default() -> Self {
+        Self {
+            draining: AtomicBool::new(false),
+            parked: parking_lot::Mutex::new(None),
+        }
+    }
+}
+
+impl<C: SchedulerConfig> SchedulerDrainState<C> {
+    pub fn enter(&self) {
+        let mut parked = self.parked.lock();
+        if parked.is_none() {
+            *parked = Some(Vec::new());
+            self.draining.store(true, Ordering::Relaxed);
+        }
+    }
+
+    pub fn leave(&self) -> Vec<SchedulerKey<C>> {
+        let mut parked = self.parked.lock();
+        self.draining.store(false, Ordering::Relaxed);
+        parked.take().unwrap_or_default()
+    }
+
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::Relaxed)
+    }
+
+    pub fn try_park(&self, key: &SchedulerKey<C>) -> bool {
+        if !self.is_draining() {
+            return false;
+        }
+
+        match self.parked.lock().as_mut() {
+            Some(parked) => {
+                parked.push(key.clone());
+                true
+            }
+
+            None => false,
+        }
+    }
+}
+
+/*
+    Tasks requiring readiness are parked at trigger time until the gate opens, the same lock discipline
+    as draining makes opening the gate and parking race-free
+ */
+pub(crate) struct SchedulerReadinessGate<C: SchedulerConfig> {
+    ready: AtomicBool,
+    notify: Notify,
+    parked: parking_lot::Mutex<Vec<SchedulerKey<C>>>,
+}
+
+impl<C: SchedulerConfig> Default for SchedulerReadinessGate<C> {
+    fn default() -> Self {
+        Self {
+            ready: AtomicBool::new(false),
+            notify: Notify::new(),
+            parked: parking_lot::Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl<C: SchedulerConfig> SchedulerReadinessGate<C> {
+    pub fn open(&self) -> Vec<SchedulerKey<C>> {
+        let mut parked = self.parked.lock();
+        self.ready.store(true, Ordering::SeqCst);
+        self.notify.notify_waiters();
+        std::mem::take(&mut *parked)
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.ready.load(Ordering::SeqCst)
+    }
+
+    pub async fn wait_ready(&self) {
+        loop {
+            let notified = self.notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            if self.is_ready() {
+                return;
+            }
+
+            notified.await;
+        }
+    }
+
+    pub fn try_park(&self, key: &SchedulerKey<C>) -> bool {
+        if self.is_ready() {
+            return false;
+        }
+
+        let mut parked = self.parked.lock();
+        if self.is_ready() {
+            return false;
+        }
+
+        parked.push(key.clone());
+        true
+    }
+}
+
+/*
+    Starting is serialized so concurrent calls can't both initialize and spawn the loops, a call racing
+    an ongoing start waits for it to finish. The flag flips once the loops are spawned and back on abort
+ */
+#[derive(Default)]
+pub(crate) struct SchedulerStartState {
+    starting: tokio::sync::Mutex<()>,
+    started: AtomicBool,
+    notify: Notify,
+}
+
+impl SchedulerStartState {
+    pub fn set_started(&self, started: bool) {
+        self.started.store(started, Ordering::SeqCst);
+        if started {
+            self.notify.notify_waiters();
+        }
+    }
+
+    pub fn is_started(&self) -> bool {
+        self.started.load(Ordering::SeqCst)
+    }
+
+    pub async fn wait_started(&self) {
+        loop {
+            let notified = self.notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            if self.is_started() {
+                return;
+            }
+
+            notified.await;
+        }
+    }
+}
+
+/*
+    Paused keys are held back both at trigger and at dispatch time (a fire may already sit in the engine
+    when pausing), resuming retriggers the key which supersedes whatever the engine still holds for it
+ */
+pub(crate) struct SchedulerPauseState<C: SchedulerConfig> {
+    paused: parking_lot::Mutex<HashSet<SchedulerKey<C>>>,
+}
+
+impl<C: SchedulerConfig> Default for SchedulerPauseState<C> {
+    fn default() -> Self {
+        Self {
+            paused: parking_lot::Mutex::new(HashSet::new()),
+        }
+    }
+}
+
+impl<C: SchedulerConfig> SchedulerPauseState<C> {
+    pub fn pause(&self, key: SchedulerKey<C>) {
+        self.paused.lock().insert(key);
+    }
+
+    pub fn resume(&self, key: &SchedulerKey<C>) -> bool {
+        self.paused.lock().remove(key)
+    }
+
+    pub fn is_paused(&self, key: &SchedulerKey<C>) -> bool {
+        self.paused.lock().contains(key)
+    }
+
+    pub fn clear(&self) {
+        self.paused.lock().clear();
+    }
+}
+
+/*
+    Decrements the in-flight counter even when the dispatch future is dropped midway
+    (e.g. its worker gets aborted), otherwise an aborted execution would be counted forever
+ */
+pub(crate) struct InFlightGuard<'a>(&'a SchedulerSharedState);
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        let previous = self.0.in_flight.fetch_sub(1, Ordering::SeqCst);
+        if previous == 1 {
+            self.0.idle_notify.notify_waiters();
+        }
+
+        if self.0.high_water_mark == Some(previous) {
+            self.0.load_notify.notify_waiters();
+        }
+    }
+}
+
+const IDLE_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
 #[inline(always)]
 fn new_worker<C: SchedulerConfig>(notify: Arc<Notify>) -> (SchedulerWorkerHot<C>, SchedulerWorkerCold<C>) {
     let queue = Worker::new_fifo();
@@ -77,6 +365,33 @@ pub struct SchedulerInitConfig<C: SchedulerConfig> {
 
     #[builder(default = FailoverPolicy::default())]
     failover_policy: FailoverPolicy,
+
+    /*
+        Once this many executions are in flight, rescheduling is deferred until one of them
+        completes (the executions which are already due still get dispatched)
+     */
+    #[builder(default, setter(strip_option))]
+    high_water_mark: Option<usize>,
+
+    /*
+        Bounds every dispatched execution of the Tasks which haven't opted out (see Task::without_default_timeout),
+        a timed out execution emits OnTimeout and fails with the error produced by the supplied function
+     */
+    #[builder(
+        setter(transform = |duration: Duration, on_timeout: impl Fn() -> C::TaskError + Send + Sync + 'static|
+            Some(Arc::new((duration, Box::new(on_timeout) as Box<dyn Fn() -> C::TaskError + Send + Sync>)))
+        ),
+        default = None
+    )]
+    default_task_timeout: Option<DefaultTaskTimeout<C::TaskError>>,
+
+    /*
+        Tasks which come due at the same instant are dispatched at random offsets within this window
+        rather than in one burst, smoothing the load at the expense of punctuality (each of them may
+        be dispatched up to this late, and the fires after the burst wait for it). Zero disables it
+     */
+    #[builder(default = Duration::ZERO)]
+    dispatch_jitter: Duration,
 }
 
 impl<C: SchedulerConfig> From<SchedulerInitConfig<C>> for LiveScheduler<C> {
@@ -112,10 +427,28 @@ impl<C: SchedulerConfig> From<SchedulerInitConfig<C>> for LiveScheduler<C> {
             global_queue: Arc::new(Injector::new()),
             instruction_queue: Arc::new((SegQueue::<SchedulerHandlePayload>::new(), Notify::new())),
             failover_policy: config.failover_policy,
+            state: Arc::new(SchedulerSharedState::new(config.high_water_mark)),
+            drain: Arc::new(SchedulerDrainState::default()),
+            readiness: Arc::new(SchedulerReadinessGate::default()),
+            paused: Arc::new(SchedulerPauseState::default()),
+            start_state: SchedulerStartState::default(),
+            default_task_timeout: config.default_task_timeout,
+            dispatch_jitter: config.dispatch_jitter,
+            dispatch_random: Arc::new(ThreadRandomSource),
+            tick_pending: parking_lot::Mutex::new(HashMap::new()),
         }
     }
 }
 
+/// [`LiveScheduler`] is the default [`Scheduler`] implementation, its engine, workers and dispatcher
+/// all run as tokio tasks (spawned once [`Scheduler::start`] is called).
+///
+/// # Runtimes
+/// Both the multi-thread and the ``current_thread`` runtime of tokio are supported. On a ``current_thread``
+/// runtime everything runs interleaved on a single thread, executions still overlap whenever they await but
+/// CPU-bound TaskFrames stall the whole scheduler (consider a
+/// [`BlockingTaskFrame`](crate::task::BlockingTaskFrame) for those), a low number of workers (such as one)
+/// is enough there. The scheduler has to be constructed and started from within the runtime.
 pub struct LiveScheduler<C: SchedulerConfig> {
     store: Arc<C::SchedulerTaskStore>,
     dispatcher: Arc<C::SchedulerTaskDispatcher>,
@@ -129,6 +462,17 @@ pub struct LiveScheduler<C: SchedulerConfig> {
     global_queue: Arc<Injector<(SchedulerKey<C>, SchedulerWork)>>,
     instruction_queue: Arc<(SegQueue<SchedulerHandlePayload>, Notify)>,
     failover_policy: FailoverPolicy,
+    state: Arc<SchedulerSharedState>,
+    drain: Arc<SchedulerDrainState<C>>,
+    readiness: Arc<SchedulerReadinessGate<C>>,
+    paused: Arc<SchedulerPauseState<C>>,
+    start_state: SchedulerStartState,
+    default_task_timeout: Option<DefaultTaskTimeout<C::TaskError>>,
+    dispatch_jitter: Duration,
+    dispatch_random: Arc<dyn RandomSource>,
+
+    // The fire times handed out while ticking (see Scheduler::tick), taking the place of the engine
+    tick_pending: parking_lot::Mutex<HashMap<SchedulerKey<C>, SystemTime>>,
 }
 
 impl<C> Default for LiveScheduler<C>
@@ -157,6 +501,7 @@ async fn apply_failover<C: SchedulerConfig>(
     work: SchedulerWork,
     store: &Arc<C::SchedulerTaskStore>,
     process: &Arc<parking_lot::RwLock<Vec<JoinHandle<()>>>>,
+    state: &Arc<SchedulerSharedState>,
 ) {
     match failover_policy {
         FailoverPolicy::Keep => {
@@ -165,7 +510,10 @@ async fn apply_failover<C: SchedulerConfig>(
 
         FailoverPolicy::Terminate => {}
 
-        FailoverPolicy::Deallocate => store.remove(key),
+        FailoverPolicy::Deallocate => {
+            store.remove(key);
+            state.notify_removed();
+        }
 
         FailoverPolicy::ShutdownScheduler => {
             let mut lock = process.write();
@@ -174,40 +522,177 @@ async fn apply_failover<C: SchedulerConfig>(
                 handle.abort();
             }
         }
+
+        FailoverPolicy::Reschedule => {
+            global_queue.push((key.clone(), SchedulerWork::Trigger));
+        }
+    }
+}
+
+// Dispatches the Task, bounding its execution by the default timeout unless the Task opted out of it
+async fn dispatch_bounded<C: SchedulerConfig>(
+    dispatcher: &C::SchedulerTaskDispatcher,
+    key: &SchedulerKey<C>,
+    task: Arc<ErasedTask<C::TaskError>>,
+    default_task_timeout: Option<&DefaultTaskTimeout<C::TaskError>>,
+) -> Result<(), C::TaskError> {
+    let timeout = default_task_timeout.filter(|_| task.uses_default_timeout());
+    match timeout {
+        Some(timeout) => {
+            let (duration, on_timeout) = timeout.as_ref();
+            let dispatched = dispatcher.dispatch(key, task.clone());
+            match tokio::time::timeout(*duration, dispatched).await {
+                Ok(result) => result,
+                Err(_) => {
+                    task.emit_hook_event::<OnTimeout>(duration).await;
+                    Err(on_timeout())
+                }
+            }
+        }
+
+        None => dispatcher.dispatch(key, task).await,
     }
 }
 
+/*
+    Missed fire times are handed out one at a time (they lie in the past so the engine fires them
+    right away), the following trigger then picks up from the fire time which was just handed out
+ */
+async fn next_fire_time<E: TaskError>(
+    task: &ErasedTask<E>,
+    now: SystemTime,
+) -> Result<SystemTime, Box<dyn Error + Send + Sync>> {
+    if let Some(time) = task.take_fire_override() {
+        return Ok(time);
+    }
+
+    let schedule = task.schedule();
+    let last = match (task.catch_up(), task.next_fire()) {
+        (CatchUpPolicy::Skip, _) | (_, None) => return schedule.schedule(now).await,
+        (_, Some(last)) if last > now => {
+            task.end_catch_up_burst();
+            return schedule.schedule(now).await;
+        }
+        (_, Some(last)) => last,
+    };
+
+    let mut missed = schedule.occurrences(last, now).await?;
+
+    // The enumeration is capped, page through it until the latest missed fire time
+    while matches!(task.catch_up(), CatchUpPolicy::RunOnce) && missed.len() >= MAX_OCCURRENCES {
+        let page = schedule.occurrences(missed[missed.len() - 1], now).await?;
+        if page.is_empty() {
+            break;
+        }
+
+        missed = page;
+    }
+
+    let handed_out = match (task.catch_up(), missed.first(), missed.last()) {
+        (CatchUpPolicy::RunAll, Some(first), _) => Some(*first),
+        (CatchUpPolicy::RunOnce, _, Some(last)) => Some(*last),
+        (CatchUpPolicy::RunAtMost(max), Some(first), _) => task.count_catch_up_fire(max).then_some(*first),
+        _ => None,
+    };
+
+    match handed_out {
+        Some(time) => Ok(time),
+        None => {
+            task.end_catch_up_burst();
+            schedule.schedule(now).await
+        }
+    }
+}
+
+// The state every worker process shares with the scheduler, handed out to each worker on start
+pub(crate) struct SchedulerWorkerContext<C: SchedulerConfig> {
+    pub hot_workers: Arc<Vec<CachePadded<SchedulerWorkerHot<C>>>>,
+    pub cold_workers: Arc<Vec<CachePadded<SchedulerWorkerCold<C>>>>,
+    pub global_queue: Arc<Injector<(SchedulerKey<C>, SchedulerWork)>>,
+    pub worker_len: usize,
+    pub store: Arc<C::SchedulerTaskStore>,
+    pub engine: Arc<C::SchedulerEngine>,
+    pub dispatcher: Arc<C::SchedulerTaskDispatcher>,
+    pub policy: FailoverPolicy,
+    pub processes: Arc<parking_lot::RwLock<Vec<JoinHandle<()>>>>,
+    pub state: Arc<SchedulerSharedState>,
+    pub drain: Arc<SchedulerDrainState<C>>,
+    pub readiness: Arc<SchedulerReadinessGate<C>>,
+    pub paused: Arc<SchedulerPauseState<C>>,
+    pub default_task_timeout: Option<DefaultTaskTimeout<C::TaskError>>,
+}
+
 #[inline(always)]
-async fn start_worker_process<C: SchedulerConfig>(
-    hot_workers: Arc<Vec<CachePadded<SchedulerWorkerHot<C>>>>,
-    cold_workers: Arc<Vec<CachePadded<SchedulerWorkerCold<C>>>>,
-    global_queue: Arc<Injector<(SchedulerKey<C>, SchedulerWork)>>,
-    idx: usize,
-    worker_len: usize,
-    store_clone: Arc<C::SchedulerTaskStore>,
-    engine_clone: Arc<C::SchedulerEngine>,
-    dispatcher_clone: Arc<C::SchedulerTaskDispatcher>,
-    policy: FailoverPolicy,
-    processes: Arc<parking_lot::RwLock<Vec<JoinHandle<()>>>>,
-) {
+async fn start_worker_process<C: SchedulerConfig>(ctx: SchedulerWorkerContext<C>, idx: usize) {
+    let SchedulerWorkerContext {
+        hot_workers,
+        cold_workers,
+        global_queue,
+        worker_len,
+        store: store_clone,
+        engine: engine_clone,
+        dispatcher: dispatcher_clone,
+        policy,
+        processes,
+        state,
+        drain,
+        readiness,
+        paused,
+        default_task_timeout,
+    } = ctx;
+
     let local_worker = {
         let mut lock = cold_workers[idx].queue.lock();
         lock.take().expect("worker queue was already taken")
     };
 
+    // Triggers held back while saturated, Dispatch work keeps being drained in the meantime
+    let mut held = Vec::new();
+
     loop {
         while let Some(work) = hot_workers[idx].ingress.pop() {
             local_worker.push(work);
         }
 
+        if !held.is_empty() && !state.is_saturated() {
+            for key in held.drain(..) {
+                local_worker.push((key, SchedulerWork::Trigger));
+            }
+        }
+
+        let mut processed = false;
         while let Some((key, work_type)) = local_worker.pop() {
+            processed = true;
             if let Some(task) = store_clone.get(&key) {
+                if let Some(age) = task.expired_at(engine_clone.clock().now()) {
+                    store_clone.remove(&key);
+                    state.notify_removed();
+                    task.emit_hook_event::<OnTaskExpired>(&age).await;
+                    continue;
+                }
+
                 match work_type {
                     SchedulerWork::Trigger => {
-                        let schedule = task.schedule();
+                        if drain.try_park(&key) {
+                            continue;
+                        }
+
+                        if task.requires_readiness() && readiness.try_park(&key) {
+                            continue;
+                        }
+
+                        if paused.is_paused(&key) {
+                            continue;
+                        }
+
+                        if state.is_saturated() {
+                            held.push(key);
+                            continue;
+                        }
+
                         let now = engine_clone.clock().now();
 
-                        let time = match schedule.schedule(now).await {
+                        let time = match next_fire_time(&task, now).await {
                             Ok(time) => time,
 
                             Err(err) => {
@@ -219,14 +704,18 @@ async fn start_worker_process<C: SchedulerConfig>(
                                     work_type,
                                     &store_clone,
                                     &processes,
+                                    &state,
                                 )
                                 .await;
                                 continue;
                             }
                         };
 
-                        match engine_clone.schedule(&key, time).await {
-                            Ok(()) => {}
+                        match engine_clone.schedule(&key, time, task.priority()).await {
+                            Ok(()) => {
+                                let previous = task.replace_next_fire(time);
+                                task.emit_hook_event::<OnTaskReschedule>(&(previous, time)).await;
+                            }
 
                             Err(err) => {
                                 eprintln!("Schedule error from SchedulerEngine: {:?}", err);
@@ -237,6 +726,7 @@ async fn start_worker_process<C: SchedulerConfig>(
                                     work_type,
                                     &store_clone,
                                     &processes,
+                                    &state,
                                 )
                                 .await;
                             }
@@ -244,7 +734,25 @@ async fn start_worker_process<C: SchedulerConfig>(
                     }
 
                     SchedulerWork::Dispatch => {
-                        let result = dispatcher_clone.dispatch(&key, task).await;
+                        if state.halted.load(Ordering::Relaxed) || paused.is_paused(&key) {
+                            continue;
+                        }
+
+                        if let Some(scheduled) = task.next_fire() {
+                            let now = engine_clone.clock().now();
+                            state.latency.record(now.duration_since(scheduled).unwrap_or_default());
+                        }
+
+                        let guard = state.enter_dispatch();
+                        let result = dispatch_bounded::<C>(
+                            &dispatcher_clone,
+                            &key,
+                            task,
+                            default_task_timeout.as_ref(),
+                        )
+                        .await;
+                        drop(guard);
+
                         match result {
                             Ok(()) => {
                                 local_worker.push((key, SchedulerWork::Trigger));
@@ -262,6 +770,7 @@ async fn start_worker_process<C: SchedulerConfig>(
                                     work_type,
                                     &store_clone,
                                     &processes,
+                                    &state,
                                 )
                                 .await;
                             }
@@ -271,6 +780,15 @@ async fn start_worker_process<C: SchedulerConfig>(
             }
         }
 
+        /*
+            Hands control back to the runtime between batches, on a current-thread runtime a worker
+            which keeps finding work (e.g. failovers requeueing into the global queue) would otherwise
+            starve the engine, the clock and every other worker
+         */
+        if processed {
+            tokio::task::yield_now().await;
+        }
+
         let mut found_work = false;
         let steal_attempts = worker_len.min(4);
         for _ in 0..steal_attempts {
@@ -314,7 +832,15 @@ async fn start_worker_process<C: SchedulerConfig>(
             continue;
         }
 
-        cold_workers[idx].notify.notified().await;
+        if held.is_empty() {
+            cold_workers[idx].notify.notified().await;
+            continue;
+        }
+
+        tokio::select! {
+            _ = state.wait_unsaturated() => {}
+            _ = cold_workers[idx].notify.notified() => {}
+        }
     }
 }
 
@@ -322,12 +848,219 @@ impl<C: SchedulerConfig> LiveScheduler<C> {
     pub fn builder() -> SchedulerInitConfigBuilder<C> {
         SchedulerInitConfig::builder()
     }
+
+    /// The number of Task executions which are currently in flight.
+    pub fn in_flight(&self) -> usize {
+        self.state.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Whether the in-flight executions have reached the configured high-water mark, while saturated
+    /// rescheduling is deferred. Always ``false`` when no high-water mark has been configured.
+    pub fn is_saturated(&self) -> bool {
+        self.state.is_saturated()
+    }
+
+    /// The timeout bounding dispatched executions of Tasks which haven't opted out via
+    /// [`Task::without_default_timeout`], ``None`` (the default) when executions are unbounded.
+    pub fn default_task_timeout(&self) -> Option<Duration> {
+        self.default_task_timeout.as_ref().map(|timeout| timeout.0)
+    }
+
+    /// The window Tasks which come due at the same instant are spread over when dispatched,
+    /// [`Duration::ZERO`] (the default) when they are dispatched in one burst.
+    pub fn dispatch_jitter(&self) -> Duration {
+        self.dispatch_jitter
+    }
+
+    /// Replaces the source of randomness the dispatch jitter offsets are drawn from, by default
+    /// [`ThreadRandomSource`]. Has to be called before [`Scheduler::start`] for it to take effect.
+    pub fn with_random_source(mut self, random: impl RandomSource) -> Self {
+        self.dispatch_random = Arc::new(random);
+        self
+    }
+
+    /// Waits until the readiness gate is opened via [`Scheduler::set_ready`], resolving
+    /// immediately if it already is.
+    pub async fn wait_ready(&self) {
+        self.readiness.wait_ready().await
+    }
+
+    /// Waits until the scheduler has started (see [`Scheduler::start`] for what that guarantees), resolving
+    /// immediately if it already has. Meant for sequencing code which doesn't own the call to start it.
+    pub async fn started(&self) {
+        self.start_state.wait_started().await
+    }
+
+    /// The engine of the scheduler, for instance to inspect the Tasks pending in it via
+    /// [`DefaultSchedulerEngine::peek_next`](crate::scheduler::engine::DefaultSchedulerEngine::peek_next).
+    pub fn engine(&self) -> &C::SchedulerEngine {
+        self.engine.as_ref()
+    }
+
+    /// The clock of the engine, for instance to advance a [`VirtualClock`](crate::scheduler::clock::VirtualClock)
+    /// while [ticking](Scheduler::tick) the scheduler.
+    pub fn clock(&self) -> &C::SchedulerClock {
+        self.engine.clock()
+    }
+
+    /*
+        Ticking bypasses the engine and the workers, so the work they would have picked up (the triggers
+        queued by scheduling, resuming and so on, as well as the instructions of TaskFrames) is drained here
+     */
+    async fn drain_tick_work(&self) {
+        let mut queued = Vec::new();
+        while let Some((id, instruction)) = self.instruction_queue.0.pop() {
+            let Some(key) = id.downcast_ref::<SchedulerKey<C>>() else {
+                continue;
+            };
+
+            match instruction {
+                SchedulerHandleInstructions::Reschedule => queued.push((key.clone(), SchedulerWork::Trigger)),
+                SchedulerHandleInstructions::Halt => self.dispatcher.cancel(key).await,
+                SchedulerHandleInstructions::Block => {
+                    self.store.remove(key);
+                    self.engine.remove(key).await;
+                    self.state.notify_removed();
+                }
+                SchedulerHandleInstructions::Execute => queued.push((key.clone(), SchedulerWork::Dispatch)),
+            }
+        }
+
+        for worker in self.hot_workers.iter() {
+            while let Some(work) = worker.ingress.pop() {
+                queued.push(work);
+            }
+        }
+
+        loop {
+            match self.global_queue.steal() {
+                Steal::Success(work) => queued.push(work),
+                Steal::Retry => continue,
+                Steal::Empty => break,
+            }
+        }
+
+        for (key, work) in queued {
+            let Some(task) = self.store.get(&key) else {
+                continue;
+            };
+
+            // Dispatch work fires the Task right away, like an override of its next fire time
+            if let SchedulerWork::Dispatch = work {
+                task.set_fire_override(self.engine.clock().now());
+            }
+
+            self.tick_trigger(&key, &task).await;
+        }
+    }
+
+    async fn tick_trigger(&self, key: &SchedulerKey<C>, task: &ErasedTask<C::TaskError>) {
+        if self.drain.try_park(key) || (task.requires_readiness() && self.readiness.try_park(key)) {
+            return;
+        }
+
+        if self.paused.is_paused(key) {
+            return;
+        }
+
+        let now = self.engine.clock().now();
+        match next_fire_time(task, now).await {
+            Ok(time) => {
+                self.tick_pending.lock().insert(key.clone(), time);
+                let previous = task.replace_next_fire(time);
+                task.emit_hook_event::<OnTaskReschedule>(&(previous, time)).await;
+            }
+
+            Err(err) => {
+                eprintln!("Computation error from TaskTrigger: {:?}", err);
+                self.tick_failover(key, SchedulerWork::Trigger).await;
+            }
+        }
+    }
+
+    async fn tick_failover(&self, key: &SchedulerKey<C>, work: SchedulerWork) {
+        apply_failover::<C>(
+            self.failover_policy,
+            key,
+            &self.global_queue,
+            work,
+            &self.store,
+            &self.process,
+            &self.state,
+        )
+        .await;
+    }
+
+    // Takes out the earliest pending fire (the highest priority first on ties), dropping expired Tasks
+    async fn next_tick(&self) -> Option<(SchedulerKey<C>, Arc<ErasedTask<C::TaskError>>, SystemTime)> {
+        loop {
+            let (key, task, time) = {
+                let mut pending = self.tick_pending.lock();
+                pending.retain(|key, _| self.store.exists(key));
+
+                let (key, task, time) = pending
+                    .iter()
+                    .filter(|(key, _)| !self.paused.is_paused(key))
+                    .filter_map(|(key, time)| Some((key.clone(), self.store.get(key)?, *time)))
+                    .min_by_key(|(_, task, time)| (*time, Reverse(task.priority())))?;
+
+                pending.remove(&key);
+                (key, task, time)
+            };
+
+            if let Some(age) = task.expired_at(self.engine.clock().now()) {
+                self.store.remove(&key);
+                self.state.notify_removed();
+                task.emit_hook_event::<OnTaskExpired>(&age).await;
+                continue;
+            }
+
+            return Some((key, task, time));
+        }
+    }
+
+    fn is_idle(&self) -> bool {
+        if self.state.in_flight.load(Ordering::SeqCst) > 0 || !self.global_queue.is_empty() {
+            return false;
+        }
+
+        let queued = self.hot_workers.iter().any(|worker| !worker.ingress.is_empty() || !worker.stealer.is_empty());
+        if queued {
+            return false;
+        }
+
+        if self.drain.is_draining() {
+            return true;
+        }
+
+        let now = self.engine.clock().now();
+        self.store.list().iter().all(|(key, task)| {
+            if task.requires_readiness() && !self.readiness.is_ready() {
+                return true;
+            }
+
+            if task.running_instances() > 0 {
+                return false;
+            }
+
+            if self.paused.is_paused(key) {
+                return true;
+            }
+
+            if task.has_fire_override() {
+                return false;
+            }
+
+            task.next_fire().is_some_and(|time| time > now)
+        })
+    }
 }
 
 impl<C: SchedulerConfig> Scheduler<C> for LiveScheduler<C> {
     type Handle = SchedulerKey<C>;
 
     async fn start(&self) {
+        let _starting = self.start_state.starting.lock().await;
         if self.has_started().await {
             return;
         }
@@ -336,6 +1069,8 @@ impl<C: SchedulerConfig> Scheduler<C> for LiveScheduler<C> {
         let store_clone = self.store.clone();
         let dispatcher_clone = self.dispatcher.clone();
 
+        self.state.halted.store(false, Ordering::Relaxed);
+
         join!(
             self.store.init(),
             self.dispatcher.init(),
@@ -344,18 +1079,24 @@ impl<C: SchedulerConfig> Scheduler<C> for LiveScheduler<C> {
 
         let mut lock = self.process.write();
         for idx in 0..self.worker_len {
-            let handle = tokio::spawn(start_worker_process(
-                self.hot_workers.clone(),
-                self.cold_workers.clone(),
-                self.global_queue.clone(),
-                idx,
-                self.worker_len,
-                self.store.clone(),
-                self.engine.clone(),
-                self.dispatcher.clone(),
-                self.failover_policy,
-                self.process.clone(),
-            ));
+            let ctx = SchedulerWorkerContext {
+                hot_workers: self.hot_workers.clone(),
+                cold_workers: self.cold_workers.clone(),
+                global_queue: self.global_queue.clone(),
+                worker_len: self.worker_len,
+                store: self.store.clone(),
+                engine: self.engine.clone(),
+                dispatcher: self.dispatcher.clone(),
+                policy: self.failover_policy,
+                processes: self.process.clone(),
+                state: self.state.clone(),
+                drain: self.drain.clone(),
+                readiness: self.readiness.clone(),
+                paused: self.paused.clone(),
+                default_task_timeout: self.default_task_timeout.clone(),
+            };
+
+            let handle = tokio::spawn(start_worker_process(ctx, idx));
 
             lock.push(handle);
         }
@@ -364,6 +1105,9 @@ impl<C: SchedulerConfig> Scheduler<C> for LiveScheduler<C> {
             &engine_clone,
             &self.hot_workers,
             &self.cold_workers,
+            &self.drain,
+            self.dispatch_jitter,
+            self.dispatch_random.clone(),
         )));
 
         lock.push(tokio::spawn(scheduler_handle_instructions_logic::<C>(
@@ -372,7 +1116,11 @@ impl<C: SchedulerConfig> Scheduler<C> for LiveScheduler<C> {
             &store_clone,
             &self.hot_workers,
             &self.cold_workers,
+            &self.state,
         )));
+
+        drop(lock);
+        self.start_state.set_started(true);
     }
 
     fn has_started(&self) -> impl Future<Output = bool> + Send {
@@ -381,6 +1129,7 @@ impl<C: SchedulerConfig> Scheduler<C> for LiveScheduler<C> {
 
     fn abort(&self) -> impl Future<Output = ()> + Send {
         let mut lock = self.process.write();
+        self.start_state.set_started(false);
 
         let handles = lock.drain(..);
         for handle in handles {
@@ -396,8 +1145,9 @@ impl<C: SchedulerConfig> Scheduler<C> for LiveScheduler<C> {
 
     async fn schedule<T: TaskFrame<Args = (), Error = C::TaskError>>(
         &self,
-        task: Task<T>,
+        mut task: Task<T>,
     ) -> Result<Self::Handle, Box<dyn Error + Send + Sync>> {
+        task.set_created_at(self.engine.clock().now());
         let erased = Arc::new(task.into_erased());
         let key = self.store.store(erased.clone())?;
         append_scheduler_handler::<C>(key.clone(), &erased, self.instruction_queue.clone()).await;
@@ -406,11 +1156,242 @@ impl<C: SchedulerConfig> Scheduler<C> for LiveScheduler<C> {
         Ok(key)
     }
 
-    fn remove(&self, key: &Self::Handle) -> impl Future<Output = ()> + Send {
-        std::future::ready(self.store.remove(key))
+    async fn remove(&self, key: &Self::Handle) {
+        self.paused.resume(key);
+        self.store.remove(key);
+        self.engine.remove(key).await;
+        self.state.notify_removed();
+    }
+
+    fn list(&self) -> impl Future<Output = TaskListing<Self::Handle, C::TaskError>> + Send {
+        std::future::ready(self.store.list())
+    }
+
+    fn refresh(&self, key: &Self::Handle) -> impl Future<Output = ()> + Send {
+        if self.store.exists(key) {
+            assign_to_trigger_worker::<C>(key.clone(), &self.hot_workers, &self.cold_workers);
+        }
+
+        std::future::ready(())
     }
 
-    fn clear(&self) -> impl Future<Output = ()> + Send {
-        std::future::ready(self.store.clear())
+    fn override_next_fire(&self, key: &Self::Handle, time: SystemTime) -> impl Future<Output = ()> + Send {
+        if let Some(task) = self.store.get(key) {
+            task.set_fire_override(time);
+
+            /*
+                An executing Task is retriggered once it finishes, retriggering it now as well
+                would have the override superseded by the fire computed after the execution
+             */
+            if task.running_instances() == 0 {
+                assign_to_trigger_worker::<C>(key.clone(), &self.hot_workers, &self.cold_workers);
+            }
+        }
+
+        std::future::ready(())
+    }
+
+    fn run_now(&self, key: &Self::Handle) -> impl Future<Output = ()> + Send {
+        self.override_next_fire(key, self.engine.clock().now())
+    }
+
+    fn next_fire(&self, key: &Self::Handle) -> impl Future<Output = Option<SystemTime>> + Send {
+        std::future::ready(self.store.get(key).and_then(|task| task.next_fire()))
+    }
+
+    fn pause(&self, key: &Self::Handle) -> impl Future<Output = ()> + Send {
+        if self.store.exists(key) {
+            self.paused.pause(key.clone());
+        }
+
+        std::future::ready(())
+    }
+
+    fn resume(&self, key: &Self::Handle) -> impl Future<Output = ()> + Send {
+        if self.paused.resume(key) && self.store.exists(key) {
+            assign_to_trigger_worker::<C>(key.clone(), &self.hot_workers, &self.cold_workers);
+        }
+
+        std::future::ready(())
+    }
+
+    fn is_paused(&self, key: &Self::Handle) -> impl Future<Output = bool> + Send {
+        std::future::ready(self.paused.is_paused(key))
+    }
+
+    async fn clear(&self) {
+        self.engine.clear().await;
+        self.paused.clear();
+        self.store.clear();
+        self.state.notify_removed();
+    }
+
+    fn enter_drain(&self) -> impl Future<Output = ()> + Send {
+        self.drain.enter();
+        std::future::ready(())
+    }
+
+    fn is_draining(&self) -> impl Future<Output = bool> + Send {
+        std::future::ready(self.drain.is_draining())
+    }
+
+    fn leave_drain(&self) -> impl Future<Output = ()> + Send {
+        for key in self.drain.leave() {
+            if self.store.exists(&key) {
+                assign_to_trigger_worker::<C>(key, &self.hot_workers, &self.cold_workers);
+            }
+        }
+
+        std::future::ready(())
+    }
+
+    fn set_ready(&self) -> impl Future<Output = ()> + Send {
+        for key in self.readiness.open() {
+            if self.store.exists(&key) {
+                assign_to_trigger_worker::<C>(key, &self.hot_workers, &self.cold_workers);
+            }
+        }
+
+        std::future::ready(())
+    }
+
+    fn is_ready(&self) -> impl Future<Output = bool> + Send {
+        std::future::ready(self.readiness.is_ready())
+    }
+
+    fn metrics(&self) -> impl Future<Output = SchedulerMetrics> + Send {
+        std::future::ready(self.state.latency.snapshot())
+    }
+
+    fn diagnostics(&self) -> impl Future<Output = SchedulerDiagnostics> + Send {
+        let tasks = self
+            .store
+            .list()
+            .into_iter()
+            .map(|(key, task)| TaskDiagnostics {
+                id: key.into(),
+                description: task.description().to_owned(),
+                owner: task.owner().to_owned(),
+                priority: task.priority(),
+                next_fire: task.next_fire(),
+                running: task.running_instances(),
+                last_run: task.last_run(),
+            })
+            .collect();
+
+        let backlog = self.global_queue.len()
+            + self
+                .hot_workers
+                .iter()
+                .map(|worker| worker.ingress.len() + worker.stealer.len())
+                .sum::<usize>();
+
+        std::future::ready(SchedulerDiagnostics {
+            tasks,
+            in_flight: self.state.in_flight.load(Ordering::SeqCst),
+            backlog,
+            draining: self.drain.is_draining(),
+            ready: self.readiness.is_ready(),
+            metrics: self.state.latency.snapshot(),
+        })
+    }
+
+    async fn wait_idle(&self) {
+        let mut observed = false;
+        loop {
+            let idle = self.is_idle();
+            if idle && observed {
+                return;
+            }
+
+            observed = idle;
+            tokio::time::sleep(IDLE_POLL_INTERVAL).await;
+        }
+    }
+
+    async fn on_empty(&self) {
+        loop {
+            let removed = self.state.removed_notify.notified();
+            let drained = self.state.idle_notify.notified();
+            tokio::pin!(removed, drained);
+            removed.as_mut().enable();
+            drained.as_mut().enable();
+
+            if self.store.is_empty() && self.state.in_flight.load(Ordering::SeqCst) == 0 {
+                return;
+            }
+
+            tokio::select! {
+                _ = removed => {}
+                _ = drained => {}
+            }
+        }
+    }
+
+    async fn tick(&self) -> TickOutcome<Self::Handle, C::TaskError> {
+        if self.has_started().await {
+            return None;
+        }
+
+        self.drain_tick_work().await;
+        let (key, task, time) = self.next_tick().await?;
+        self.engine.clock().idle_to(time).await;
+
+        let now = self.engine.clock().now();
+        self.state.latency.record(now.duration_since(time).unwrap_or_default());
+
+        let guard = self.state.enter_dispatch();
+        let result = dispatch_bounded::<C>(
+            &self.dispatcher,
+            &key,
+            task.clone(),
+            self.default_task_timeout.as_ref(),
+        )
+        .await;
+        drop(guard);
+
+        match &result {
+            Ok(()) => self.tick_trigger(&key, &task).await,
+            Err(err) => {
+                eprintln!(
+                    "Scheduler engine received an error for Task with identifier ({:?}):\n\t {:?}",
+                    key, err
+                );
+                self.tick_failover(&key, SchedulerWork::Dispatch).await;
+            }
+        }
+
+        self.drain_tick_work().await;
+        Some((key, result))
+    }
+
+    async fn stop_and_clear(&self, timeout: Duration) -> SchedulerShutdownSummary {
+        if !self.has_started().await {
+            self.engine.clear().await;
+            self.paused.clear();
+            self.store.clear();
+            self.state.notify_removed();
+            return SchedulerShutdownSummary::default();
+        }
+
+        self.state.halted.store(true, Ordering::Relaxed);
+        let in_flight = self.state.in_flight.load(Ordering::SeqCst);
+
+        let timed_out = tokio::time::timeout(timeout, self.state.wait_in_flight_drained())
+            .await
+            .is_err();
+
+        let aborted = self.state.in_flight.load(Ordering::SeqCst);
+        self.abort().await;
+        self.engine.clear().await;
+        self.paused.clear();
+        self.store.clear();
+        self.state.notify_removed();
+
+        SchedulerShutdownSummary {
+            drained: in_flight.saturating_sub(aborted),
+            aborted,
+            timed_out,
+        }
     }
 }
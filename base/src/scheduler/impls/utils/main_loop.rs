@@ -1,8 +1,10 @@
 use crate::scheduler::{SchedulerConfig, SchedulerWorkerCold};
 use crate::scheduler::engine::SchedulerEngine;
-use crate::scheduler::impls::live::SchedulerWorkerHot;
+use crate::scheduler::impls::live::{SchedulerDrainState, SchedulerWorkerHot};
 use crate::scheduler::impls::utils::spawn_task;
+use crate::utils::RandomSource;
 use std::sync::Arc;
+use std::time::Duration;
 use crossbeam::utils::CachePadded;
 
 #[inline(always)]
@@ -10,15 +12,49 @@ pub fn main_loop_logic<C: SchedulerConfig>(
     engine: &Arc<C::SchedulerEngine>,
     hot_workers: &Arc<Vec<CachePadded<SchedulerWorkerHot<C>>>>,
     cold_workers: &Arc<Vec<CachePadded<SchedulerWorkerCold<C>>>>,
-
+    drain: &Arc<SchedulerDrainState<C>>,
+    dispatch_jitter: Duration,
+    random: Arc<dyn RandomSource>,
 ) -> impl Future<Output = ()> + 'static {
     let engine = engine.clone();
     let hot_workers = hot_workers.clone();
     let cold_workers = cold_workers.clone();
+    let drain = drain.clone();
 
     async move {
         loop {
-            for id in engine.retrieve().await {
+            let due = engine.retrieve().await;
+            if due.len() < 2 || dispatch_jitter.is_zero() {
+                for id in due {
+                    if drain.try_park(&id) {
+                        continue;
+                    }
+
+                    spawn_task::<C>(id, &hot_workers, &cold_workers);
+                }
+
+                continue;
+            }
+
+            /*
+                Spreads Tasks due at the same instant over the jitter window, each one gets a random
+                offset and they are handed out in order of it, sleeping in between
+             */
+            let mut spread = due
+                .into_iter()
+                .map(|id| (dispatch_jitter.mul_f64(random.f64()), id))
+                .collect::<Vec<_>>();
+            spread.sort_by_key(|(offset, _)| *offset);
+
+            let mut elapsed = Duration::ZERO;
+            for (offset, id) in spread {
+                tokio::time::sleep(offset - elapsed).await;
+                elapsed = offset;
+
+                if drain.try_park(&id) {
+                    continue;
+                }
+
                 spawn_task::<C>(id, &hot_workers, &cold_workers);
             }
         }
@@ -1,5 +1,5 @@
 use crate::scheduler::impls::utils::{assign_to_trigger_worker, spawn_task};
-use crate::scheduler::live::SchedulerWorkerHot;
+use crate::scheduler::live::{SchedulerSharedState, SchedulerWorkerHot};
 use crate::scheduler::task_dispatcher::SchedulerTaskDispatcher;
 use crate::scheduler::task_store::SchedulerTaskStore;
 use crate::scheduler::{SchedulerConfig, SchedulerHandlePayload, SchedulerKey, SchedulerWorkerCold};
@@ -52,41 +52,50 @@ pub fn scheduler_handle_instructions_logic<C: SchedulerConfig>(
     store: &Arc<C::SchedulerTaskStore>,
     hot_workers: &Arc<Vec<CachePadded<SchedulerWorkerHot<C>>>>,
     cold_workers: &Arc<Vec<CachePadded<SchedulerWorkerCold<C>>>>,
+    state: &Arc<SchedulerSharedState>,
 ) -> impl Future<Output = ()> + Send + 'static {
     let dispatcher = dispatcher.clone();
     let store = store.clone();
     let hot_workers = hot_workers.clone();
     let cold_workers = cold_workers.clone();
     let instruct_queue = instruct_queue.clone();
+    let state = state.clone();
 
     async move {
-        while let Some((id, instruction)) = instruct_queue.0.pop() {
-            let id = id.downcast_ref::<SchedulerKey<C>>().unwrap_or_else(|| {
-                panic!(
-                    "Cannot downcast to TaskIdentifier of type {:?}",
-                    type_name::<SchedulerKey<C>>()
-                )
-            });
+        loop {
+            let notified = instruct_queue.1.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
 
-            match instruction {
-                SchedulerHandleInstructions::Reschedule => {
-                    assign_to_trigger_worker::<C>(id.clone(), &hot_workers, &cold_workers);
-                }
+            while let Some((id, instruction)) = instruct_queue.0.pop() {
+                let id = id.downcast_ref::<SchedulerKey<C>>().unwrap_or_else(|| {
+                    panic!(
+                        "Cannot downcast to TaskIdentifier of type {:?}",
+                        type_name::<SchedulerKey<C>>()
+                    )
+                });
 
-                SchedulerHandleInstructions::Halt => {
-                    dispatcher.cancel(id).await;
-                }
+                match instruction {
+                    SchedulerHandleInstructions::Reschedule => {
+                        assign_to_trigger_worker::<C>(id.clone(), &hot_workers, &cold_workers);
+                    }
 
-                SchedulerHandleInstructions::Block => {
-                    store.remove(id);
-                }
+                    SchedulerHandleInstructions::Halt => {
+                        dispatcher.cancel(id).await;
+                    }
 
-                SchedulerHandleInstructions::Execute => {
-                    spawn_task::<C>(id.clone(), &hot_workers, &cold_workers);
+                    SchedulerHandleInstructions::Block => {
+                        store.remove(id);
+                        state.notify_removed();
+                    }
+
+                    SchedulerHandleInstructions::Execute => {
+                        spawn_task::<C>(id.clone(), &hot_workers, &cold_workers);
+                    }
                 }
             }
-        }
 
-        instruct_queue.1.notified().await;
+            notified.await;
+        }
     }
 }
@@ -0,0 +1,110 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/*
+    Bucket i counts the latencies within [2^(i - 1), 2^i) microseconds (bucket 0 holds sub-microsecond
+    ones), the last bucket absorbs everything beyond ~9 minutes
+ */
+const LATENCY_BUCKETS: usize = 30;
+
+#[inline(always)]
+fn bucket_of(latency: Duration) -> usize {
+    let micros = latency.as_micros().min(u64::MAX as u128) as u64;
+    let bucket = (u64::BITS - micros.leading_zeros()) as usize;
+    bucket.min(LATENCY_BUCKETS - 1)
+}
+
+#[inline(always)]
+fn bucket_upper_bound(bucket: usize) -> Duration {
+    Duration::from_micros(1 << bucket)
+}
+
+/// A lock-free histogram of the scheduling latencies, recorded by the scheduler every time it
+/// dispatches a Task.
+pub(crate) struct SchedulerLatencyHistogram {
+    buckets: [AtomicU64; LATENCY_BUCKETS],
+    max_micros: AtomicU64,
+}
+
+impl Default for SchedulerLatencyHistogram {
+    fn default() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+            max_micros: AtomicU64::new(0),
+        }
+    }
+}
+
+impl SchedulerLatencyHistogram {
+    pub fn record(&self, latency: Duration) {
+        self.buckets[bucket_of(latency)].fetch_add(1, Ordering::Relaxed);
+        let micros = latency.as_micros().min(u64::MAX as u128) as u64;
+        self.max_micros.fetch_max(micros, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> SchedulerMetrics {
+        SchedulerMetrics {
+            buckets: std::array::from_fn(|idx| self.buckets[idx].load(Ordering::Relaxed)),
+            max_latency: Duration::from_micros(self.max_micros.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+/// [`SchedulerMetrics`] is a point-in-time snapshot of the scheduling latency of a
+/// [Scheduler](crate::scheduler::Scheduler), that is how late Tasks are dispatched compared to the
+/// fire time they were scheduled for (the key indicator of a saturated scheduler).
+///
+/// Latencies are measured via the [SchedulerClock](crate::scheduler::clock::SchedulerClock) of the
+/// scheduler, so they stay consistent under virtual time. They are kept in power-of-two buckets, hence
+/// percentiles are approximate and reported as the upper bound of the bucket they land in (at most
+/// twice the actual value), while [`SchedulerMetrics::max_latency`] is exact.
+///
+/// # See Also
+/// - [`Scheduler::metrics`](crate::scheduler::Scheduler::metrics) - Where this snapshot is obtained from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchedulerMetrics {
+    buckets: [u64; LATENCY_BUCKETS],
+    max_latency: Duration,
+}
+
+impl SchedulerMetrics {
+    /// The number of dispatches measured so far.
+    pub fn samples(&self) -> u64 {
+        self.buckets.iter().sum()
+    }
+
+    pub fn max_latency(&self) -> Duration {
+        self.max_latency
+    }
+
+    /// The approximate latency under which the ``quantile`` (clamped within ``0.0..=1.0``) of
+    /// dispatches fall, ``Duration::ZERO`` when nothing has been measured yet.
+    pub fn latency_percentile(&self, quantile: f64) -> Duration {
+        let samples = self.samples();
+        if samples == 0 {
+            return Duration::ZERO;
+        }
+
+        let rank = ((samples as f64) * quantile.clamp(0.0, 1.0)).ceil().max(1.0) as u64;
+        let mut seen = 0;
+        for (bucket, count) in self.buckets.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return bucket_upper_bound(bucket).min(self.max_latency);
+            }
+        }
+
+        self.max_latency
+    }
+
+    /// A histogram of the measured latencies as ``(upper bound, count)`` pairs, skipping the empty buckets.
+    pub fn histogram(&self) -> Vec<(Duration, u64)> {
+        self.buckets
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0)
+            .map(|(bucket, count)| (bucket_upper_bound(bucket), *count))
+            .collect()
+    }
+}
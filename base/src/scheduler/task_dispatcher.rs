@@ -1,8 +1,10 @@
 pub mod default; // skipcq: RS-D1001
+pub mod isolating; // skipcq: RS-D1001
 
 use crate::scheduler::{SchedulerConfig, SchedulerKey};
 use crate::task::ErasedTask;
 pub use default::*;
+pub use isolating::*;
 use std::ops::Deref;
 
 pub trait SchedulerTaskDispatcher<C: SchedulerConfig>: 'static + Send + Sync {
@@ -24,13 +24,15 @@ impl<C: SchedulerConfig> SchedulerTaskDispatcher<C> for DefaultTaskDispatcher<C>
     ) -> impl Future<Output = Result<(), C::TaskError>> + Send {
         
         // TODO: Find a way to remove the Notify when a Task is removed
+        // The entry guard locks its shard, it must be released before awaiting the execution
         let notifier = self.0
             .entry(key.clone())
-            .or_insert_with(|| Arc::new(Notify::new()));
+            .or_insert_with(|| Arc::new(Notify::new()))
+            .clone();
 
         async move {
             tokio::select! {
-                result = task.run() => result,
+                result = task.run() => result.map(|_| ()),
                 _ = notifier.notified() => Ok(()),
             }
         }
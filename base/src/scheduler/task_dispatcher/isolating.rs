@@ -0,0 +1,142 @@
+use crate::scheduler::task_dispatcher::{DefaultTaskDispatcher, SchedulerTaskDispatcher};
+use crate::scheduler::{SchedulerConfig, SchedulerKey};
+use crate::task::ErasedTask;
+use dashmap::DashMap;
+use std::ops::Deref;
+use tokio::runtime::Runtime;
+use tokio::task::AbortHandle;
+
+/// [`IsolatingDispatcher`] is a [`SchedulerTaskDispatcher`] which runs the Tasks that opted into
+/// isolation (via [`Task::with_isolation`](crate::task::Task::with_isolation)) on a dedicated multi-thread
+/// tokio runtime, while every other Task is handed to the wrapped dispatcher (running on the shared runtime).
+///
+/// This prevents a Task which blocks its thread (or is otherwise untrusted / heavy) from starving the
+/// rest of the Scheduler, at the cost of the isolated Tasks competing among themselves instead.
+///
+/// # Resource Implications
+/// The dedicated runtime spawns its own worker threads upfront (on top of the ones of the shared runtime)
+/// which live for as long as the dispatcher does, so size it for the isolated workload only. Isolated
+/// executions also pay for a cross-runtime hand-off on every dispatch. Dropping the dispatcher shuts the
+/// runtime down in the background, without waiting for the isolated executions still running.
+///
+/// # Example(s)
+/// ```
+/// use chronographer::scheduler::LiveScheduler;
+/// use chronographer::scheduler::task_dispatcher::IsolatingDispatcher;
+/// # use chronographer::prelude::*;
+/// # use chronographer::scheduler::clock::ProgressiveClock;
+/// # use chronographer::scheduler::engine::DefaultSchedulerEngine;
+/// # use chronographer::scheduler::task_store::EphemeralSchedulerTaskStore;
+/// # use chronographer::scheduler::{Scheduler, SchedulerConfig};
+/// # use chronographer::task::TaskScheduleImmediate;
+/// # struct MyConfig;
+/// # impl SchedulerConfig for MyConfig {
+/// #     type TaskError = String;
+/// #     type SchedulerTaskStore = EphemeralSchedulerTaskStore<Self>;
+/// #     type SchedulerTaskDispatcher = IsolatingDispatcher<Self>;
+/// #     type SchedulerEngine = DefaultSchedulerEngine<Self>;
+/// #     type SchedulerClock = ProgressiveClock;
+/// # }
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// # let (frame, schedule) = (
+/// #     DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }),
+/// #     TaskScheduleImmediate,
+/// # );
+///
+/// let scheduler = LiveScheduler::<MyConfig>::builder()
+///     .dispatcher(IsolatingDispatcher::new(2)?)
+///     .store(Default::default())
+///     .engine(Default::default())
+///     .build();
+///
+/// scheduler.schedule(Task::new(frame, schedule).with_isolation()).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # See Also
+/// - [`DefaultTaskDispatcher`] - The default wrapped dispatcher.
+/// - [`SchedulerTaskDispatcher`] - The direct implementor of this trait.
+pub struct IsolatingDispatcher<C: SchedulerConfig, D: SchedulerTaskDispatcher<C> = DefaultTaskDispatcher<C>> {
+    inner: D,
+    runtime: Option<Runtime>,
+    running: DashMap<SchedulerKey<C>, AbortHandle>,
+}
+
+impl<C: SchedulerConfig> IsolatingDispatcher<C> {
+    /// Creates the dispatcher alongside a dedicated runtime with ``worker_threads`` worker
+    /// threads (at least one), wrapping the [`DefaultTaskDispatcher`].
+    pub fn new(worker_threads: usize) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(worker_threads.max(1))
+            .thread_name("chronographer-isolated")
+            .enable_all()
+            .build()?;
+
+        Ok(Self::new_with(DefaultTaskDispatcher::default(), runtime))
+    }
+}
+
+impl<C: SchedulerConfig, D: SchedulerTaskDispatcher<C>> IsolatingDispatcher<C, D> {
+    pub fn new_with(inner: D, runtime: Runtime) -> Self {
+        Self {
+            inner,
+            runtime: Some(runtime),
+            running: DashMap::new(),
+        }
+    }
+
+    pub fn inner(&self) -> &D {
+        &self.inner
+    }
+}
+
+impl<C: SchedulerConfig, D: SchedulerTaskDispatcher<C>> Drop for IsolatingDispatcher<C, D> {
+    fn drop(&mut self) {
+        /*
+            Dropping a runtime blocks until its threads are joined, which panics when the
+            dispatcher happens to be dropped from within an asynchronous context
+         */
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+impl<C: SchedulerConfig, D: SchedulerTaskDispatcher<C>> SchedulerTaskDispatcher<C> for IsolatingDispatcher<C, D> {
+    fn init(&self) -> impl Future<Output = ()> + Send {
+        self.inner.init()
+    }
+
+    async fn dispatch(
+        &self,
+        id: &SchedulerKey<C>,
+        task: impl Deref<Target = ErasedTask<C::TaskError>> + Send + Sync + 'static,
+    ) -> Result<(), C::TaskError> {
+        if !task.is_isolated() {
+            return self.inner.dispatch(id, task).await;
+        }
+
+        let runtime = self.runtime.as_ref().expect("the isolated runtime is only taken on drop");
+        let handle = runtime.spawn(async move { task.run().await });
+        self.running.insert(id.clone(), handle.abort_handle());
+
+        let result = handle.await;
+        self.running.remove(id);
+
+        match result {
+            Ok(result) => result.map(|_| ()),
+            Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+            Err(_) => Ok(()),
+        }
+    }
+
+    async fn cancel(&self, id: &SchedulerKey<C>) {
+        if let Some((_, handle)) = self.running.remove(id) {
+            handle.abort();
+        }
+
+        self.inner.cancel(id).await;
+    }
+}
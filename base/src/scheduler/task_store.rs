@@ -1,7 +1,7 @@
 pub mod ephemeral;
 // skipcq: RS-D1001
 
-use crate::scheduler::SchedulerConfig;
+use crate::scheduler::{SchedulerConfig, TaskListing};
 #[allow(unused_imports)]
 use crate::task::ErasedTask;
 pub use ephemeral::*;
@@ -28,5 +28,13 @@ pub trait SchedulerTaskStore<C: SchedulerConfig>: 'static + Send + Sync {
 
     fn remove(&self, key: &Self::Key);
 
+    /// Snapshots every stored Task alongside its key, in no particular order.
+    fn list(&self) -> TaskListing<Self::Key, C::TaskError>;
+
+    /// Whether no Task is stored, implementations are encouraged to override this without snapshotting.
+    fn is_empty(&self) -> bool {
+        self.list().is_empty()
+    }
+
     fn clear(&self);
 }
\ No newline at end of file
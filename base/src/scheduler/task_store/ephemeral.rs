@@ -1,4 +1,4 @@
-use crate::scheduler::SchedulerConfig;
+use crate::scheduler::{SchedulerConfig, TaskListing};
 use crate::scheduler::task_store::SchedulerTaskStore;
 use crate::task::ErasedTask;
 use std::error::Error;
@@ -74,6 +74,26 @@ impl<C: SchedulerConfig> SchedulerTaskStore<C> for EphemeralSchedulerTaskStore<C
         }
     }
 
+    fn list(&self) -> TaskListing<Self::Key, C::TaskError> {
+        let mut tasks = Vec::new();
+        for (shard_idx, shard) in self.0.iter().enumerate() {
+            tasks.extend(shard.read().iter().map(|(inner, task)| {
+                let key = TaskKey {
+                    shard_idx: shard_idx as u16,
+                    inner,
+                };
+
+                (key, task.clone())
+            }));
+        }
+
+        tasks
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.iter().all(|shard| shard.read().is_empty())
+    }
+
     fn clear(&self) {
         for shard in self.0.iter() {
             shard.write().clear();
@@ -1,3 +1,5 @@
+pub mod barrier; // skipcq: RS-D1001
+
 pub mod dependency; // skipcq: RS-D1001
 
 pub mod frames; // skipcq: RS-D1001
@@ -8,26 +10,156 @@ pub mod hooks; // skipcq: RS-D1001
 
 pub mod schedule; // skipcq: RS-D1001
 
+#[cfg(feature = "serde")]
+pub mod spec; // skipcq: RS-D1001
+
+pub use barrier::*;
 pub use frame_builder::*;
 pub use frames::*;
 pub use hooks::*;
 pub use schedule::*;
 
 use crate::errors::TaskError;
+use dashmap::DashMap;
+use std::any::Any;
+use std::collections::VecDeque;
+use std::error::Error;
 use std::fmt::Debug;
 use std::sync::{Arc, LazyLock};
-use std::sync::atomic::AtomicUsize;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime};
 
 static INSTANCE_ID: LazyLock<AtomicUsize> = LazyLock::new(|| AtomicUsize::new(0));
 
+pub(crate) static TASK_INPUTS: LazyLock<DashMap<usize, Arc<dyn Any + Send + Sync>>> =
+    LazyLock::new(DashMap::new);
+
+pub(crate) static TASK_METADATA: LazyLock<DashMap<usize, Arc<TaskMetadata>>> =
+    LazyLock::new(DashMap::new);
+
 pub type ErasedTask<E> = Task<Box<dyn DynTaskFrame<E, ()>>>;
 
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaskPriority(pub i32);
+
+impl TaskPriority {
+    pub const LOW: Self = Self(-100);
+    pub const NORMAL: Self = Self(0);
+    pub const HIGH: Self = Self(100);
+}
+
+/// [`CatchUpPolicy`] decides what happens to the fire times a [`Task`] has missed (for example when
+/// the [Scheduler](crate::scheduler::Scheduler) was down or an execution overran the next fire time).
+///
+/// Missed fire times are the ones [`TaskSchedule::occurrences`] enumerates between the last fire
+/// time of the [`Task`] and the current time, the default is [`CatchUpPolicy::Skip`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CatchUpPolicy {
+    /// Ignores the missed fire times and continues from the current time.
+    #[default]
+    Skip,
+
+    /// Fires once immediately for all the missed fire times.
+    RunOnce,
+
+    /// Fires once for every missed fire time, one after the other.
+    RunAll,
+
+    /// Fires for the earliest missed fire times one after the other (as [`CatchUpPolicy::RunAll`] does), but at
+    /// most the given number of times in a row, the remaining missed fire times are dropped and the [`Task`]
+    /// resumes its normal cadence (as [`CatchUpPolicy::Skip`] does). This bounds the burst of rapid fires after
+    /// the Scheduler stalled for a while, the burst count resets as soon as the [`Task`] has caught up.
+    RunAtMost(NonZeroUsize),
+}
+
+/// How an [`ErasedTask::run`] which didn't fail went, either the TaskFrame ran to completion or the run
+/// was skipped without executing it, as the cap of [`Task::with_max_concurrent_instances`] was reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskRunStatus {
+    Completed,
+
+    /// Carries the number of executions which were in flight, as [`OnTaskSkipped`] does.
+    Skipped { running: usize },
+}
+
+/// A single entry of the execution history of a [`Task`] (see [`Task::with_history`]), holding
+/// when the execution started, how long it took and the ``Debug`` representation of its error (if it failed).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunRecord {
+    pub started_at: SystemTime,
+    pub duration: Duration,
+    pub error: Option<String>,
+}
+
+/// Human-readable metadata of a [`Task`] (see [`Task::with_description`] and [`Task::with_owner`]),
+/// it has no effect on scheduling nor execution. Both fields are empty by default.
+///
+/// With the ``serde`` feature it can be persisted as is (both fields are plain strings) and restored
+/// via [`Task::with_metadata`], [`TaskSpec`](crate::task::spec::TaskSpec) carries it as well.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TaskMetadata {
+    pub description: String,
+    pub owner: String,
+}
+
+impl RunRecord {
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
 pub struct Task<T1> {
     frame: T1,
     schedule: Box<dyn TaskSchedule>,
+    priority: TaskPriority,
+    catch_up: CatchUpPolicy,
+    next_fire: parking_lot::Mutex<Option<SystemTime>>,
+    fire_override: parking_lot::Mutex<Option<SystemTime>>,
+    catch_up_burst: AtomicUsize,
+    max_instances: Option<NonZeroUsize>,
+    running: AtomicUsize,
+    input: Option<TaskRegistrySlot<Arc<dyn Any + Send + Sync>>>,
+    metadata: Arc<TaskMetadata>,
+    metadata_slot: Option<TaskRegistrySlot<Arc<TaskMetadata>>>,
+    history_size: usize,
+    history: parking_lot::Mutex<VecDeque<RunRecord>>,
+    created_at: SystemTime,
+    max_lifetime: Option<Duration>,
+    requires_readiness: bool,
+    isolated: bool,
+    default_timeout: bool,
     instance_id: usize
 }
 
+/*
+    The input and metadata live in registries keyed by the instance id (so the Copy-able TaskFrameContext
+    can reach them), this slot ties the registry entry to the lifetime of the Task owning it
+ */
+struct TaskRegistrySlot<V: 'static>(&'static DashMap<usize, V>, usize);
+
+impl<V: 'static> Drop for TaskRegistrySlot<V> {
+    fn drop(&mut self) {
+        self.0.remove(&self.1);
+    }
+}
+
+/*
+    Releases the execution slot even when the run future is dropped midway (e.g. the dispatcher
+    cancels it), otherwise the cancelled execution would count towards the cap forever
+ */
+struct RunningGuard<'a>(&'a AtomicUsize);
+
+impl Drop for RunningGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
 impl<T1> Task<T1> {
     pub async fn attach_hook<EV: TaskHookEvent>(&self, hook: Arc<impl TaskHook<EV>>) {
         let ctx = TaskHookContext(self.instance_id);
@@ -39,6 +171,10 @@ impl<T1> Task<T1> {
         TASKHOOK_REGISTRY.get::<EV, T>(self.instance_id)
     }
 
+    pub fn hook_count<EV: TaskHookEvent>(&self) -> usize {
+        TaskHookContext(self.instance_id).hook_count::<EV>()
+    }
+
     pub async fn emit_hook_event<EV: TaskHookEvent>(&self, payload: &EV::Payload<'_>) {
         let ctx = TaskHookContext(self.instance_id);
 
@@ -51,29 +187,209 @@ impl<T1> Task<T1> {
         ctx.detach_hook::<EV, T>().await;
     }
 
+    pub fn next_emission<EV: OwnedPayloadEvent>(&self) -> impl Future<Output = EV::Owned> + Send + use<T1, EV> {
+        TaskHookContext(self.instance_id).next_emission::<EV>()
+    }
+
+    pub fn next_emission_with<EV, R, F>(&self, map: F) -> impl Future<Output = R> + Send + use<T1, EV, R, F>
+    where
+        EV: TaskHookEvent,
+        R: Send + 'static,
+        F: for<'a> FnOnce(&EV::Payload<'a>) -> R + Send + Sync + 'static,
+    {
+        TaskHookContext(self.instance_id).next_emission_with::<EV, R, F>(map)
+    }
+
     pub fn schedule(&self) -> &dyn TaskSchedule  {
         self.schedule.as_ref()
     }
+
+    pub fn priority(&self) -> TaskPriority {
+        self.priority
+    }
+
+    pub fn catch_up(&self) -> CatchUpPolicy {
+        self.catch_up
+    }
+
+    pub fn next_fire(&self) -> Option<SystemTime> {
+        *self.next_fire.lock()
+    }
+
+    /// Retrieves the input supplied via [`Task::with_input`], see it for the typing contract.
+    pub fn input<I: Send + Sync + 'static>(&self) -> Option<Arc<I>> {
+        TASK_INPUTS.get(&self.instance_id)?.value().clone().downcast::<I>().ok()
+    }
+
+    pub fn description(&self) -> &str {
+        &self.metadata.description
+    }
+
+    pub fn owner(&self) -> &str {
+        &self.metadata.owner
+    }
+
+    pub fn metadata(&self) -> &Arc<TaskMetadata> {
+        &self.metadata
+    }
+
+    pub fn max_concurrent_instances(&self) -> Option<NonZeroUsize> {
+        self.max_instances
+    }
+
+    pub fn running_instances(&self) -> usize {
+        self.running.load(Ordering::SeqCst)
+    }
+
+    fn try_enter(&self) -> Option<RunningGuard<'_>> {
+        let max = match self.max_instances {
+            Some(max) => max.get(),
+            None => usize::MAX,
+        };
+
+        self.running
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |running| {
+                (running < max).then_some(running + 1)
+            })
+            .ok()
+            .map(|_| RunningGuard(&self.running))
+    }
+
+    /// Bounds how long the [`Task`] exists for, measured on the wall-clock from its construction. Once
+    /// exceeded the [Scheduler](crate::scheduler::Scheduler) drops the Task (instead of rescheduling or
+    /// dispatching it) and emits [`OnTaskExpired`], by default Tasks live indefinitely.
+    ///
+    /// It is independent of a run limit (such as a [`ThresholdTaskFrame`] or the ``max_runs`` of a
+    /// [TaskSpec](crate::task::spec::TaskSpec)), whichever is hit first wins. An execution already
+    /// underway when the lifetime runs out is not interrupted.
+    ///
+    /// The lifetime is counted from the moment the Task is scheduled, as read from the
+    /// [`SchedulerClock`](crate::scheduler::clock::SchedulerClock) of the scheduler (so it follows virtual
+    /// and replayed time as well).
+    pub fn with_max_lifetime(mut self, max_lifetime: Duration) -> Self {
+        self.max_lifetime = Some(max_lifetime);
+        self
+    }
+
+    /// The time the [`Task`] was scheduled at (per the clock of the scheduler), or constructed at
+    /// (per the wall clock) if it hasn't been scheduled yet.
+    pub fn created_at(&self) -> SystemTime {
+        self.created_at
+    }
+
+    pub fn max_lifetime(&self) -> Option<Duration> {
+        self.max_lifetime
+    }
+
+    pub fn requires_readiness(&self) -> bool {
+        self.requires_readiness
+    }
+
+    pub fn is_isolated(&self) -> bool {
+        self.isolated
+    }
+
+    pub fn uses_default_timeout(&self) -> bool {
+        self.default_timeout
+    }
+
+    /// Whether the [`Task`] has outlived its max lifetime (see [`Task::with_max_lifetime`]) at ``now``,
+    /// returning how long it has existed for if so.
+    pub fn expired_at(&self, now: SystemTime) -> Option<Duration> {
+        let max_lifetime = self.max_lifetime?;
+        let age = now.duration_since(self.created_at).unwrap_or_default();
+        (age > max_lifetime).then_some(age)
+    }
+
+    /// The most recent executions of the [`Task`] (oldest first), bounded by [`Task::with_history`].
+    pub fn history(&self) -> Vec<RunRecord> {
+        self.history.lock().iter().cloned().collect()
+    }
+
+    /// The most recent execution of the [`Task`], without copying the rest of its history.
+    pub fn last_run(&self) -> Option<RunRecord> {
+        self.history.lock().back().cloned()
+    }
+
+    fn record_run(&self, record: RunRecord) {
+        if self.history_size == 0 {
+            return;
+        }
+
+        let mut history = self.history.lock();
+        if history.len() == self.history_size {
+            history.pop_front();
+        }
+
+        history.push_back(record);
+    }
+
+    pub(crate) fn set_created_at(&mut self, time: SystemTime) {
+        self.created_at = time;
+    }
+
+    pub(crate) fn replace_next_fire(&self, time: SystemTime) -> Option<SystemTime> {
+        self.next_fire.lock().replace(time)
+    }
+
+    pub(crate) fn set_fire_override(&self, time: SystemTime) {
+        *self.fire_override.lock() = Some(time);
+    }
+
+    pub(crate) fn take_fire_override(&self) -> Option<SystemTime> {
+        self.fire_override.lock().take()
+    }
+
+    pub(crate) fn has_fire_override(&self) -> bool {
+        self.fire_override.lock().is_some()
+    }
+
+    // Counts a missed fire time towards the current burst, false once ``max`` of them were handed out
+    pub(crate) fn count_catch_up_fire(&self, max: NonZeroUsize) -> bool {
+        self.catch_up_burst.fetch_add(1, Ordering::SeqCst) < max.get()
+    }
+
+    pub(crate) fn end_catch_up_burst(&self) {
+        self.catch_up_burst.store(0, Ordering::SeqCst);
+    }
 }
 
 impl<E: TaskError> ErasedTask<E> {
-    pub async fn run(&self) -> Result<(), E> {
+    pub async fn run(&self) -> Result<TaskRunStatus, E> {
+        let Some(_guard) = self.try_enter() else {
+            let running = self.running_instances();
+            self.emit_hook_event::<OnTaskSkipped>(&running).await;
+            return Ok(TaskRunStatus::Skipped { running });
+        };
+
         let ctx = TaskFrameContext(RestrictTaskFrameContext::new(self));
         ctx.emit::<OnTaskStart>(&()).await; // skipcq: RS-E1015
 
+        let started_at = SystemTime::now();
+        let started = tokio::time::Instant::now();
         let result = self.frame.erased_execute(&ctx, &()).await;
         let err = match &result {
             Ok(_) => None,
             Err(e) => Some(e as &dyn TaskError),
         };
 
+        self.record_run(RunRecord {
+            started_at,
+            duration: started.elapsed(),
+            error: err.map(|e| format!("{e:?}")),
+        });
+
         ctx.emit::<OnTaskEnd>(&err).await;
-        result
+        result.map(|_| TaskRunStatus::Completed)
     }
 
     pub fn frame(&self) -> &dyn DynTaskFrame<E, ()> {
         self.frame.as_ref()
     }
+
+    pub fn describe(&self) -> FrameNode {
+        self.frame.erased().erased_describe()
+    }
 }
 
 impl<T1: TaskFrame<Args = ()>> Task<T1> {
@@ -81,10 +397,159 @@ impl<T1: TaskFrame<Args = ()>> Task<T1> {
         Self {
             frame,
             schedule: Box::new(schedule),
-            instance_id: INSTANCE_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+            priority: TaskPriority::default(),
+            catch_up: CatchUpPolicy::default(),
+            next_fire: parking_lot::Mutex::new(None),
+            fire_override: parking_lot::Mutex::new(None),
+            catch_up_burst: AtomicUsize::new(0),
+            max_instances: None,
+            running: AtomicUsize::new(0),
+            input: None,
+            metadata: Arc::new(TaskMetadata::default()),
+            metadata_slot: None,
+            history_size: 0,
+            history: parking_lot::Mutex::new(VecDeque::new()),
+            created_at: SystemTime::now(),
+            max_lifetime: None,
+            requires_readiness: false,
+            isolated: false,
+            default_timeout: true,
+            instance_id: INSTANCE_ID.fetch_add(1, Ordering::Relaxed)
         }
     }
 
+    /// Hands out a receiver resolved with the outcome of the **next** execution of this [`Task`] to complete
+    /// (not every one), bridging a scheduled Task with request / response style usage. A failed execution
+    /// resolves it with a clone of its error, an execution which is cancelled midway (or skipped) doesn't
+    /// complete, the receiver then resolves on the following one. The hook backing it detaches itself once
+    /// the receiver is resolved.
+    ///
+    /// The receiver is armed as soon as this resolves, so it has to be called before the execution starts
+    /// (e.g. before scheduling the Task, see [`Scheduler::schedule_with_completion`](crate::scheduler::Scheduler::schedule_with_completion)).
+    pub async fn next_completion(&self) -> tokio::sync::oneshot::Receiver<Result<(), T1::Error>>
+    where
+        T1::Error: Clone,
+    {
+        TaskHookContext(self.instance_id)
+            .next_emission_channel::<OnTaskEnd, _, _>(|err| match err {
+                None => Ok(()),
+                Some(err) => Err((*err)
+                    .as_any()
+                    .downcast_ref::<T1::Error>()
+                    .expect("the executions of a Task fail with its own error type")
+                    .clone()),
+            })
+            .await
+    }
+
+    pub fn with_priority(mut self, priority: TaskPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn with_catch_up(mut self, catch_up: CatchUpPolicy) -> Self {
+        self.catch_up = catch_up;
+        self
+    }
+
+    /// Caps how many executions of this [`Task`] may be in flight at the same time, once the cap is
+    /// reached further fires are skipped and [`OnTaskSkipped`] is emitted instead of spawning yet
+    /// another execution (the skipped run reports [`TaskRunStatus::Skipped`]). By default, executions are unbounded.
+    pub fn with_max_concurrent_instances(mut self, max: NonZeroUsize) -> Self {
+        self.max_instances = Some(max);
+        self
+    }
+
+    /// Supplies a typed input to the [`Task`] (for example a job payload), which its TaskFrames can read
+    /// on every execution via [`RestrictTaskFrameContext::input`] (or [`Task::input`] from the outside).
+    ///
+    /// The input is stored type-erased and retrieved via a downcast, so it must be read back with the
+    /// **exact** type it was supplied with (supplying a ``&'static str`` and reading a ``String`` yields
+    /// ``None``, as does reading a [`Task`] which has no input). The input is shared, not cloned, between
+    /// executions so any mutation has to go through interior mutability. Supplying an input again
+    /// replaces the previous one.
+    pub fn with_input<I: Send + Sync + 'static>(mut self, input: I) -> Self {
+        self.input.get_or_insert_with(|| TaskRegistrySlot(&TASK_INPUTS, self.instance_id));
+        TASK_INPUTS.insert(self.instance_id, Arc::new(input));
+        self
+    }
+
+    /// Describes what the [`Task`] does for the humans operating it, readable from the TaskFrames via
+    /// [`RestrictTaskFrameContext::metadata`] and from the outside via [`Task::description`].
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        Arc::make_mut(&mut self.metadata).description = description.into();
+        self.publish_metadata();
+        self
+    }
+
+    /// Names who owns the [`Task`] (a team, a contact... etc.), so whoever is on-call knows who to reach
+    /// when it fails. Readable the same way as [`Task::with_description`].
+    pub fn with_owner(mut self, owner: impl Into<String>) -> Self {
+        Arc::make_mut(&mut self.metadata).owner = owner.into();
+        self.publish_metadata();
+        self
+    }
+
+    /// Replaces the whole [`TaskMetadata`] of the [`Task`], for instance to restore one which was persisted.
+    pub fn with_metadata(mut self, metadata: TaskMetadata) -> Self {
+        self.metadata = Arc::new(metadata);
+        self.publish_metadata();
+        self
+    }
+
+    fn publish_metadata(&mut self) {
+        // Reassigning the slot would drop the previous one, evicting the entry which was just inserted
+        self.metadata_slot.get_or_insert_with(|| TaskRegistrySlot(&TASK_METADATA, self.instance_id));
+        TASK_METADATA.insert(self.instance_id, self.metadata.clone());
+    }
+
+    /// Retains the last ``size`` executions of the [`Task`] as [`RunRecord`], retrievable via [`Task::history`]
+    /// (handy for debugging flaky Tasks without any external storage), by default no history is kept.
+    ///
+    /// The buffer is allocated upfront, costing ``size * size_of::<RunRecord>()`` bytes (56 bytes per record
+    /// on 64-bit targets) plus the error message of every failed execution it holds. Recording takes a short
+    /// uncontended lock once per execution.
+    pub fn with_history(mut self, size: usize) -> Self {
+        self.history_size = size;
+        self.history = parking_lot::Mutex::new(VecDeque::with_capacity(size));
+        self
+    }
+
+    /// Holds the [`Task`] back until its [Scheduler](crate::scheduler::Scheduler) is marked as ready
+    /// via [`Scheduler::set_ready`](crate::scheduler::Scheduler::set_ready), for Tasks which depend on
+    /// global resources initialized on boot. By default, Tasks don't wait for readiness.
+    pub fn with_readiness_gate(mut self) -> Self {
+        self.requires_readiness = true;
+        self
+    }
+
+    /// Opts the [`Task`] into running on a dedicated runtime when hosted on a Scheduler whose dispatcher is
+    /// an [`IsolatingDispatcher`](crate::scheduler::task_dispatcher::IsolatingDispatcher), other dispatchers
+    /// ignore it. By default, Tasks run on the shared runtime.
+    pub fn with_isolation(mut self) -> Self {
+        self.isolated = true;
+        self
+    }
+
+    /// Opts the [`Task`] out of the default execution timeout of its [Scheduler](crate::scheduler::LiveScheduler)
+    /// (see ``LiveScheduler::builder().default_task_timeout(...)``), for Tasks which are expected to run
+    /// for long or which bound themselves via a [`TimeoutTaskFrame`]. By default, Tasks use it.
+    pub fn without_default_timeout(mut self) -> Self {
+        self.default_timeout = false;
+        self
+    }
+
+    /// Validates the [`Task`] upfront by computing the next fire time of its [`TaskSchedule`] once (from the
+    /// current time), surfacing a misconfigured schedule (e.g. a cron expression which never matches) when
+    /// building the Task rather than at its first fire. The computed time is discarded.
+    ///
+    /// Validation is opt-in, as computing the fire time isn't free for every schedule (those waiting on
+    /// outside state, such as a [`TaskScheduleDependency`], wait for it during validation as well).
+    pub async fn validated(self) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        self.schedule.schedule(SystemTime::now()).await?;
+        Ok(self)
+    }
+
     pub fn frame(&self) -> &T1 {
         &self.frame
     }
@@ -93,6 +558,23 @@ impl<T1: TaskFrame<Args = ()>> Task<T1> {
         ErasedTask {
             frame: Box::new(self.frame),
             schedule: self.schedule,
+            priority: self.priority,
+            catch_up: self.catch_up,
+            next_fire: self.next_fire,
+            fire_override: self.fire_override,
+            catch_up_burst: self.catch_up_burst,
+            max_instances: self.max_instances,
+            running: self.running,
+            input: self.input,
+            metadata: self.metadata,
+            metadata_slot: self.metadata_slot,
+            history_size: self.history_size,
+            history: self.history,
+            created_at: self.created_at,
+            max_lifetime: self.max_lifetime,
+            requires_readiness: self.requires_readiness,
+            isolated: self.isolated,
+            default_timeout: self.default_timeout,
             instance_id: self.instance_id
         }
     }
@@ -0,0 +1,146 @@
+use crate::errors::TaskBarrierBroken;
+use crate::task::{OnTaskEnd, OnTaskExpired, Task, TaskHook, TaskHookContext, TaskHookEvent};
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+#[derive(Default)]
+struct BarrierState {
+    pending: parking_lot::Mutex<HashSet<usize>>,
+    dropped: parking_lot::Mutex<Vec<usize>>,
+    notify: tokio::sync::Notify,
+}
+
+impl BarrierState {
+    fn settle(&self, instance_id: usize, expired: bool) {
+        if !self.pending.lock().remove(&instance_id) {
+            return;
+        }
+
+        if expired {
+            self.dropped.lock().push(instance_id);
+        }
+
+        self.notify.notify_waiters();
+    }
+}
+
+struct TaskBarrierMonitor(Arc<BarrierState>);
+
+#[async_trait]
+impl TaskHook<OnTaskEnd> for TaskBarrierMonitor {
+    async fn on_event(&self, ctx: &TaskHookContext, _payload: &<OnTaskEnd as TaskHookEvent>::Payload<'_>) {
+        self.0.settle(ctx.0, false);
+    }
+}
+
+#[async_trait]
+impl TaskHook<OnTaskExpired> for TaskBarrierMonitor {
+    async fn on_event(&self, ctx: &TaskHookContext, _payload: &<OnTaskExpired as TaskHookEvent>::Payload<'_>) {
+        self.0.settle(ctx.0, true);
+    }
+}
+
+/// [`TaskBarrier`] groups a set of [Tasks](Task) and resolves once every one of them has run at
+/// least once (e.g. "run A, B and C, then proceed"), it complements [`FrameDependency`](crate::task::dependency::FrameDependency)
+/// by offering a handle which can be awaited directly rather than gating a TaskFrame.
+///
+/// # Behavior
+/// A member counts as completed on its first [`OnTaskEnd`] after being added, regardless of whether
+/// the execution succeeded or failed. Executions which already happened before it was added don't count.
+///
+/// Members which don't complete are handled as follows:
+/// - An execution which is cancelled midway (its future dropped, for example by the dispatcher) emits no
+///   [`OnTaskEnd`] and as such doesn't count, the barrier keeps waiting for the next completed execution.
+/// - A member dropped by the Scheduler for outliving its max lifetime ([`OnTaskExpired`]) will never complete,
+///   it breaks the barrier and [`TaskBarrier::wait`] returns a [`TaskBarrierBroken`] once every other member settled.
+/// - A member removed from the Scheduler emits nothing, it has to be taken out via [`TaskBarrier::forget`]
+///   otherwise [`TaskBarrier::wait`] never resolves (pair it with a timeout when that may happen).
+///
+/// The barrier attaches a hook to every member which stays attached once the barrier resolves, a Task
+/// should be a member of at most one [`TaskBarrier`] at a time.
+///
+/// # Example
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::scheduler::{DefaultLiveScheduler, Scheduler};
+/// # use chronographer::task::{TaskBarrier, TaskScheduleImmediate};
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// # let scheduler = DefaultLiveScheduler::<String>::default();
+/// # scheduler.start().await;
+/// # let step = || DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) });
+/// # let (task_a, task_b) = (Task::new(step(), TaskScheduleImmediate), Task::new(step(), TaskScheduleImmediate));
+/// let barrier = TaskBarrier::new();
+/// barrier.add(&task_a).await;
+/// barrier.add(&task_b).await;
+///
+/// scheduler.schedule(task_a).await?;
+/// scheduler.schedule(task_b).await?;
+///
+/// barrier.wait().await?; // Both ran at least once
+/// # scheduler.abort().await;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # See Also
+/// - [`OnTaskEnd`] - The event marking a member as completed.
+/// - [`FrameDependency`](crate::task::dependency::FrameDependency) - For gating executions on other Tasks instead.
+#[derive(Clone, Default)]
+pub struct TaskBarrier(Arc<BarrierState>);
+
+impl TaskBarrier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn add<T1>(&self, task: &Task<T1>) {
+        if !self.0.pending.lock().insert(task.instance_id) {
+            return;
+        }
+
+        let monitor = Arc::new(TaskBarrierMonitor(self.0.clone()));
+        task.attach_hook::<OnTaskEnd>(monitor.clone()).await;
+        task.attach_hook::<OnTaskExpired>(monitor).await;
+    }
+
+    /// Takes ``task`` out of the barrier without it having to complete (e.g. after removing it from the
+    /// Scheduler), waking up [`TaskBarrier::wait`] if it was the last pending member.
+    pub fn forget<T1>(&self, task: &Task<T1>) {
+        self.0.settle(task.instance_id, false);
+    }
+
+    pub fn pending(&self) -> usize {
+        self.0.pending.lock().len()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.pending() == 0
+    }
+
+    /// Waits until every member has settled, resolving right away when there are no pending members.
+    ///
+    /// # Error(s)
+    /// Returns a [`TaskBarrierBroken`] carrying the instance ids of the members which expired before completing.
+    pub async fn wait(&self) -> Result<(), TaskBarrierBroken> {
+        loop {
+            let notified = self.0.notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            if self.is_complete() {
+                break;
+            }
+
+            notified.await;
+        }
+
+        let dropped = self.0.dropped.lock().clone();
+        if !dropped.is_empty() {
+            return Err(TaskBarrierBroken(dropped));
+        }
+
+        Ok(())
+    }
+}
@@ -1,15 +1,151 @@
 use std::num::NonZeroU16;
 use std::ops::{BitAnd, BitOr, Not};
 use std::pin::Pin;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, AtomicU16, Ordering};
+use std::sync::{Arc, Weak};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 use async_trait::async_trait;
 use crate::task::{OnTaskEnd, Task, TaskFrame, TaskHook, TaskHookContext, TaskHookEvent};
 
 type ExternalFn = Box<dyn Fn() -> Pin<Box<dyn Future<Output = bool> + Send>> + Send + Sync>;
 
+/// [`DependencyObserver`] is notified whenever the [`FrameDependency`] it observes (see
+/// [`FrameDependency::with_observer`]) transitions between resolved and unresolved, any ``Fn(bool)``
+/// closure is an observer as well.
+pub trait DependencyObserver: Send + Sync + 'static {
+    fn on_transition(&self, resolved: bool);
+}
+
+impl<F: Fn(bool) + Send + Sync + 'static> DependencyObserver for F {
+    fn on_transition(&self, resolved: bool) {
+        self(resolved)
+    }
+}
+
+// The flag behind monitor-based dependencies, it refreshes the observations it is part of once it flips
+#[derive(Default)]
+struct DependencyFlag {
+    resolved: AtomicBool,
+    watchers: parking_lot::Mutex<Vec<Weak<DependencyObservation>>>,
+}
+
+impl DependencyFlag {
+    fn is_resolved(&self) -> bool {
+        self.resolved.load(Ordering::Relaxed)
+    }
+
+    fn resolve(&self) {
+        if self.resolved.swap(true, Ordering::Relaxed) {
+            return;
+        }
+
+        let watchers = self
+            .watchers
+            .lock()
+            .iter()
+            .filter_map(Weak::upgrade)
+            .collect::<Vec<_>>();
+
+        for watcher in watchers {
+            watcher.refresh();
+        }
+    }
+
+    fn watch(&self, observation: &Arc<DependencyObservation>) {
+        let mut watchers = self.watchers.lock();
+        watchers.retain(|watcher| watcher.strong_count() > 0);
+        watchers.push(Arc::downgrade(observation));
+    }
+}
+
+/*
+    Mirrors the structure of a dependency so it can be evaluated synchronously when one of its flags flips,
+    external dependencies can only be evaluated asynchronously so they leave the state unknown
+ */
+enum DependencyShape {
+    Flag(Arc<DependencyFlag>),
+    External,
+    LogicalAnd(Box<DependencyShape>, Box<DependencyShape>),
+    LogicalOr(Box<DependencyShape>, Box<DependencyShape>),
+    LogicalNot(Box<DependencyShape>),
+}
+
+impl DependencyShape {
+    fn of(inner: &DependencyInner) -> Self {
+        match inner {
+            DependencyInner::Flag(flag) => DependencyShape::Flag(flag.clone()),
+            DependencyInner::External(_) => DependencyShape::External,
+            DependencyInner::LogicalAnd(dep1, dep2) => {
+                DependencyShape::LogicalAnd(Box::new(Self::of(dep1)), Box::new(Self::of(dep2)))
+            }
+            DependencyInner::LogicalOr(dep1, dep2) => {
+                DependencyShape::LogicalOr(Box::new(Self::of(dep1)), Box::new(Self::of(dep2)))
+            }
+            DependencyInner::LogicalNot(dep1) => DependencyShape::LogicalNot(Box::new(Self::of(dep1))),
+        }
+    }
+
+    fn flags(&self, flags: &mut Vec<Arc<DependencyFlag>>) {
+        match self {
+            DependencyShape::Flag(flag) => flags.push(flag.clone()),
+            DependencyShape::External => {}
+            DependencyShape::LogicalAnd(dep1, dep2) | DependencyShape::LogicalOr(dep1, dep2) => {
+                dep1.flags(flags);
+                dep2.flags(flags);
+            }
+            DependencyShape::LogicalNot(dep1) => dep1.flags(flags),
+        }
+    }
+
+    fn evaluate(&self) -> Option<bool> {
+        match self {
+            DependencyShape::Flag(flag) => Some(flag.is_resolved()),
+            DependencyShape::External => None,
+            DependencyShape::LogicalAnd(dep1, dep2) => match (dep1.evaluate(), dep2.evaluate()) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            },
+            DependencyShape::LogicalOr(dep1, dep2) => match (dep1.evaluate(), dep2.evaluate()) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            },
+            DependencyShape::LogicalNot(dep1) => dep1.evaluate().map(|resolved| !resolved),
+        }
+    }
+}
+
+const STATE_UNKNOWN: u8 = 0;
+const STATE_RESOLVED: u8 = 1;
+const STATE_UNRESOLVED: u8 = 2;
+
+struct DependencyObservation {
+    observer: Box<dyn DependencyObserver>,
+    shape: DependencyShape,
+    disabled: AtomicBool,
+    last: AtomicU8,
+}
+
+impl DependencyObservation {
+    fn record(&self, resolved: bool) {
+        let state = if resolved { STATE_RESOLVED } else { STATE_UNRESOLVED };
+        let previous = self.last.swap(state, Ordering::SeqCst);
+        if previous != state && previous != STATE_UNKNOWN {
+            self.observer.on_transition(resolved);
+        }
+    }
+
+    fn refresh(&self) {
+        if self.disabled.load(Ordering::Relaxed) {
+            self.record(false);
+        } else if let Some(resolved) = self.shape.evaluate() {
+            self.record(resolved);
+        }
+    }
+}
+
 enum DependencyInner {
-    Flag(Arc<AtomicBool>),
+    Flag(Arc<DependencyFlag>),
     External(ExternalFn),
     LogicalAnd(Box<DependencyInner>, Box<DependencyInner>),
     LogicalOr(Box<DependencyInner>, Box<DependencyInner>),
@@ -19,7 +155,7 @@ enum DependencyInner {
 impl DependencyInner {
     fn is_resolved(&self) -> Pin<Box<dyn Future<Output = bool> + Send + '_>> {
         match self {
-            DependencyInner::Flag(flag) => Box::pin(std::future::ready(flag.load(Ordering::Relaxed))),
+            DependencyInner::Flag(flag) => Box::pin(std::future::ready(flag.is_resolved())),
             DependencyInner::External(func) => func(),
             DependencyInner::LogicalAnd(dep1, dep2) => {
                 Box::pin(async move { dep1.is_resolved().await && dep2.is_resolved().await })
@@ -36,68 +172,90 @@ impl DependencyInner {
 
 pub struct FrameDependency {
     inner: DependencyInner,
-    disabled: AtomicBool
+    disabled: AtomicBool,
+    observation: Option<Arc<DependencyObservation>>,
 }
 
-macro_rules! impl_monitor_based_dependency {
-    (($flag: ident, $countdown: ident, $payload: ident, $task: expr, $value: expr) -> $body: block) => {{
-        struct DependencyTaskMonitor(Arc<AtomicBool>, AtomicU16);
+// A countdown of the runs a monitor-based dependency waits for, ``counts`` filters the runs by whether they failed
+struct DependencyCountdown {
+    flag: Arc<DependencyFlag>,
+    remaining: u16,
+    counts: fn(bool) -> bool,
+}
+
+/*
+    Hooks are keyed by their type, so every monitor-based dependency on a Task shares a single monitor
+    rather than attaching its own (which would replace the monitor of the previous dependency)
+ */
+#[derive(Default)]
+struct DependencyTaskMonitor(parking_lot::Mutex<Vec<DependencyCountdown>>);
+
+#[async_trait]
+impl TaskHook<OnTaskEnd> for DependencyTaskMonitor {
+    async fn on_event(&self, _ctx: &TaskHookContext, payload: &<OnTaskEnd as TaskHookEvent>::Payload<'_>) {
+        let failed = payload.is_some();
+        let mut resolved = Vec::new();
 
-        #[async_trait]
-        impl TaskHook<OnTaskEnd> for DependencyTaskMonitor {
-            async fn on_event(&self, _ctx: &TaskHookContext, payload: &<OnTaskEnd as TaskHookEvent>::Payload<'_>) {
-                let $payload = payload;
-                let $flag = &self.0;
-                let $countdown = &self.1;
-                $body
+        self.0.lock().retain_mut(|countdown| {
+            if !(countdown.counts)(failed) {
+                return true;
             }
-        }
 
-        let flag = Arc::new(AtomicBool::new(false));
-        let monitor = DependencyTaskMonitor(flag.clone(), AtomicU16::new($value.get()));
-        $task.attach_hook(Arc::new(monitor)).await;
+            countdown.remaining -= 1;
+            if countdown.remaining > 0 {
+                return true;
+            }
 
-        FrameDependency {
-            inner: DependencyInner::Flag(flag),
-            disabled: AtomicBool::new(false)
+            resolved.push(countdown.flag.clone());
+            false
+        });
+
+        // Resolving notifies the observers, which shouldn't run while holding the lock
+        for flag in resolved {
+            flag.resolve();
         }
-    }};
+    }
 }
 
 impl FrameDependency {
-    pub async fn runs(task: &Task<impl TaskFrame>, value: NonZeroU16) -> FrameDependency {
-        impl_monitor_based_dependency!((flag, countdown, _payload, task, value) -> {
-            let res = countdown.fetch_sub(1, Ordering::Relaxed) - 1;
-            if res == 0 {
-                flag.store(true, Ordering::Relaxed);
-            }
-        })
+    fn new(inner: DependencyInner) -> Self {
+        FrameDependency {
+            inner,
+            disabled: AtomicBool::new(false),
+            observation: None,
+        }
     }
 
-    pub async fn successful_runs(task: &Task<impl TaskFrame>, value: NonZeroU16) -> FrameDependency {
-        impl_monitor_based_dependency!((flag, countdown, payload, task, value) -> {
-            if payload.is_some() {
-                return;
-            }
+    async fn monitor(task: &Task<impl TaskFrame>, value: NonZeroU16, counts: fn(bool) -> bool) -> FrameDependency {
+        let flag = Arc::new(DependencyFlag::default());
+        let countdown = DependencyCountdown {
+            flag: flag.clone(),
+            remaining: value.get(),
+            counts,
+        };
 
-            let res = countdown.fetch_sub(1, Ordering::Relaxed) - 1;
-            if res == 0 {
-                flag.store(true, Ordering::Relaxed);
+        match task.get_hook::<OnTaskEnd, DependencyTaskMonitor>() {
+            Some(monitor) => monitor.0.lock().push(countdown),
+            None => {
+                let monitor = DependencyTaskMonitor::default();
+                monitor.0.lock().push(countdown);
+                task.attach_hook(Arc::new(monitor)).await;
             }
-        })
+        }
+
+        FrameDependency::new(DependencyInner::Flag(flag))
     }
 
-    pub async fn failed_runs(task: &Task<impl TaskFrame>, value: NonZeroU16) -> FrameDependency {
-        impl_monitor_based_dependency!((flag, countdown, payload, task, value) -> {
-            if payload.is_none() {
-                return;
-            }
+    pub async fn runs(task: &Task<impl TaskFrame>, value: NonZeroU16) -> FrameDependency {
+        Self::monitor(task, value, |_| true).await
+    }
 
-            let res = countdown.fetch_sub(1, Ordering::Relaxed) - 1;
-            if res == 0 {
-                flag.store(true, Ordering::Relaxed);
-            }
-        })
+    pub async fn successful_runs(task: &Task<impl TaskFrame>, value: NonZeroU16) -> FrameDependency {
+        Self::monitor(task, value, |failed| !failed).await
+    }
+
+    pub async fn failed_runs(task: &Task<impl TaskFrame>, value: NonZeroU16) -> FrameDependency {
+        Self::monitor(task, value, |failed| failed).await
     }
 
     pub fn external<F: Future<Output = bool> + Send>(
@@ -105,23 +263,69 @@ impl FrameDependency {
     ) -> FrameDependency {
         let value = Arc::new(value);
 
-        FrameDependency {
-            inner: DependencyInner::External(Box::new(move || {
-                let value = Arc::clone(&value);
+        FrameDependency::new(DependencyInner::External(Box::new(move || {
+            let value = Arc::clone(&value);
 
-                Box::pin(async move { value().await })
-            })),
-            
-            disabled: AtomicBool::new(false),
+            Box::pin(async move { value().await })
+        })))
+    }
+
+    /// Attaches ``observer`` to the dependency, notifying it whenever the dependency as a whole transitions
+    /// between resolved and unresolved, independently of which frame (if any) evaluates it. Dependencies
+    /// without an observer carry no observation overhead, attaching another observer replaces the previous one.
+    ///
+    /// # Transitions
+    /// The state is tracked from the moment the observer is attached, the initial state isn't reported and
+    /// neither is a state which didn't change. Transitions are detected when:
+    /// - A task-based dependency (such as [`FrameDependency::runs`]) resolves itself automatically.
+    /// - The dependency is [disabled](FrameDependency::disable) (making it unresolved) or re-enabled.
+    /// - The dependency is evaluated via [`FrameDependency::is_resolved`] (e.g. by a [`DependencyTaskFrame`](crate::task::DependencyTaskFrame)).
+    ///
+    /// Dependencies involving [`FrameDependency::external`] can only be evaluated asynchronously, their state
+    /// is only known (and thus transitions only detected) once evaluated. Combining dependencies (via ``&``,
+    /// ``|`` and ``!``) drops the observers of the combined ones, observers belong on the final dependency.
+    pub fn with_observer(mut self, observer: impl DependencyObserver) -> Self {
+        let shape = DependencyShape::of(&self.inner);
+        let initial = match self.is_disabled() {
+            true => Some(false),
+            false => shape.evaluate(),
+        };
+
+        let observation = Arc::new(DependencyObservation {
+            observer: Box::new(observer),
+            shape,
+            disabled: AtomicBool::new(self.is_disabled()),
+            last: AtomicU8::new(match initial {
+                Some(true) => STATE_RESOLVED,
+                Some(false) => STATE_UNRESOLVED,
+                None => STATE_UNKNOWN,
+            }),
+        });
+
+        let mut flags = Vec::new();
+        observation.shape.flags(&mut flags);
+        for flag in flags {
+            flag.watch(&observation);
         }
+
+        self.observation = Some(observation);
+        self
     }
 
     pub fn disable(&self) {
         self.disabled.store(true, Ordering::Relaxed);
+        if let Some(observation) = &self.observation {
+            observation.disabled.store(true, Ordering::Relaxed);
+            observation.refresh();
+        }
     }
 
     pub fn enable(&self) {
         self.disabled.store(false, Ordering::Relaxed);
+        if let Some(observation) = &self.observation {
+            observation.disabled.store(false, Ordering::Relaxed);
+            observation.refresh();
+        }
     }
 
     pub fn is_disabled(&self) -> bool {
@@ -129,11 +333,12 @@ impl FrameDependency {
     }
 
     pub async fn is_resolved(&self) -> bool {
-        if self.is_disabled() {
-            return false;
+        let resolved = !self.is_disabled() && self.inner.is_resolved().await;
+        if let Some(observation) = &self.observation {
+            observation.record(resolved);
         }
 
-        self.inner.is_resolved().await
+        resolved
     }
 }
 
@@ -141,10 +346,7 @@ impl BitAnd for FrameDependency {
     type Output = FrameDependency;
 
     fn bitand(self, rhs: Self) -> Self::Output {
-        FrameDependency {
-            inner: DependencyInner::LogicalAnd(Box::new(self.inner), Box::new(rhs.inner)),
-            disabled: AtomicBool::new(false)
-        }
+        FrameDependency::new(DependencyInner::LogicalAnd(Box::new(self.inner), Box::new(rhs.inner)))
     }
 }
 
@@ -152,10 +354,7 @@ impl BitOr for FrameDependency {
     type Output = FrameDependency;
 
     fn bitor(self, rhs: Self) -> Self::Output {
-        FrameDependency {
-            inner: DependencyInner::LogicalOr(Box::new(self.inner), Box::new(rhs.inner)),
-            disabled: AtomicBool::new(false)
-        }
+        FrameDependency::new(DependencyInner::LogicalOr(Box::new(self.inner), Box::new(rhs.inner)))
     }
 }
 
@@ -163,9 +362,6 @@ impl Not for FrameDependency {
     type Output = FrameDependency;
 
     fn not(self) -> Self::Output {
-        FrameDependency {
-            inner: DependencyInner::LogicalNot(Box::new(self.inner)),
-            disabled: AtomicBool::new(false)
-        }
+        FrameDependency::new(DependencyInner::LogicalNot(Box::new(self.inner)))
     }
 }
\ No newline at end of file
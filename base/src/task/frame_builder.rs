@@ -4,8 +4,9 @@
 use crate::task::conditionframe::ConditionalFramePredicate;
 use crate::task::dependency::FrameDependency;
 use crate::task::retryframe::RetryBackoffStrategy;
-use crate::task::{ConditionalTaskFrame, ConstantBackoffStrategy, DefaultTimeoutError, DependencyTaskFrame, FallbackTaskFrame, NoOperationTaskFrame, RetriableTaskFrame, TaskFrame, TimeoutTaskFrame};
+use crate::task::{CollectionExecPolicy, CollectionTaskError, CollectionTaskFrame, ConditionalTaskFrame, ConstantBackoffStrategy, DefaultTimeoutError, DelayTaskFrame, DependencyTaskFrame, ErasedTaskFrame, FallbackTaskFrame, NoOperationTaskFrame, ParallelExecStrategy, RetriableTaskFrame, SelectFrameAccessor, SelectionExecStrategy, SequentialExecStrategy, TaskFrame, TimeoutTaskFrame};
 use std::num::NonZeroU32;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// [`TaskFrameBuilder`] is a composable builder for constructing [`TaskFrame`] workflows, it wraps
@@ -40,6 +41,11 @@ use std::time::Duration;
 /// - [`with_condition`](TaskFrameBuilder::with_condition) - Wraps with [`ConditionalTaskFrame`], only executing if the predicate is true (no-op otherwise).
 /// - [`with_fallback_condition`](TaskFrameBuilder::with_fallback_condition) - Wraps with [`ConditionalTaskFrame`], executing a fallback frame when the predicate is false.
 /// - [`with_dependency`](TaskFrameBuilder::with_dependency) - Wraps with [`DependencyTaskFrame`], waiting for a dependency to be resolved before executing.
+/// - [`with_delay`](TaskFrameBuilder::with_delay) - Wraps with [`DelayTaskFrame`], waiting for the given duration before executing.
+/// - [`with_sequential_after`](TaskFrameBuilder::with_sequential_after) - Groups into a sequential [`CollectionTaskFrame`], executing the given frames after it.
+/// - [`with_parallel`](TaskFrameBuilder::with_parallel) - Groups into a parallel [`CollectionTaskFrame`], executing the given frames alongside it.
+/// - [`with_parallel_policy`](TaskFrameBuilder::with_parallel_policy) - Groups into a parallel [`CollectionTaskFrame`] whose outcome is decided by the given policy.
+/// - [`with_select`](TaskFrameBuilder::with_select) - Groups into a selection [`CollectionTaskFrame`], executing only the frame picked by the accessor.
 /// - [`build`](TaskFrameBuilder::build) - Consumes the builder and returns the fully composed frame.
 ///
 /// # Constructor(s)
@@ -110,6 +116,8 @@ use std::time::Duration;
 /// - [`FallbackTaskFrame`] - The fallback wrapper frame.
 /// - [`ConditionalTaskFrame`] - The conditional execution wrapper frame.
 /// - [`DependencyTaskFrame`] - The dependency-gated wrapper frame.
+/// - [`DelayTaskFrame`] - The delayed execution wrapper frame.
+/// - [`CollectionTaskFrame`] - The frame grouping multiple frames under an execution strategy.
 /// - [`Task`](crate::task::Task) - The top-level struct combining a frame with a trigger.
 pub struct TaskFrameBuilder<T: TaskFrame>(T);
 
@@ -720,6 +728,304 @@ impl<T: TaskFrame> TaskFrameBuilder<T> {
         TaskFrameBuilder(dependent)
     }
 
+    /// Method wraps the inner [`TaskFrame`] in a [`DelayTaskFrame`] which idles for the specified
+    /// duration before executing the inner frame.
+    ///
+    /// The delay happens on every execution of the workflow and does not count as part of the inner
+    /// frame's execution (i.e. a [`TimeoutTaskFrame`] wrapped **inside** the delay won't be affected).
+    ///
+    /// # Arguments
+    /// ``delay`` is a type [`Duration`] parameter specifying how long to wait before executing the inner task.
+    ///
+    /// # Returns
+    /// A [`TaskFrameBuilder`] wrapping its inner workflow with a delay.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use chronographer::task::{TaskFrameBuilder, DelayTaskFrame};
+    ///
+    /// # use chronographer::task::{TaskFrame, TaskFrameContext};
+    /// #
+    /// # struct MyTaskFrame;
+    /// #
+    /// # impl TaskFrame for MyTaskFrame {
+    /// #     type Error = String;
+    /// #     type Args = ();
+    /// #
+    /// #     async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    ///
+    /// let task: DelayTaskFrame<MyTaskFrame> = TaskFrameBuilder::new(MyTaskFrame)
+    ///     .with_delay(Duration::from_secs(3)) // Wait 3 seconds before every execution
+    ///     .build();
+    /// ```
+    ///
+    /// # See Also
+    /// - [`TaskFrameBuilder`] - The main builder which the method is part of.
+    /// - [`DelayTaskFrame`] - The TaskFrame component which wraps the innermost TaskFrame.
+    /// - [`TaskFrame`] - The trait that ``frame`` must implement.
+    pub fn with_delay(self, delay: Duration) -> TaskFrameBuilder<DelayTaskFrame<T>> {
+        TaskFrameBuilder(DelayTaskFrame::new(self.0, delay))
+    }
+
+    /// Method places the inner [`TaskFrame`] at the start of a sequential [`CollectionTaskFrame`],
+    /// followed by the supplied TaskFrames which execute one after the other once it succeeds.
+    ///
+    /// The first failure (including the inner TaskFrame's) stops the sequence and is propagated
+    /// as a [`CollectionTaskError`](crate::task::CollectionTaskError) carrying the index of the failed TaskFrame, the inner TaskFrame
+    /// always sits at index ``0``.
+    ///
+    /// # Arguments
+    /// ``frames`` is the list of TaskFrames to execute (in order) after the inner TaskFrame.
+    ///
+    /// # Returns
+    /// A [`TaskFrameBuilder`] wrapping the sequential collection of TaskFrames.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use chronographer::task::{CollectionTaskFrame, SequentialExecStrategy, TaskFrameBuilder};
+    ///
+    /// # use chronographer::task::{TaskFrame, TaskFrameContext};
+    /// #
+    /// # struct MyTaskFrame;
+    /// #
+    /// # impl TaskFrame for MyTaskFrame {
+    /// #     type Error = String;
+    /// #     type Args = ();
+    /// #
+    /// #     async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// #
+    /// # struct CleanupFrame;
+    /// #
+    /// # impl TaskFrame for CleanupFrame {
+    /// #     type Error = String;
+    /// #     type Args = ();
+    /// #
+    /// #     async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    ///
+    /// let task: CollectionTaskFrame<SequentialExecStrategy> = TaskFrameBuilder::new(MyTaskFrame)
+    ///     .with_sequential_after(vec![Arc::new(CleanupFrame)]) // Runs "CleanupFrame" once "MyTaskFrame" succeeds
+    ///     .build();
+    /// ```
+    ///
+    /// # See Also
+    /// - [`TaskFrameBuilder`] - The main builder which the method is part of.
+    /// - [`CollectionTaskFrame`] - The TaskFrame component which groups the TaskFrames.
+    /// - [`SequentialExecStrategy`] - The strategy used for executing the TaskFrames.
+    pub fn with_sequential_after(
+        self,
+        frames: Vec<Arc<dyn ErasedTaskFrame<()>>>,
+    ) -> TaskFrameBuilder<CollectionTaskFrame<SequentialExecStrategy>>
+    where
+        T: TaskFrame<Args = ()>
+    {
+        TaskFrameBuilder(CollectionTaskFrame::sequential(self.chained_with(frames)))
+    }
+
+    /// Method places the inner [`TaskFrame`] inside a parallel [`CollectionTaskFrame`] alongside
+    /// the supplied TaskFrames, all of them execute concurrently.
+    ///
+    /// The collection uses the default [`ParallelExecStrategy`] policy, quitting on the first failure,
+    /// for a different policy use [`with_parallel_policy`](TaskFrameBuilder::with_parallel_policy).
+    /// The inner TaskFrame always sits at index ``0``.
+    ///
+    /// # Arguments
+    /// ``frames`` is the list of TaskFrames to execute concurrently with the inner TaskFrame.
+    ///
+    /// # Returns
+    /// A [`TaskFrameBuilder`] wrapping the parallel collection of TaskFrames.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use chronographer::task::{CollectionTaskFrame, ParallelExecStrategy, TaskFrameBuilder};
+    ///
+    /// # use chronographer::task::{TaskFrame, TaskFrameContext};
+    /// #
+    /// # struct MyTaskFrame;
+    /// #
+    /// # impl TaskFrame for MyTaskFrame {
+    /// #     type Error = String;
+    /// #     type Args = ();
+    /// #
+    /// #     async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// #
+    /// # struct MetricsFrame;
+    /// #
+    /// # impl TaskFrame for MetricsFrame {
+    /// #     type Error = String;
+    /// #     type Args = ();
+    /// #
+    /// #     async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    ///
+    /// let task: CollectionTaskFrame<ParallelExecStrategy> = TaskFrameBuilder::new(MyTaskFrame)
+    ///     .with_parallel(vec![Arc::new(MetricsFrame)]) // Runs "MyTaskFrame" and "MetricsFrame" together
+    ///     .build();
+    /// ```
+    ///
+    /// # See Also
+    /// - [`TaskFrameBuilder`] - The main builder which the method is part of.
+    /// - [`CollectionTaskFrame`] - The TaskFrame component which groups the TaskFrames.
+    /// - [`ParallelExecStrategy`] - The strategy used for executing the TaskFrames.
+    pub fn with_parallel(
+        self,
+        frames: Vec<Arc<dyn ErasedTaskFrame<()>>>,
+    ) -> TaskFrameBuilder<CollectionTaskFrame<ParallelExecStrategy>>
+    where
+        T: TaskFrame<Args = ()>
+    {
+        TaskFrameBuilder(CollectionTaskFrame::new(
+            self.chained_with(frames),
+            ParallelExecStrategy::default(),
+        ))
+    }
+
+    /// Method places the inner [`TaskFrame`] inside a parallel [`CollectionTaskFrame`] alongside
+    /// the supplied TaskFrames just as [`with_parallel`](TaskFrameBuilder::with_parallel) does, but decides
+    /// the outcome of the collection via ``policy`` (e.g. [`GroupedTaskFramesAggregate`](crate::task::GroupedTaskFramesAggregate) to gather the
+    /// errors of every failed TaskFrame). The inner TaskFrame always sits at index ``0``.
+    ///
+    /// # Arguments
+    /// ``frames`` is the list of TaskFrames to execute concurrently with the inner TaskFrame, while
+    /// ``policy`` is the [`CollectionExecPolicy`] the collection runs with.
+    ///
+    /// # Returns
+    /// A [`TaskFrameBuilder`] wrapping the parallel collection of TaskFrames.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use chronographer::task::{CollectionTaskFrame, GroupedTaskFramesAggregate, ParallelExecStrategy, TaskFrameBuilder};
+    ///
+    /// # use chronographer::task::{TaskFrame, TaskFrameContext};
+    /// #
+    /// # struct MyTaskFrame;
+    /// #
+    /// # impl TaskFrame for MyTaskFrame {
+    /// #     type Error = String;
+    /// #     type Args = ();
+    /// #     type Workflow = Self;
+    /// #
+    /// #     async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// #
+    /// # struct MetricsFrame;
+    /// #
+    /// # impl TaskFrame for MetricsFrame {
+    /// #     type Error = String;
+    /// #     type Args = ();
+    /// #     type Workflow = Self;
+    /// #
+    /// #     async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    ///
+    /// let task: CollectionTaskFrame<ParallelExecStrategy<GroupedTaskFramesAggregate>> = TaskFrameBuilder::new(MyTaskFrame)
+    ///     .with_parallel_policy(vec![Arc::new(MetricsFrame)], GroupedTaskFramesAggregate) // Fails with every error at once
+    ///     .build();
+    /// ```
+    ///
+    /// # See Also
+    /// - [`TaskFrameBuilder`] - The main builder which the method is part of.
+    /// - [`CollectionTaskFrame`] - The TaskFrame component which groups the TaskFrames.
+    /// - [`CollectionExecPolicy`] - The trait that ``policy`` must implement.
+    pub fn with_parallel_policy<P>(
+        self,
+        frames: Vec<Arc<dyn ErasedTaskFrame<()>>>,
+        policy: P,
+    ) -> TaskFrameBuilder<CollectionTaskFrame<ParallelExecStrategy<P>>>
+    where
+        T: TaskFrame<Args = ()>,
+        P: CollectionExecPolicy<CollectionTaskError> + Send + Sync + 'static,
+    {
+        TaskFrameBuilder(CollectionTaskFrame::parallel(self.chained_with(frames), policy))
+    }
+
+    /// Method places the inner [`TaskFrame`] inside a selection-based [`CollectionTaskFrame`] alongside
+    /// the supplied TaskFrames, on every execution only the TaskFrame picked by ``accessor`` runs.
+    ///
+    /// The inner TaskFrame sits at index ``0`` while the supplied TaskFrames follow from index ``1``
+    /// onwards. An out-of-bounds index fails the execution with a [`CollectionTaskError`](crate::task::CollectionTaskError).
+    ///
+    /// # Arguments
+    /// ``frames`` is the list of TaskFrames which can be selected besides the inner TaskFrame, while
+    /// ``accessor`` is the [`SelectFrameAccessor`] which computes the index of the TaskFrame to execute.
+    ///
+    /// # Returns
+    /// A [`TaskFrameBuilder`] wrapping the selection collection of TaskFrames.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use chronographer::task::{RestrictTaskFrameContext, TaskFrameBuilder};
+    ///
+    /// # use chronographer::task::{TaskFrame, TaskFrameContext};
+    /// #
+    /// # struct MyTaskFrame;
+    /// #
+    /// # impl TaskFrame for MyTaskFrame {
+    /// #     type Error = String;
+    /// #     type Args = ();
+    /// #
+    /// #     async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// #
+    /// # struct CanaryFrame;
+    /// #
+    /// # impl TaskFrame for CanaryFrame {
+    /// #     type Error = String;
+    /// #     type Args = ();
+    /// #
+    /// #     async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    ///
+    /// let task = TaskFrameBuilder::new(MyTaskFrame)
+    ///     .with_select(
+    ///         vec![Arc::new(CanaryFrame)],
+    ///         |_ctx: &RestrictTaskFrameContext| async { 1 } // Always picks "CanaryFrame"
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    /// # See Also
+    /// - [`TaskFrameBuilder`] - The main builder which the method is part of.
+    /// - [`CollectionTaskFrame`] - The TaskFrame component which groups the TaskFrames.
+    /// - [`SelectionExecStrategy`] - The strategy used for executing the TaskFrames.
+    /// - [`SelectFrameAccessor`] - The trait that ``accessor`` must implement.
+    pub fn with_select<S: SelectFrameAccessor>(
+        self,
+        frames: Vec<Arc<dyn ErasedTaskFrame<()>>>,
+        accessor: S,
+    ) -> TaskFrameBuilder<CollectionTaskFrame<SelectionExecStrategy<S>>>
+    where
+        T: TaskFrame<Args = ()>
+    {
+        TaskFrameBuilder(CollectionTaskFrame::selection(self.chained_with(frames), accessor))
+    }
+
     /// Method consumes the builder and returns the underlying, fully-composed [`TaskFrame`].
     ///
     /// This method serves as the final step in the builder chain. After stacking various behaviors
@@ -764,4 +1070,17 @@ impl<T: TaskFrame> TaskFrameBuilder<T> {
     pub fn build(self) -> T {
         self.0
     }
+
+    fn chained_with(
+        self,
+        frames: Vec<Arc<dyn ErasedTaskFrame<()>>>,
+    ) -> Vec<Arc<dyn ErasedTaskFrame<()>>>
+    where
+        T: TaskFrame<Args = ()>
+    {
+        let mut chain: Vec<Arc<dyn ErasedTaskFrame<()>>> = Vec::with_capacity(frames.len() + 1);
+        chain.push(Arc::new(self.0));
+        chain.extend(frames);
+        chain
+    }
 }
@@ -1,40 +1,122 @@
+pub mod auditframe; // skipcq: RS-D1001
+
+pub mod blockingframe; // skipcq: RS-D1001
+
+pub mod chunkedframe; // skipcq: RS-D1001
+
+pub mod coalesceframe; // skipcq: RS-D1001
+
 pub mod conditionframe; // skipcq: RS-D1001
 
 pub mod dependencyframe; // skipcq: RS-D1001
 
 pub mod fallbackframe; // skipcq: RS-D1001
 
+pub mod finalizerframe; // skipcq: RS-D1001
+
+pub mod keyedframe; // skipcq: RS-D1001
+
+pub mod healthgateframe; // skipcq: RS-D1001
+
+pub mod killswitchframe; // skipcq: RS-D1001
+
+pub mod leaderframe; // skipcq: RS-D1001
+
 pub mod noopframe; // skipcq: RS-D1001
 
+pub mod orderedframe; // skipcq: RS-D1001
+
+pub mod preconditionframe; // skipcq: RS-D1001
+
+pub mod resilienceframe; // skipcq: RS-D1001
+
 pub mod collectionframe; // skipcq: RS-D1001
 
 pub mod retryframe; // skipcq: RS-D1001
 
+pub mod rolloutframe; // skipcq: RS-D1001
+
 pub mod timeoutframe; // skipcq: RS-D1001
 
+pub mod traceframe; // skipcq: RS-D1001
+
+pub mod deadlineframe; // skipcq: RS-D1001
+
 pub mod delayframe; // skipcq: RS-D1001
 
 pub mod dynamicframe; // skipcq: RS-D1001
 
 pub mod thresholdframe; // skipcq: RS-D1001
 
+pub mod sheddingframe; // skipcq: RS-D1001
+
+pub mod switchframe; // skipcq: RS-D1001
+
+pub mod tagbudgetframe; // skipcq: RS-D1001
+
+pub use auditframe::*;
+pub use blockingframe::*;
+pub use chunkedframe::*;
+pub use coalesceframe::*;
 pub use collectionframe::*;
 pub use conditionframe::*;
+pub use deadlineframe::*;
 pub use delayframe::*;
 pub use dependencyframe::*;
 pub use fallbackframe::*;
+pub use finalizerframe::*;
+pub use healthgateframe::*;
+pub use keyedframe::*;
+pub use killswitchframe::*;
+pub use leaderframe::*;
 pub use noopframe::*;
+pub use orderedframe::*;
+pub use preconditionframe::*;
+pub use resilienceframe::*;
 pub use retryframe::*;
+pub use rolloutframe::*;
+pub use sheddingframe::*;
+pub use switchframe::*;
+pub use tagbudgetframe::*;
 pub use thresholdframe::*;
 pub use timeoutframe::*;
+pub use traceframe::*;
 
 use crate::errors::TaskError;
-use crate::task::{ErasedTask, NonObserverTaskHook, Sealed, TaskHook, TaskHookContext, TaskHookEvent, TaskHookLayer, TASKHOOK_REGISTRY};
+use crate::task::{ErasedTask, NonObserverTaskHook, Sealed, TaskHook, TaskHookContext, TaskHookEvent, TaskHookLayer, INSTANCE_ID, TASKHOOK_REGISTRY, TASK_INPUTS, TASK_METADATA, TaskMetadata};
 use async_trait::async_trait;
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 use std::sync::Arc;
+use std::sync::atomic::Ordering;
 use crate::scheduler::utils::{SchedulerHandleInstructions, SchedulerHandle};
 
+/*
+    The deadline is carried as a task-local rather than inside the context, so it follows the
+    execution down the frame chain (but not into spawned tasks) while the context stays Copy
+ */
+tokio::task_local! {
+    static FRAME_DEADLINE: tokio::time::Instant;
+    static FRAME_TRACE: TraceContext;
+}
+
+/// Runs ``future`` with ``deadline`` as the deadline visible to the frames it executes (see
+/// [`RestrictTaskFrameContext::deadline`]), an earlier deadline set by an outer frame is kept.
+pub(crate) async fn with_deadline<F: Future>(deadline: tokio::time::Instant, future: F) -> F::Output {
+    let deadline = FRAME_DEADLINE
+        .try_with(|outer| (*outer).min(deadline))
+        .unwrap_or(deadline);
+
+    FRAME_DEADLINE.scope(deadline, future).await
+}
+
+/// Runs ``future`` with ``trace`` as the trace context visible to the frames it executes (see
+/// [`RestrictTaskFrameContext::trace_context`]), replacing the one set by an outer frame.
+pub(crate) async fn with_trace_context<F: Future>(trace: TraceContext, future: F) -> F::Output {
+    FRAME_TRACE.scope(trace, future).await
+}
+
 #[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct RestrictTaskFrameContext(usize);
@@ -58,9 +140,55 @@ impl TaskFrameContext {
     instruct_method!(instruct_halt, Halt);
     instruct_method!(instruct_execute, Execute);
 
+    /// Marks the Task owning this TaskFrame for removal from its [Scheduler](crate::scheduler::Scheduler),
+    /// for when the TaskFrame determines it should never run again (e.g. the resource it manages was deleted).
+    ///
+    /// Cancellation takes effect **after** the current execution, not mid-run. The current execution
+    /// (including whatever follows this call) runs to completion, the Task is simply never rescheduled
+    /// nor dispatched again afterward.
+    ///
+    /// # Panics
+    /// Like the other ``instruct_*`` methods, this panics when the Task isn't hosted on a Scheduler.
+    pub fn cancel_self(&self) {
+        self.instruct_block();
+    }
+
     pub fn as_restricted(&self) -> &RestrictTaskFrameContext {
         &self.0
     }
+
+    /// Executes ``frame`` in a sandbox, with a fresh context of its own instead of this one (as is the case
+    /// when a wrapper frame runs the frames it wraps), returning its result. Meant for running plugins or
+    /// otherwise untrusted frames which shouldn't observe nor mutate the state of the Task hosting them.
+    ///
+    /// # Isolation Boundary
+    /// The sandboxed frame sees a context with a fresh instance id, which the per-Task state is keyed by:
+    /// - **Hooks**, it starts with none attached, the hooks of the parent are neither visible to it nor emitted
+    ///   to by it. Hooks it attaches stay in the sandbox and are dropped once the execution ends (even when
+    ///   cancelled midway), as is any shared state created via [`RestrictTaskFrameContext::shared`].
+    /// - **Metadata and input**, it sees empty [`TaskMetadata`] and no input.
+    /// - **Scheduler**, it has no access to the Scheduler hosting the parent, the ``instruct_*`` methods and
+    ///   [`TaskFrameContext::cancel_self`] panic like they do for a Task which isn't hosted on one.
+    ///
+    /// The execution itself isn't isolated, it runs on the same tokio task as the parent. As such the
+    /// [deadline](RestrictTaskFrameContext::deadline) and [trace context](RestrictTaskFrameContext::trace_context)
+    /// set by outer frames still apply (read-only), and the arguments are passed by reference. Global state
+    /// (e.g. [`KillSwitches`] or [`TagBudgets`]) is shared as with any other frame.
+    pub async fn sandbox<T: TaskFrame>(&self, frame: &T, args: &T::Args) -> Result<(), T::Error> {
+        let sandbox = SandboxInstance(INSTANCE_ID.fetch_add(1, Ordering::Relaxed));
+        let ctx = TaskFrameContext(RestrictTaskFrameContext(sandbox.0));
+
+        frame.execute(&ctx, args).await
+    }
+}
+
+// Drops whatever the sandboxed frame attached once the sandbox ends, including when it's cancelled midway
+struct SandboxInstance(usize);
+
+impl Drop for SandboxInstance {
+    fn drop(&mut self) {
+        TASKHOOK_REGISTRY.clear_instance(self.0);
+    }
 }
 
 impl RestrictTaskFrameContext {
@@ -68,6 +196,41 @@ impl RestrictTaskFrameContext {
         Self(task.instance_id)
     }
 
+    pub fn instance_id(&self) -> usize {
+        self.0
+    }
+
+    /// Retrieves the input supplied to the Task via [`Task::with_input`](crate::task::Task::with_input),
+    /// returning ``None`` when the Task has no input or ``I`` isn't the exact type it was supplied with.
+    pub fn input<I: Send + Sync + 'static>(&self) -> Option<Arc<I>> {
+        TASK_INPUTS.get(&self.0)?.value().clone().downcast::<I>().ok()
+    }
+
+    /// Retrieves the description and owner supplied to the Task (see [`Task::with_description`](crate::task::Task::with_description)),
+    /// both are empty when none were supplied.
+    pub fn metadata(&self) -> Arc<TaskMetadata> {
+        TASK_METADATA
+            .get(&self.0)
+            .map(|entry| entry.value().clone())
+            .unwrap_or_default()
+    }
+
+    /// The deadline the current execution has to finish by, as set by an outer frame bounding it (such as
+    /// [`TimeoutTaskFrame`]), ``None`` when no outer frame bounds it.
+    ///
+    /// Deadlines propagate **downward**, outer frames set it for everything they execute and inner frames
+    /// read it (e.g. [`RetriableTaskFrame`] with ``respect_deadline`` stops retrying once it can't finish
+    /// in time). When nested, the earliest deadline wins. It isn't carried into tasks spawned by a frame.
+    pub fn deadline(&self) -> Option<tokio::time::Instant> {
+        FRAME_DEADLINE.try_with(|deadline| *deadline).ok()
+    }
+
+    /// The trace context of the current execution, as set by an outer [`TraceContextTaskFrame`], ``None``
+    /// when no outer frame sets it. Like the deadline, it isn't carried into tasks spawned by a frame.
+    pub fn trace_context(&self) -> Option<TraceContext> {
+        FRAME_TRACE.try_with(|trace| *trace).ok()
+    }
+
     pub async fn emit<EV: TaskHookEvent>(&self, payload: &EV::Payload<'_>) {
         let ctx = TaskHookContext(self.0);
 
@@ -119,12 +282,74 @@ impl Deref for TaskFrameContext {
     }
 }
 
+/// [`FrameNode`] is a purely introspective description of a TaskFrame chain, each node holds
+/// the name of a TaskFrame alongside the descriptions of the TaskFrames it wraps.
+///
+/// It is produced via [`TaskFrame::describe`] and renders (via [`Display`]) as ``Retry(Timeout(MyTaskFrame))``.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameNode {
+    name: Cow<'static, str>,
+    children: Vec<FrameNode>,
+}
+
+impl FrameNode {
+    pub fn new(name: impl Into<Cow<'static, str>>, children: Vec<FrameNode>) -> Self {
+        Self {
+            name: name.into(),
+            children,
+        }
+    }
+
+    pub fn leaf(name: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(name, Vec::new())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn children(&self) -> &[FrameNode] {
+        &self.children
+    }
+}
+
+impl Display for FrameNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)?;
+        if self.children.is_empty() {
+            return Ok(());
+        }
+
+        f.write_str("(")?;
+        for (idx, child) in self.children.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "{child}")?;
+        }
+        f.write_str(")")
+    }
+}
+
+fn short_type_name<T: ?Sized>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
 pub trait TaskFrame: 'static + Send + Sync + Sized {
     type Error: TaskError;
     type Args: Send + Sync + 'static;
     type Workflow: TaskFrame;
 
     fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> impl Future<Output = Result<(), Self::Error>> + Send;
+
+    /// Describes the structure of the TaskFrame chain for tooling / visualization purposes, it
+    /// has no effect on execution. Defaults to a leaf node named after the type.
+    fn describe(&self) -> FrameNode {
+        FrameNode::leaf(short_type_name::<Self>())
+    }
 }
 
 #[async_trait]
@@ -147,6 +372,7 @@ impl<T: TaskFrame<Error: Into<T::Error>>> DynTaskFrame<T::Error, T::Args> for T
 #[async_trait]
 pub trait ErasedTaskFrame<Args: Send + Sync + 'static>: 'static + Send + Sync {
     async fn erased_execute(&self, ctx: &TaskFrameContext, args: &Args) -> Result<(), Box<dyn TaskError>>;
+    fn erased_describe(&self) -> FrameNode;
 }
 
 #[async_trait]
@@ -156,6 +382,10 @@ impl<T: TaskFrame<Error: Into<T::Error>>> ErasedTaskFrame<T::Args> for T {
             .await
             .map_err(|x| Box::new(x) as Box<dyn TaskError>)
     }
+
+    fn erased_describe(&self) -> FrameNode {
+        self.describe()
+    }
 }
 
 impl Sealed for TaskFrameContext {}
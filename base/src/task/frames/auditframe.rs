@@ -0,0 +1,139 @@
+use crate::task::{FrameNode, TaskFrame, TaskFrameContext};
+use async_trait::async_trait;
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+/// The outcome of a single execution recorded by [`AuditTaskFrame`], failures carry the
+/// ``Debug`` representation of the error the wrapped TaskFrame returned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditOutcome {
+    Success,
+    Failure(String),
+}
+
+/// A single immutable entry of the audit log, describing which Task ran (via its instance id and
+/// the label of the [`AuditTaskFrame`]), when it started / ended and how it went.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditRecord {
+    pub task_id: usize,
+    pub label: Cow<'static, str>,
+    pub started_at: SystemTime,
+    pub ended_at: SystemTime,
+    pub outcome: AuditOutcome,
+}
+
+/// [`AuditSink`] is the destination [`AuditTaskFrame`] appends its [`AuditRecord`] to, the
+/// default is [`MemoryAuditSink`] but it can be implemented to persist records elsewhere (a file,
+/// a database... etc.).
+#[async_trait]
+pub trait AuditSink: Send + Sync + 'static {
+    async fn append(&self, record: AuditRecord);
+}
+
+/// An in-memory [`AuditSink`] acting as a ring buffer, once its capacity is reached the
+/// oldest [`AuditRecord`] is evicted to make room for the newest.
+pub struct MemoryAuditSink {
+    capacity: NonZeroUsize,
+    records: parking_lot::Mutex<VecDeque<AuditRecord>>,
+}
+
+impl MemoryAuditSink {
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            capacity,
+            records: parking_lot::Mutex::new(VecDeque::with_capacity(capacity.get())),
+        }
+    }
+
+    pub fn records(&self) -> Vec<AuditRecord> {
+        self.records.lock().iter().cloned().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.lock().is_empty()
+    }
+}
+
+impl Default for MemoryAuditSink {
+    fn default() -> Self {
+        Self::new(NonZeroUsize::new(1024).unwrap())
+    }
+}
+
+#[async_trait]
+impl AuditSink for MemoryAuditSink {
+    async fn append(&self, record: AuditRecord) {
+        let mut records = self.records.lock();
+        if records.len() == self.capacity.get() {
+            records.pop_front();
+        }
+
+        records.push_back(record);
+    }
+}
+
+pub struct AuditTaskFrame<T: TaskFrame, S: AuditSink = MemoryAuditSink> {
+    frame: T,
+    label: Cow<'static, str>,
+    sink: Arc<S>,
+}
+
+impl<T: TaskFrame> AuditTaskFrame<T> {
+    pub fn new(frame: T, label: impl Into<Cow<'static, str>>) -> Self {
+        Self::new_with(frame, label, Arc::new(MemoryAuditSink::default()))
+    }
+}
+
+impl<T: TaskFrame, S: AuditSink> AuditTaskFrame<T, S> {
+    pub fn new_with(frame: T, label: impl Into<Cow<'static, str>>, sink: Arc<S>) -> Self {
+        Self {
+            frame,
+            label: label.into(),
+            sink,
+        }
+    }
+
+    pub fn sink(&self) -> &Arc<S> {
+        &self.sink
+    }
+}
+
+impl<T: TaskFrame, S: AuditSink> TaskFrame for AuditTaskFrame<T, S> {
+    type Error = T::Error;
+    type Args = T::Args;
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
+        let started_at = SystemTime::now();
+        let result = self.frame.execute(ctx, args).await;
+        let ended_at = SystemTime::now();
+
+        let outcome = match &result {
+            Ok(()) => AuditOutcome::Success,
+            Err(err) => AuditOutcome::Failure(format!("{err:?}")),
+        };
+
+        self.sink
+            .append(AuditRecord {
+                task_id: ctx.instance_id(),
+                label: self.label.clone(),
+                started_at,
+                ended_at,
+                outcome,
+            })
+            .await;
+
+        result
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("Audit", vec![self.frame.describe()])
+    }
+}
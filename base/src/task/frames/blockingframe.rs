@@ -0,0 +1,64 @@
+use crate::errors::{BlockingTaskFrameError, ChronographerErrors, TaskError};
+use crate::task::{FrameNode, TaskFrame, TaskFrameContext};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// [`BlockingTaskFrame`] runs a synchronous closure on the blocking thread pool of tokio (via
+/// [`tokio::task::spawn_blocking`]), for CPU-bound or blocking work which would otherwise stall every Task
+/// sharing an async worker thread. Errors of the closure come back as [`BlockingTaskFrameError::Inner`]
+/// and its panics as [`BlockingTaskFrameError::Panicked`]. Dropping the execution (e.g. on a timeout) does
+/// **not** stop the closure, it keeps running on its thread until it returns.
+///
+/// ```
+/// # use chronographer::task::BlockingTaskFrame;
+/// let frame = BlockingTaskFrame::new(|| {
+///     let archive = std::fs::read("archive.tar")?;
+///     println!("{} bytes", archive.len());
+///     Ok::<_, std::io::Error>(())
+/// });
+/// ```
+pub struct BlockingTaskFrame<F, E>(Arc<F>, PhantomData<E>);
+
+impl<F, E> BlockingTaskFrame<F, E>
+where
+    F: Fn() -> Result<(), E> + Send + Sync + 'static,
+    E: TaskError,
+{
+    pub fn new(func: F) -> Self {
+        Self(Arc::new(func), PhantomData)
+    }
+}
+
+impl<F, E> TaskFrame for BlockingTaskFrame<F, E>
+where
+    F: Fn() -> Result<(), E> + Send + Sync + 'static,
+    E: TaskError,
+{
+    type Error = BlockingTaskFrameError<E>;
+    type Args = ();
+    type Workflow = Self;
+
+    async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+        let func = self.0.clone();
+        match tokio::task::spawn_blocking(move || func()).await {
+            Ok(result) => result.map_err(BlockingTaskFrameError::Inner),
+
+            Err(err) if err.is_panic() => {
+                let payload = err.into_panic();
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|msg| msg.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "<non-string panic payload>".to_string());
+
+                Err(BlockingTaskFrameError::Panicked(message))
+            }
+
+            Err(_) => Err(ChronographerErrors::Cancelled.into()),
+        }
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::leaf("Blocking")
+    }
+}
@@ -0,0 +1,99 @@
+use crate::errors::TaskError;
+use crate::scheduler::utils::SchedulerHandle;
+use crate::task::{FrameNode, TaskFrame, TaskFrameContext};
+
+/// What a chunk processor of [`ChunkedTaskFrame`] reports after processing a single chunk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChunkOutcome<P> {
+    /// More work remains, the next chunk resumes from the enclosed progress.
+    More(P),
+
+    /// The job is complete, the next job starts over from the default progress.
+    Done,
+}
+
+/// [`ChunkedTaskFrame`] processes a long job one bounded chunk per execution, then has the Task fire again
+/// right away for the remainder (when hosted on a [Scheduler](crate::scheduler::Scheduler)), so a batch job
+/// yields between chunks instead of monopolizing a worker. The progress ``P`` handed to the processor is kept
+/// in memory, it stays put when a chunk fails and resets to its default once the processor reports
+/// [`ChunkOutcome::Done`]. To survive restarts, persist [`ChunkedTaskFrame::progress`] and construct the frame
+/// again via [`ChunkedTaskFrame::resume`].
+///
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::{ChunkOutcome, ChunkedTaskFrame};
+/// # async fn archive_rows(offset: usize, limit: usize) -> Result<usize, String> { Ok(limit.min(2500 - offset)) }
+/// let frame = ChunkedTaskFrame::new(|_ctx: &TaskFrameContext, offset: usize| async move {
+///     let archived = archive_rows(offset, 1000).await?;
+///     Ok::<_, String>(match archived {
+///         1000 => ChunkOutcome::More(offset + 1000),
+///         _ => ChunkOutcome::Done,
+///     })
+/// });
+/// assert_eq!(frame.progress(), 0);
+/// ```
+pub struct ChunkedTaskFrame<T, P> {
+    processor: T,
+    progress: parking_lot::Mutex<P>,
+    gate: tokio::sync::Mutex<()>,
+}
+
+impl<T, F, E, P> ChunkedTaskFrame<T, P>
+where
+    T: (Fn(&TaskFrameContext, P) -> F) + Send + Sync + 'static,
+    F: Future<Output = Result<ChunkOutcome<P>, E>> + Send + 'static,
+    E: TaskError,
+    P: Default + Clone + Send + Sync + 'static,
+{
+    pub fn new(processor: T) -> Self {
+        Self::resume(processor, P::default())
+    }
+
+    /// Constructs a [`ChunkedTaskFrame`] which picks up from previously persisted ``progress``.
+    pub fn resume(processor: T, progress: P) -> Self {
+        Self {
+            processor,
+            progress: parking_lot::Mutex::new(progress),
+            gate: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// The progress the next chunk resumes from.
+    pub fn progress(&self) -> P {
+        self.progress.lock().clone()
+    }
+}
+
+impl<T, F, E, P> TaskFrame for ChunkedTaskFrame<T, P>
+where
+    T: (Fn(&TaskFrameContext, P) -> F) + Send + Sync + 'static,
+    F: Future<Output = Result<ChunkOutcome<P>, E>> + Send + 'static,
+    E: TaskError,
+    P: Default + Clone + Send + Sync + 'static,
+{
+    type Error = E;
+    type Args = ();
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+        let _gate = self.gate.lock().await;
+        let progress = self.progress();
+
+        match (self.processor)(ctx, progress).await? {
+            ChunkOutcome::More(next) => {
+                *self.progress.lock() = next;
+                if ctx.get_hook::<(), SchedulerHandle>().is_some() {
+                    ctx.instruct_execute();
+                }
+            }
+
+            ChunkOutcome::Done => *self.progress.lock() = P::default(),
+        }
+
+        Ok(())
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::leaf("Chunked")
+    }
+}
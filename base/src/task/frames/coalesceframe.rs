@@ -0,0 +1,123 @@
+use crate::task::{FrameNode, TaskFrame, TaskFrameContext, TaskHookEvent};
+use crate::utils::macros::define_event;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+
+// Carries the number of suppressed failures alongside the [`Debug`](std::fmt::Debug) representation of their error.
+define_event!(OnErrorBurst, (usize, Arc<str>));
+
+// The error currently repeating, alongside the failures swallowed since it last propagated
+struct ErrorStreak {
+    signature: Arc<str>,
+    suppressed: usize,
+    propagated_at: Instant,
+}
+
+/// [`CoalesceErrorsTaskFrame`] throttles repeated identical failures of its frame (errors are the same when
+/// their [`Debug`](std::fmt::Debug) representations are), so a Task flapping on one error doesn't flood the logs.
+/// A repeat within ``interval`` of the error last propagating is **suppressed** and the execution succeeds, the
+/// suppressed failures are reported through [`OnErrorBurst`] once the interval elapses, a different error comes
+/// up or the frame succeeds again. Since suppressed failures look like successes to everything observing the
+/// outcome (retries, failover, hooks), only wrap frames whose repeated failures are safe to swallow.
+///
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::CoalesceErrorsTaskFrame;
+/// # use std::time::Duration;
+/// let poll = DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async {
+///     Err::<(), _>("upstream is down".to_owned())
+/// });
+///
+/// // Propagates the same error at most once a minute, reporting how often it repeated in between
+/// let frame = CoalesceErrorsTaskFrame::new(poll, Duration::from_secs(60));
+/// assert_eq!(frame.suppressed(), 0);
+/// ```
+pub struct CoalesceErrorsTaskFrame<T: TaskFrame> {
+    frame: T,
+    interval: Duration,
+    streak: parking_lot::Mutex<Option<ErrorStreak>>,
+}
+
+impl<T: TaskFrame> CoalesceErrorsTaskFrame<T> {
+    /// Constructs a [`CoalesceErrorsTaskFrame`] propagating the same error of ``frame`` at most once per ``interval``.
+    pub fn new(frame: T, interval: Duration) -> Self {
+        Self {
+            frame,
+            interval,
+            streak: parking_lot::Mutex::new(None),
+        }
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// The number of failures suppressed since the repeating error last propagated.
+    pub fn suppressed(&self) -> usize {
+        self.streak
+            .lock()
+            .as_ref()
+            .map_or(0, |streak| streak.suppressed)
+    }
+}
+
+impl<T: TaskFrame> TaskFrame for CoalesceErrorsTaskFrame<T> {
+    type Error = T::Error;
+    type Args = T::Args;
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
+        let err = match self.frame.execute(ctx, args).await {
+            Ok(()) => {
+                let streak = self.streak.lock().take();
+                if let Some(streak) = streak.filter(|streak| streak.suppressed > 0) {
+                    ctx.emit::<OnErrorBurst>(&(streak.suppressed, streak.signature)).await;
+                }
+
+                return Ok(());
+            }
+
+            Err(err) => err,
+        };
+
+        let signature: Arc<str> = format!("{err:?}").into();
+        let now = Instant::now();
+
+        // The burst is taken out of the streak, so the lock is released before emitting it
+        let burst = {
+            let mut streak = self.streak.lock();
+            match streak.as_mut() {
+                Some(current) if current.signature == signature => {
+                    if now.duration_since(current.propagated_at) < self.interval {
+                        current.suppressed += 1;
+                        return Ok(());
+                    }
+
+                    current.propagated_at = now;
+                    (std::mem::take(&mut current.suppressed), signature)
+                }
+
+                _ => {
+                    let previous = streak.replace(ErrorStreak {
+                        signature,
+                        suppressed: 0,
+                        propagated_at: now,
+                    });
+
+                    previous.map_or((0, Arc::from("")), |previous| (previous.suppressed, previous.signature))
+                }
+            }
+        };
+
+        if burst.0 > 0 {
+            ctx.emit::<OnErrorBurst>(&burst).await;
+        }
+
+        Err(err)
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("CoalesceErrors", vec![self.frame.describe()])
+    }
+}
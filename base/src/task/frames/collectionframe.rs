@@ -1,11 +1,16 @@
 use crate::task::TaskHookEvent;
-use crate::errors::{TaskError, TaskSelectionIndexOutOfBounds};
-use crate::task::{ErasedTaskFrame, RestrictTaskFrameContext, TaskFrame, TaskFrameContext};
+use crate::errors::{
+    ChronographerErrors, QuorumUnreachable, SelectionWeightsAllZero, TaskError, TaskSelectionIndexOutOfBounds,
+};
+use crate::task::{ErasedTaskFrame, FrameNode, RestrictTaskFrameContext, TaskFrame, TaskFrameContext};
 use crate::utils::macros::{define_event, define_event_group};
+use crate::utils::{RandomSource, ThreadRandomSource};
 use async_trait::async_trait;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 use std::future::Future;
+use std::collections::{HashMap, VecDeque};
+use std::num::NonZeroUsize;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -40,6 +45,56 @@ impl Display for CollectionTaskError {
 
 impl Error for CollectionTaskError {}
 
+/// [`AggregateError`] gathers the errors of every failed child of a [`CollectionTaskFrame`], as
+/// produced by the [`GroupedTaskFramesAggregate`] policy. It is carried inside the [`CollectionTaskError`]
+/// returned by the collection and can be recovered by downcasting its [inner](CollectionTaskError::inner) error.
+///
+/// The errors are ordered by the index of the child which produced them.
+#[derive(Debug)]
+pub struct AggregateError {
+    errors: Vec<CollectionTaskError>,
+}
+
+impl AggregateError {
+    pub fn new(mut errors: Vec<CollectionTaskError>) -> Self {
+        errors.sort_by_key(|err| err.index());
+        Self { errors }
+    }
+
+    pub fn errors(&self) -> &[CollectionTaskError] {
+        &self.errors
+    }
+
+    pub fn indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.errors.iter().map(|err| err.index())
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn into_errors(self) -> Vec<CollectionTaskError> {
+        self.errors
+    }
+}
+
+impl Display for AggregateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} task frame(s) have failed", self.errors.len())?;
+        for err in &self.errors {
+            write!(f, "\n\t{err}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for AggregateError {}
+
 #[async_trait]
 pub trait CollectionExecStrategy: Send + Sync + Sized + 'static {
     async fn execute(
@@ -48,15 +103,23 @@ pub trait CollectionExecStrategy: Send + Sync + Sized + 'static {
     ) -> Result<(), <CollectionTaskFrame<Self> as TaskFrame>::Error>;
 }
 
+#[non_exhaustive]
 pub enum ConsensusGTFE<T: Error + Send + Sync + 'static> {
     SkipResult,
     ReturnError(T),
     ReturnSuccess,
+    DeferError(T),
 }
 
 #[async_trait]
 pub trait CollectionExecPolicy<T: Error + Send + Sync + 'static>: Send + Sync {
     async fn should_quit(&self, result: Option<T>) -> ConsensusGTFE<T>;
+
+    /// Decides the outcome once every child ran without the policy quitting, receiving the errors
+    /// deferred (via [`ConsensusGTFE::DeferError`]) along the way. By default, the collection succeeds.
+    async fn conclude(&self, _deferred: Vec<T>) -> Result<(), T> {
+        Ok(())
+    }
 }
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -95,6 +158,31 @@ impl<T: Error + Send + Sync + 'static> CollectionExecPolicy<T> for GroupedTaskFr
     }
 }
 
+/// A policy which runs every child regardless of failures and, if any failed, returns a single
+/// [`CollectionTaskError`] carrying an [`AggregateError`] of all of them (indexed by the earliest failed child).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GroupedTaskFramesAggregate;
+
+#[async_trait]
+impl CollectionExecPolicy<CollectionTaskError> for GroupedTaskFramesAggregate {
+    async fn should_quit(&self, result: Option<CollectionTaskError>) -> ConsensusGTFE<CollectionTaskError> {
+        match result {
+            None => ConsensusGTFE::SkipResult,
+            Some(err) => ConsensusGTFE::DeferError(err),
+        }
+    }
+
+    async fn conclude(&self, deferred: Vec<CollectionTaskError>) -> Result<(), CollectionTaskError> {
+        if deferred.is_empty() {
+            return Ok(());
+        }
+
+        let aggregate = AggregateError::new(deferred);
+        let index = aggregate.errors()[0].index();
+        Err(CollectionTaskError::new(index, Box::new(aggregate) as Box<dyn TaskError>))
+    }
+}
+
 #[derive(Debug, Default, Copy, Clone)]
 pub struct SequentialExecStrategy<P = GroupedTaskFramesQuitOnFailure> {
     policy: P,
@@ -114,6 +202,7 @@ impl<P: CollectionExecPolicy<CollectionTaskError> + Send + Sync + 'static> Colle
         &self,
         handle: CollectionTaskFrameHandle<'_, Self>,
     ) -> Result<(), <CollectionTaskFrame<Self> as TaskFrame>::Error> {
+        let mut deferred = Vec::new();
         for idx in 0..handle.length() {
             let result = handle
                 .execute(idx)
@@ -123,12 +212,13 @@ impl<P: CollectionExecPolicy<CollectionTaskError> + Send + Sync + 'static> Colle
 
             match self.policy.should_quit(result).await {
                 ConsensusGTFE::SkipResult => continue,
+                ConsensusGTFE::DeferError(err) => deferred.push(err),
                 ConsensusGTFE::ReturnSuccess => return Ok(()),
                 ConsensusGTFE::ReturnError(err) => return Err(err),
             }
         }
 
-        Ok(())
+        self.policy.conclude(deferred).await
     }
 }
 
@@ -182,6 +272,7 @@ impl<P: CollectionExecPolicy<CollectionTaskError> + Send + Sync + 'static> Colle
             });
         }
 
+        let mut deferred = Vec::new();
         while let Some(joined) = js.join_next().await {
             let Ok((idx, result)) = joined else {
                 continue;
@@ -190,12 +281,104 @@ impl<P: CollectionExecPolicy<CollectionTaskError> + Send + Sync + 'static> Colle
 
             match self.policy.should_quit(result).await {
                 ConsensusGTFE::SkipResult => continue,
+                ConsensusGTFE::DeferError(err) => deferred.push(err),
                 ConsensusGTFE::ReturnSuccess => return Ok(()),
                 ConsensusGTFE::ReturnError(err) => return Err(err),
             }
         }
 
-        Ok(())
+        self.policy.conclude(deferred).await
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct QuorumExecStrategy {
+    quorum: NonZeroUsize,
+}
+
+impl QuorumExecStrategy {
+    pub fn new(quorum: NonZeroUsize) -> Self {
+        Self { quorum }
+    }
+
+    pub fn quorum(&self) -> NonZeroUsize {
+        self.quorum
+    }
+}
+
+#[async_trait]
+impl CollectionExecStrategy for QuorumExecStrategy {
+    async fn execute(
+        &self,
+        handle: CollectionTaskFrameHandle<'_, Self>,
+    ) -> Result<(), <CollectionTaskFrame<Self> as TaskFrame>::Error> {
+        let required = self.quorum.get();
+        let size = handle.length();
+        let unreachable = |idx: usize, failed: usize| {
+            CollectionTaskError::new(
+                idx,
+                Box::new(QuorumUnreachable {
+                    required,
+                    failed,
+                    size,
+                }) as Box<dyn TaskError>,
+            )
+        };
+
+        // No child has run (let alone failed), so the index points past the last one
+        if required > size {
+            return Err(unreachable(size, 0));
+        }
+
+        let mut js = tokio::task::JoinSet::new();
+        let mut indices = HashMap::with_capacity(size);
+        for idx in 0..size {
+            let frame = handle.collection.taskframes[idx].clone();
+            let ctx = *handle.ctx;
+            let child = js.spawn(async move {
+                ctx.emit::<OnChildTaskFrameStart>(&(idx, frame.as_ref())).await;
+                let result = frame.erased_execute(&ctx, &()).await;
+                match result {
+                    Ok(()) => ctx.emit::<OnChildTaskFrameEnd>(&None).await,
+                    Err(ref err) => {
+                        ctx
+                            .emit::<OnChildTaskFrameEnd>(&Some(err.as_ref()))
+                            .await
+                    }
+                }
+
+                result.is_ok()
+            });
+
+            indices.insert(child.id(), idx);
+        }
+
+        let mut succeeded = 0;
+        let mut failed = 0;
+        let mut last_failed = 0;
+        while let Some(joined) = js.join_next_with_id().await {
+            // A child which panicked counts as failed
+            let (idx, success) = match joined {
+                Ok((id, success)) => (indices[&id], success),
+                Err(err) => (indices[&err.id()], false),
+            };
+
+            if success {
+                succeeded += 1;
+                if succeeded == required {
+                    handle.ctx.emit::<OnQuorumReached>(&succeeded).await;
+                    return Ok(());
+                }
+            } else {
+                failed += 1;
+                last_failed = idx;
+                if size - failed < required {
+                    return Err(unreachable(idx, failed));
+                }
+            }
+        }
+
+        Err(unreachable(last_failed, failed))
     }
 }
 
@@ -215,6 +398,60 @@ where
     }
 }
 
+/*
+    Weights are kept as running totals, so a single draw in the range of the total weight
+    maps onto a child via a binary search, children with a weight of zero are never picked
+ */
+pub struct WeightedSelectFrameAccessor {
+    cumulative: Vec<u64>,
+    random: Box<dyn RandomSource>,
+}
+
+impl WeightedSelectFrameAccessor {
+    pub fn new(weights: impl IntoIterator<Item = u64>) -> Result<Self, SelectionWeightsAllZero> {
+        let mut total = 0u64;
+        let cumulative: Vec<u64> = weights
+            .into_iter()
+            .map(|weight| {
+                total = total.saturating_add(weight);
+                total
+            })
+            .collect();
+
+        if total == 0 {
+            return Err(SelectionWeightsAllZero);
+        }
+
+        Ok(Self {
+            cumulative,
+            random: Box::new(ThreadRandomSource),
+        })
+    }
+
+    pub fn with_random_source(mut self, random: impl RandomSource) -> Self {
+        self.random = Box::new(random);
+        self
+    }
+
+    pub fn weight(&self, idx: usize) -> Option<u64> {
+        let upper = *self.cumulative.get(idx)?;
+        let lower = idx.checked_sub(1).map_or(0, |prev| self.cumulative[prev]);
+        Some(upper - lower)
+    }
+
+    pub fn total_weight(&self) -> u64 {
+        self.cumulative.last().copied().unwrap_or(0)
+    }
+}
+
+#[async_trait]
+impl SelectFrameAccessor for WeightedSelectFrameAccessor {
+    async fn select(&self, _ctx: &RestrictTaskFrameContext) -> usize {
+        let draw = self.random.u64_below(self.total_weight());
+        self.cumulative.partition_point(|bound| *bound <= draw)
+    }
+}
+
 pub struct SelectionExecStrategy<S: SelectFrameAccessor> {
     accessor: S,
 }
@@ -250,8 +487,116 @@ impl<S: SelectFrameAccessor> CollectionExecStrategy for SelectionExecStrategy<S>
     }
 }
 
+/*
+    Each child keeps a sliding window of its most recent outcomes (the same bookkeeping the
+    adaptive shedding TaskFrame uses), children without any recorded outcome count as fully healthy
+ */
+pub struct HealthSelectionExecStrategy {
+    window: NonZeroUsize,
+    outcomes: parking_lot::Mutex<Vec<VecDeque<bool>>>,
+}
+
+impl HealthSelectionExecStrategy {
+    pub fn new(window: NonZeroUsize) -> Self {
+        Self {
+            window,
+            outcomes: parking_lot::Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn window(&self) -> NonZeroUsize {
+        self.window
+    }
+
+    pub fn health(&self, idx: usize) -> f64 {
+        self.outcomes
+            .lock()
+            .get(idx)
+            .map_or(1.0, Self::success_rate)
+    }
+
+    fn success_rate(outcomes: &VecDeque<bool>) -> f64 {
+        if outcomes.is_empty() {
+            return 1.0;
+        }
+
+        outcomes.iter().filter(|x| **x).count() as f64 / outcomes.len() as f64
+    }
+
+    fn ranking(&self, size: usize) -> Vec<(usize, f64)> {
+        let mut outcomes = self.outcomes.lock();
+        if outcomes.len() < size {
+            outcomes.resize_with(size, || VecDeque::with_capacity(self.window.get()));
+        }
+
+        let mut ranking: Vec<(usize, f64)> = outcomes[..size]
+            .iter()
+            .map(Self::success_rate)
+            .enumerate()
+            .collect();
+
+        // Stable, so equally healthy children keep their order
+        ranking.sort_by(|a, b| b.1.total_cmp(&a.1));
+        ranking
+    }
+
+    fn record(&self, idx: usize, success: bool) {
+        let mut outcomes = self.outcomes.lock();
+        let Some(outcomes) = outcomes.get_mut(idx) else {
+            return;
+        };
+
+        if outcomes.len() == self.window.get() {
+            outcomes.pop_front();
+        }
+        outcomes.push_back(success);
+    }
+}
+
+#[async_trait]
+impl CollectionExecStrategy for HealthSelectionExecStrategy {
+    async fn execute(
+        &self,
+        handle: CollectionTaskFrameHandle<'_, Self>,
+    ) -> Result<(), <CollectionTaskFrame<Self> as TaskFrame>::Error> {
+        let size = handle.length();
+        let mut last_error = None;
+
+        for (idx, health) in self.ranking(size) {
+            handle.ctx.emit::<OnTaskFrameSelection>(&(idx, health)).await;
+
+            match handle.execute(idx).await {
+                Ok(()) => {
+                    self.record(idx, true);
+                    return Ok(());
+                }
+
+                Err(err) => {
+                    self.record(idx, false);
+                    last_error = Some(CollectionTaskError::new(idx, err));
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| {
+            CollectionTaskError::new(
+                0,
+                Box::new(TaskSelectionIndexOutOfBounds {
+                    index: 0,
+                    src: String::from("HealthSelectionExecStrategy"),
+                    size,
+                }) as Box<dyn TaskError>,
+            )
+        }))
+    }
+}
+
 define_event!(OnChildTaskFrameStart, (usize, &'a dyn ErasedTaskFrame<()>));
 define_event!(OnChildTaskFrameEnd, Option<&'a dyn TaskError>);
+define_event!(OnQuorumReached, usize);
+
+// Emitted by the health-aware selection for every child it routes to, carries its index and health score
+define_event!(OnTaskFrameSelection, (usize, f64));
 
 define_event_group!(
     ChildTaskFrameEvents,
@@ -301,6 +646,17 @@ impl<P: CollectionExecPolicy<CollectionTaskError> + Send + Sync + 'static>
     }
 }
 
+pub type QuorumTaskFrame = CollectionTaskFrame<QuorumExecStrategy>;
+
+impl CollectionTaskFrame<QuorumExecStrategy> {
+    pub fn quorum(taskframes: Vec<Arc<dyn ErasedTaskFrame<()>>>, quorum: NonZeroUsize) -> Self {
+        Self {
+            taskframes,
+            strategy: QuorumExecStrategy::new(quorum),
+        }
+    }
+}
+
 impl<S: SelectFrameAccessor> CollectionTaskFrame<SelectionExecStrategy<S>> {
     pub fn selection(taskframes: Vec<Arc<dyn ErasedTaskFrame<()>>>, accessor: S) -> Self {
         Self {
@@ -310,6 +666,54 @@ impl<S: SelectFrameAccessor> CollectionTaskFrame<SelectionExecStrategy<S>> {
     }
 }
 
+pub type SelectTaskFrame<S = WeightedSelectFrameAccessor> = CollectionTaskFrame<SelectionExecStrategy<S>>;
+
+impl CollectionTaskFrame<SelectionExecStrategy<WeightedSelectFrameAccessor>> {
+    pub fn weighted_builder() -> WeightedSelectBuilder {
+        WeightedSelectBuilder {
+            taskframes: Vec::new(),
+            weights: Vec::new(),
+            random: Box::new(ThreadRandomSource),
+        }
+    }
+}
+
+/// A builder for a weighted [`SelectTaskFrame`], each child TaskFrame is paired with a weight and
+/// gets picked with a probability proportional to it. Obtained via [`SelectTaskFrame::weighted_builder`].
+pub struct WeightedSelectBuilder {
+    taskframes: Vec<Arc<dyn ErasedTaskFrame<()>>>,
+    weights: Vec<u64>,
+    random: Box<dyn RandomSource>,
+}
+
+impl WeightedSelectBuilder {
+    pub fn frame(mut self, frame: Arc<dyn ErasedTaskFrame<()>>, weight: u64) -> Self {
+        self.taskframes.push(frame);
+        self.weights.push(weight);
+        self
+    }
+
+    pub fn with_random_source(mut self, random: impl RandomSource) -> Self {
+        self.random = Box::new(random);
+        self
+    }
+
+    pub fn build(self) -> Result<SelectTaskFrame, SelectionWeightsAllZero> {
+        let mut accessor = WeightedSelectFrameAccessor::new(self.weights)?;
+        accessor.random = self.random;
+        Ok(CollectionTaskFrame::selection(self.taskframes, accessor))
+    }
+}
+
+impl CollectionTaskFrame<HealthSelectionExecStrategy> {
+    pub fn health_aware(taskframes: Vec<Arc<dyn ErasedTaskFrame<()>>>, window: NonZeroUsize) -> Self {
+        Self {
+            taskframes,
+            strategy: HealthSelectionExecStrategy::new(window),
+        }
+    }
+}
+
 pub struct CollectionTaskFrameHandle<'a, T: CollectionExecStrategy> {
     collection: &'a CollectionTaskFrame<T>,
     ctx: &'a TaskFrameContext,
@@ -325,6 +729,11 @@ impl<'a, T: CollectionExecStrategy> CollectionTaskFrameHandle<'a, T> {
             }) as Box<dyn TaskError>);
         };
 
+        // Steps starting past the deadline of the chain (see DeadlineTaskFrame) fail without running
+        if self.ctx.deadline().is_some_and(|deadline| tokio::time::Instant::now() >= deadline) {
+            return Err(Box::new(ChronographerErrors::DeadlineExceeded) as Box<dyn TaskError>);
+        }
+
         self.ctx
             .emit::<OnChildTaskFrameStart>(&(idx, taskframe))
             .await;
@@ -374,4 +783,11 @@ impl<T: CollectionExecStrategy> TaskFrame for CollectionTaskFrame<T> {
 
         self.strategy.execute(handle).await
     }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new(
+            "Collection",
+            self.taskframes.iter().map(|frame| frame.erased_describe()).collect(),
+        )
+    }
 }
@@ -1,7 +1,7 @@
 use crate::errors::ConditionalTaskFrameError;
 use crate::task::TaskFrame;
 use crate::task::noopframe::NoOperationTaskFrame;
-use crate::task::{RestrictTaskFrameContext, TaskFrameContext, TaskHookEvent};
+use crate::task::{FrameNode, RestrictTaskFrameContext, TaskFrameContext, TaskHookEvent};
 use crate::utils::macros::{define_event, define_event_group};
 use async_trait::async_trait;
 use typed_builder::TypedBuilder;
@@ -131,4 +131,8 @@ where
 
         result.map_err(ConditionalTaskFrameError::SecondaryFailed)
     }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("Condition", vec![self.frame.describe(), self.fallback.describe()])
+    }
 }
@@ -0,0 +1,74 @@
+use crate::errors::{ChronographerErrors, DeadlineTaskFrameError};
+use crate::task::frames::with_deadline;
+use crate::task::{FrameNode, TaskFrame, TaskFrameContext, TaskHookEvent};
+use crate::utils::macros::define_event;
+use std::time::Duration;
+
+define_event!(OnDeadlineExceeded, Duration);
+
+/// [`DeadlineTaskFrame`] bounds the whole of its frame (typically a sequential chain) by a single deadline,
+/// ``now + budget`` as the execution starts, instead of bounding every step on its own. The deadline is visible
+/// to the frames underneath (see [`RestrictTaskFrameContext::deadline`](crate::task::RestrictTaskFrameContext::deadline)),
+/// so steps of a [`CollectionTaskFrame`](crate::task::CollectionTaskFrame) which would begin past it don't run
+/// and a step still in flight is dropped. Either way the execution fails with [`ChronographerErrors::DeadlineExceeded`]
+/// and emits [`OnDeadlineExceeded`].
+///
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::{CollectionTaskFrame, DeadlineTaskFrame, ErasedTaskFrame};
+/// # use std::sync::Arc;
+/// # use std::time::Duration;
+/// # fn step() -> Arc<dyn ErasedTaskFrame<()>> {
+/// #     Arc::new(DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }))
+/// # }
+/// # let (extract, transform, load) = (step(), step(), step());
+/// // Extract, transform and load have to finish within 30 seconds in total
+/// let frame = DeadlineTaskFrame::new(
+///     CollectionTaskFrame::sequential(vec![extract, transform, load]),
+///     Duration::from_secs(30),
+/// );
+/// ```
+pub struct DeadlineTaskFrame<T: TaskFrame> {
+    frame: T,
+    budget: Duration,
+}
+
+impl<T: TaskFrame> DeadlineTaskFrame<T> {
+    pub fn new(frame: T, budget: Duration) -> Self {
+        Self { frame, budget }
+    }
+
+    pub fn budget(&self) -> Duration {
+        self.budget
+    }
+}
+
+impl<T: TaskFrame> TaskFrame for DeadlineTaskFrame<T> {
+    type Error = DeadlineTaskFrameError<T::Error>;
+    type Args = T::Args;
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
+        let Some(deadline) = tokio::time::Instant::now().checked_add(self.budget) else {
+            return self.frame.execute(ctx, args).await.map_err(DeadlineTaskFrameError::Inner);
+        };
+
+        let result = tokio::select! {
+            result = with_deadline(deadline, self.frame.execute(ctx, args)) => Some(result),
+            _ = tokio::time::sleep_until(deadline) => None,
+        };
+
+        match result {
+            Some(Ok(())) => Ok(()),
+            Some(Err(err)) if tokio::time::Instant::now() < deadline => Err(DeadlineTaskFrameError::Inner(err)),
+            _ => {
+                ctx.emit::<OnDeadlineExceeded>(&self.budget).await;
+                Err(ChronographerErrors::DeadlineExceeded.into())
+            }
+        }
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("Deadline", vec![self.frame.describe()])
+    }
+}
@@ -1,5 +1,5 @@
 use crate::task::TaskFrame;
-use crate::task::{TaskFrameContext, TaskHookEvent};
+use crate::task::{FrameNode, TaskFrameContext, TaskHookEvent};
 use crate::utils::macros::{define_event, define_event_group};
 use std::time::Duration;
 
@@ -52,4 +52,8 @@ impl<T: TaskFrame> TaskFrame for DelayTaskFrame<T> {
 
         self.frame.execute(ctx, args).await
     }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("Delay", vec![self.frame.describe()])
+    }
 }
@@ -1,16 +1,42 @@
 use std::marker::PhantomData;
 use crate::utils::macros::define_event;
-use crate::errors::TaskError;
+use crate::errors::{ChronographerErrors, TaskError};
 use crate::task::TaskHookEvent;
 use crate::task::dependency::FrameDependency;
 use crate::task::TaskFrame;
-use crate::task::{Debug, TaskFrameContext};
+use crate::task::{Debug, FrameNode, TaskFrameContext};
 use typed_builder::TypedBuilder;
 
 pub trait DefaultDependencyError: TaskError {
     fn default_dependency_error() -> Self;
 }
 
+impl DefaultDependencyError for ChronographerErrors {
+    fn default_dependency_error() -> Self {
+        ChronographerErrors::DependenciesUnresolved
+    }
+}
+
+impl DefaultDependencyError for Box<dyn TaskError> {
+    fn default_dependency_error() -> Self {
+        Box::new(ChronographerErrors::DependenciesUnresolved)
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl DefaultDependencyError for anyhow::Error {
+    fn default_dependency_error() -> Self {
+        anyhow::Error::new(ChronographerErrors::DependenciesUnresolved)
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl DefaultDependencyError for eyre::Report {
+    fn default_dependency_error() -> Self {
+        eyre::Report::new(ChronographerErrors::DependenciesUnresolved)
+    }
+}
+
 pub trait DependencyUnresolve<T: TaskError>: Send + Sync {
     fn execute(&self) -> Result<(), T>;
 }
@@ -108,4 +134,8 @@ impl<T: TaskFrame> TaskFrame for DependencyTaskFrame<T> {
 
         self.frame.execute(&ctx, args).await
     }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("Dependency", vec![self.frame.describe()])
+    }
 }
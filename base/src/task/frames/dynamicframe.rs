@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 use crate::errors::TaskError;
-use crate::task::{TaskFrame, TaskFrameContext};
+use crate::task::{FrameNode, TaskFrame, TaskFrameContext};
 
 pub struct DynamicTaskFrame<T, Args>(T, PhantomData<Args>);
 
@@ -31,4 +31,8 @@ where
     async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
         self.0(ctx, args).await
     }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::leaf("Dynamic")
+    }
 }
\ No newline at end of file
@@ -1,7 +1,7 @@
 use crate::utils::macros::define_event;
 use crate::errors::TaskError;
 use crate::task::TaskFrame;
-use crate::task::{TaskFrameContext, TaskHookEvent};
+use crate::task::{FrameNode, TaskFrameContext, TaskHookEvent};
 
 define_event!(OnFallbackEvent, &'a dyn TaskError);
 
@@ -32,4 +32,8 @@ where
             Ok(()) => Ok(()),
         }
     }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("Fallback", vec![self.0.describe(), self.1.describe()])
+    }
 }
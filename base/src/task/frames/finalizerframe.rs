@@ -0,0 +1,84 @@
+use crate::task::{FrameNode, TaskFrame, TaskFrameContext};
+
+/// How the execution of the inner frame of a [`FinalizerTaskFrame`] went, handed to the finalizer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FinalizerOutcome {
+    Succeeded,
+    Failed,
+
+    /// The execution was dropped before the inner frame finished (e.g. a timeout elapsed).
+    Cancelled,
+}
+
+/*
+    Runs the finalizer on drop, so it runs even when the execution future is dropped mid-way,
+    the outcome stays Cancelled unless the inner frame got to finish
+ */
+struct FinalizerGuard<'a, F: Fn(FinalizerOutcome)> {
+    finalizer: &'a F,
+    outcome: FinalizerOutcome,
+}
+
+impl<F: Fn(FinalizerOutcome)> Drop for FinalizerGuard<'_, F> {
+    fn drop(&mut self) {
+        (self.finalizer)(self.outcome);
+    }
+}
+
+/// [`FinalizerTaskFrame`] is the ``try / finally`` of TaskFrames, its finalizer runs exactly once per
+/// execution with the [`FinalizerOutcome`], even when the execution is dropped before the inner frame
+/// finishes (it is invoked from a drop guard). For the same reason the finalizer is synchronous and must
+/// not panic, cleanup which needs to ``.await`` should be spawned from it instead.
+///
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::{FinalizerOutcome, FinalizerTaskFrame};
+/// let export = DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) });
+/// let frame = FinalizerTaskFrame::new(export, |outcome: FinalizerOutcome| {
+///     println!("export ended with {outcome:?}, releasing its lock file");
+/// });
+/// ```
+pub struct FinalizerTaskFrame<T, F> {
+    frame: T,
+    finalizer: F,
+}
+
+impl<T, F> FinalizerTaskFrame<T, F>
+where
+    T: TaskFrame,
+    F: Fn(FinalizerOutcome) + Send + Sync + 'static,
+{
+    pub fn new(frame: T, finalizer: F) -> Self {
+        Self { frame, finalizer }
+    }
+}
+
+impl<T, F> TaskFrame for FinalizerTaskFrame<T, F>
+where
+    T: TaskFrame,
+    F: Fn(FinalizerOutcome) + Send + Sync + 'static,
+{
+    type Error = T::Error;
+    type Args = T::Args;
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
+        let mut guard = FinalizerGuard {
+            finalizer: &self.finalizer,
+            outcome: FinalizerOutcome::Cancelled,
+        };
+
+        let result = self.frame.execute(ctx, args).await;
+        guard.outcome = match &result {
+            Ok(()) => FinalizerOutcome::Succeeded,
+            Err(_) => FinalizerOutcome::Failed,
+        };
+
+        drop(guard);
+        result
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("Finalizer", vec![self.frame.describe()])
+    }
+}
@@ -0,0 +1,116 @@
+use crate::errors::GateOnHealthTaskFrameError;
+use crate::task::hooks::RollingOutcomeHook;
+use crate::task::{FrameNode, TaskFrame, TaskFrameContext, TaskHookEvent};
+use crate::utils::macros::define_event;
+use std::borrow::Cow;
+use std::sync::Arc;
+
+/// [`HealthSource`] supplies the named health metrics consulted by [`GateOnHealthTaskFrame`], a metric
+/// is a score between ``0.0`` (unhealthy) and ``1.0`` (healthy), or ``None`` when nothing is known yet.
+///
+/// [`RollingOutcomeHook`] is a source whose metrics are the success rates of its labels over the retained
+/// windows, any ``Fn(&str) -> Option<f64>`` closure is a source as well (for metrics kept elsewhere).
+pub trait HealthSource: Send + Sync + 'static {
+    fn health(&self, metric: &str) -> Option<f64>;
+}
+
+impl HealthSource for RollingOutcomeHook {
+    fn health(&self, metric: &str) -> Option<f64> {
+        self.snapshot(metric).success_rate()
+    }
+}
+
+impl<F: Fn(&str) -> Option<f64> + Send + Sync + 'static> HealthSource for F {
+    fn health(&self, metric: &str) -> Option<f64> {
+        self(metric)
+    }
+}
+
+define_event!(OnHealthGateOpen, f64);
+
+/// [`GateOnHealthTaskFrame`] runs its frame only while a named health metric of its [`HealthSource`] stays
+/// at or above ``threshold`` (e.g. skipping publishing while ingestion succeeded less than 90% of the time over
+/// the last hour). Below it the execution fails with [`GateOnHealthTaskFrameError::HealthGateOpen`] and emits
+/// [`OnHealthGateOpen`], while a metric which isn't known yet leaves the gate closed.
+///
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::GateOnHealthTaskFrame;
+/// # use std::sync::Arc;
+/// let publish = DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) });
+/// let rates = Arc::new(|metric: &str| (metric == "ingest").then_some(0.95));
+///
+/// let frame = GateOnHealthTaskFrame::new(publish, rates, "ingest", 0.9);
+/// assert_eq!(frame.health(), Some(0.95));
+/// ```
+pub struct GateOnHealthTaskFrame<T: TaskFrame> {
+    frame: T,
+    source: Arc<dyn HealthSource>,
+    metric: Cow<'static, str>,
+    threshold: f64,
+}
+
+impl<T: TaskFrame> GateOnHealthTaskFrame<T> {
+    /// Constructs a [`GateOnHealthTaskFrame`] running ``frame`` while the metric named ``metric`` of ``source``
+    /// is at least ``threshold``.
+    ///
+    /// # Panics
+    /// Panics if ``threshold`` lies outside of ``[0, 1]``.
+    pub fn new(
+        frame: T,
+        source: Arc<impl HealthSource>,
+        metric: impl Into<Cow<'static, str>>,
+        threshold: f64,
+    ) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&threshold),
+            "the threshold of GateOnHealthTaskFrame must lie within [0, 1]"
+        );
+
+        Self {
+            frame,
+            source,
+            metric: metric.into(),
+            threshold,
+        }
+    }
+
+    pub fn metric(&self) -> &str {
+        &self.metric
+    }
+
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// The current value of the metric, ``None`` when it isn't known yet.
+    pub fn health(&self) -> Option<f64> {
+        self.source.health(&self.metric)
+    }
+}
+
+impl<T: TaskFrame> TaskFrame for GateOnHealthTaskFrame<T> {
+    type Error = GateOnHealthTaskFrameError<T::Error>;
+    type Args = T::Args;
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
+        if let Some(health) = self.health().filter(|health| *health < self.threshold) {
+            ctx.emit::<OnHealthGateOpen>(&health).await;
+            return Err(GateOnHealthTaskFrameError::HealthGateOpen {
+                metric: self.metric.clone(),
+                health,
+                threshold: self.threshold,
+            });
+        }
+
+        self.frame
+            .execute(ctx, args)
+            .await
+            .map_err(GateOnHealthTaskFrameError::Inner)
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("GateOnHealth", vec![self.frame.describe()])
+    }
+}
@@ -0,0 +1,134 @@
+use crate::task::{FrameNode, RestrictTaskFrameContext, TaskFrame, TaskFrameContext};
+use dashmap::DashMap;
+use std::sync::{Arc, LazyLock};
+
+static KEYED_LOCKS: LazyLock<KeyedLocks> = LazyLock::new(KeyedLocks::new);
+
+/// [`KeyedLocks`] is a table of per-key locks consulted by [`KeyedSerializeTaskFrame`], frames sharing
+/// the same table serialize their executions per key. Frames use the process-wide table by default,
+/// a separate table can be supplied via [`KeyedSerializeTaskFrame::with_locks`] to scope the serialization.
+/// Locks only live while an execution holds or waits on them, so the table doesn't grow with every key ever seen.
+#[derive(Clone, Default)]
+pub struct KeyedLocks(Arc<DashMap<String, Arc<tokio::sync::Mutex<()>>>>);
+
+impl KeyedLocks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The process-wide table, used by [`KeyedSerializeTaskFrame::new`].
+    pub fn global() -> Self {
+        KEYED_LOCKS.clone()
+    }
+
+    /// The number of keys currently in use (held or waited on).
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn is_locked(&self, key: &str) -> bool {
+        self.0
+            .get(key)
+            .is_some_and(|lock| lock.try_lock().is_err())
+    }
+
+    fn lease(&self, key: String) -> KeyedLockLease<'_> {
+        let lock = match self.0.get(&key) {
+            Some(lock) => lock.clone(),
+            None => self.0.entry(key.clone()).or_default().clone(),
+        };
+
+        KeyedLockLease { locks: self, key, lock }
+    }
+}
+
+// Releases the key on drop, so executions cancelled midway (their future dropped) don't leave it behind
+struct KeyedLockLease<'a> {
+    locks: &'a KeyedLocks,
+    key: String,
+    lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl Drop for KeyedLockLease<'_> {
+    /*
+        Only the table and this lease referencing the lock means no other execution holds or waits on it,
+        the check and removal happen under the shard lock so no execution can pick it up in between
+     */
+    fn drop(&mut self) {
+        self.locks.0.remove_if(&self.key, |_, lock| Arc::strong_count(lock) == 2);
+    }
+}
+
+type SerializationKey = Box<dyn Fn(&RestrictTaskFrameContext) -> Option<String> + Send + Sync>;
+
+/// [`KeyedSerializeTaskFrame`] serializes the executions of its frame per key (e.g. per account), executions
+/// sharing a key run one after another in arrival order while those with different keys run concurrently. The
+/// key is read off the context on every execution, ``None`` runs the frame without serializing. Serialization
+/// spans every frame sharing the same [`KeyedLocks`] table, across Tasks.
+///
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::{KeyedLocks, KeyedSerializeTaskFrame, RestrictTaskFrameContext};
+/// struct Account {
+///     id: u64,
+/// }
+///
+/// let process = DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) });
+/// let frame = KeyedSerializeTaskFrame::new(process, |ctx: &RestrictTaskFrameContext| {
+///     ctx.input::<Account>().map(|account| account.id.to_string())
+/// })
+/// .with_locks(KeyedLocks::new());
+/// assert!(frame.locks().is_empty());
+/// ```
+pub struct KeyedSerializeTaskFrame<T: TaskFrame> {
+    frame: T,
+    key: SerializationKey,
+    locks: KeyedLocks,
+}
+
+impl<T: TaskFrame> KeyedSerializeTaskFrame<T> {
+    pub fn new(
+        frame: T,
+        key: impl Fn(&RestrictTaskFrameContext) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            frame,
+            key: Box::new(key),
+            locks: KeyedLocks::global(),
+        }
+    }
+
+    pub fn with_locks(mut self, locks: KeyedLocks) -> Self {
+        self.locks = locks;
+        self
+    }
+
+    pub fn locks(&self) -> &KeyedLocks {
+        &self.locks
+    }
+}
+
+impl<T: TaskFrame> TaskFrame for KeyedSerializeTaskFrame<T> {
+    type Error = T::Error;
+    type Args = T::Args;
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
+        let Some(key) = (self.key)(ctx) else {
+            return self.frame.execute(ctx, args).await;
+        };
+
+        let lease = self.locks.lease(key);
+        let _guard = lease.lock.lock().await;
+
+        self.frame.execute(ctx, args).await
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("KeyedSerialize", vec![self.frame.describe()])
+    }
+}
@@ -0,0 +1,128 @@
+use crate::task::{FrameNode, TaskFrame, TaskFrameContext, TaskHookEvent};
+use crate::utils::macros::define_event;
+use dashmap::DashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock};
+use typed_builder::TypedBuilder;
+
+static KILL_SWITCHES: LazyLock<DashMap<String, Arc<AtomicBool>>> = LazyLock::new(DashMap::new);
+
+/// [`KillSwitches`] is the process-wide registry of named kill switches consulted by [`KillSwitchTaskFrame`],
+/// flipping a single switch disables every frame bound to it at once (e.g. during an incident), without
+/// touching the Tasks or the Scheduler.
+///
+/// Switches are created (untripped) the first time they are referenced, so a switch may be set before any
+/// frame binds to it and vice versa.
+pub struct KillSwitches;
+
+impl KillSwitches {
+    /// Trips (``true``) or resets (``false``) the switch named ``name``, taking effect from the next
+    /// execution of every frame bound to it (executions already past the check are unaffected).
+    pub fn set(name: &str, tripped: bool) {
+        Self::switch(name).store(tripped, Ordering::SeqCst);
+    }
+
+    pub fn is_tripped(name: &str) -> bool {
+        KILL_SWITCHES
+            .get(name)
+            .is_some_and(|switch| switch.load(Ordering::SeqCst))
+    }
+
+    /// The shared flag backing the switch named ``name``, created untripped if it doesn't exist yet.
+    pub fn switch(name: &str) -> Arc<AtomicBool> {
+        if let Some(switch) = KILL_SWITCHES.get(name) {
+            return switch.clone();
+        }
+
+        KILL_SWITCHES.entry(name.to_owned()).or_default().clone()
+    }
+}
+
+#[derive(TypedBuilder)]
+#[builder(build_method(into = KillSwitchTaskFrame<T>))]
+pub struct KillSwitchTaskFrameConfig<T: TaskFrame> {
+    frame: T,
+
+    #[builder(setter(into))]
+    switch: String,
+
+    /*
+        While tripped, executions succeed without running the frame unless an error is supplied,
+        in which case they fail with it instead
+     */
+    #[builder(
+        setter(transform = |on_tripped: impl Fn() -> T::Error + Send + Sync + 'static|
+            Some(Box::new(on_tripped) as Box<dyn Fn() -> T::Error + Send + Sync>)
+        ),
+        default = None
+    )]
+    on_tripped: Option<Box<dyn Fn() -> T::Error + Send + Sync>>,
+}
+
+impl<T: TaskFrame> From<KillSwitchTaskFrameConfig<T>> for KillSwitchTaskFrame<T> {
+    fn from(config: KillSwitchTaskFrameConfig<T>) -> Self {
+        Self {
+            frame: config.frame,
+            flag: KillSwitches::switch(&config.switch),
+            switch: Arc::from(config.switch),
+            on_tripped: config.on_tripped,
+        }
+    }
+}
+
+define_event!(OnKillSwitchTripped, Arc<str>);
+
+/// [`KillSwitchTaskFrame`] skips its frame while the named switch it is bound to is tripped (see
+/// [`KillSwitches`]), emitting [`OnKillSwitchTripped`] and succeeding instead (or failing with the error
+/// supplied via ``on_tripped``). Checking the switch costs a single atomic load per execution.
+///
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::{KillSwitches, KillSwitchTaskFrame};
+/// let frame = KillSwitchTaskFrame::builder()
+///     .frame(DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }))
+///     .switch("billing")
+///     .build();
+///
+/// // During an incident, every frame bound to "billing" stops running
+/// KillSwitches::set("billing", true);
+/// assert!(KillSwitches::is_tripped(frame.switch()));
+/// ```
+pub struct KillSwitchTaskFrame<T: TaskFrame> {
+    frame: T,
+    switch: Arc<str>,
+    flag: Arc<AtomicBool>,
+    on_tripped: Option<Box<dyn Fn() -> T::Error + Send + Sync>>,
+}
+
+impl<T: TaskFrame> KillSwitchTaskFrame<T> {
+    pub fn builder() -> KillSwitchTaskFrameConfigBuilder<T> {
+        KillSwitchTaskFrameConfig::builder()
+    }
+
+    pub fn switch(&self) -> &str {
+        &self.switch
+    }
+}
+
+impl<T: TaskFrame> TaskFrame for KillSwitchTaskFrame<T> {
+    type Error = T::Error;
+    type Args = T::Args;
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
+        if self.flag.load(Ordering::SeqCst) {
+            ctx.emit::<OnKillSwitchTripped>(&self.switch).await;
+            return match &self.on_tripped {
+                Some(on_tripped) => Err(on_tripped()),
+                None => Ok(()),
+            };
+        }
+
+        self.frame.execute(ctx, args).await
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("KillSwitch", vec![self.frame.describe()])
+    }
+}
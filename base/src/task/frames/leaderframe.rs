@@ -0,0 +1,92 @@
+use crate::task::{FrameNode, TaskFrame, TaskFrameContext, TaskHookEvent};
+use crate::utils::macros::define_event;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// [`LeaderElector`] decides whether this instance of the application currently holds leadership,
+/// consulted by [`LeaderOnlyTaskFrame`] before every execution. The default is [`AlwaysLeader`] (for
+/// single-node deployments), it can be implemented on top of a distributed lease (Redis, etcd... etc.).
+///
+/// Implementations should fail closed, reporting no leadership when it cannot be determined (e.g. the
+/// lease store is unreachable), so no two instances run the same singleton Task at once.
+#[async_trait]
+pub trait LeaderElector: Send + Sync + 'static {
+    async fn is_leader(&self) -> bool;
+}
+
+#[async_trait]
+impl<F, Fut> LeaderElector for F
+where
+    F: Fn() -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = bool> + Send,
+{
+    async fn is_leader(&self) -> bool {
+        self().await
+    }
+}
+
+/// A [`LeaderElector`] which always holds leadership, for single-node deployments.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AlwaysLeader;
+
+#[async_trait]
+impl LeaderElector for AlwaysLeader {
+    async fn is_leader(&self) -> bool {
+        true
+    }
+}
+
+define_event!(OnLeadershipSkipped, ());
+
+/// [`LeaderOnlyTaskFrame`] runs its frame only on the instance its [`LeaderElector`] reports as the leader,
+/// making the Task a singleton across a multi-instance deployment. Elsewhere the execution succeeds without
+/// running the frame and emits [`OnLeadershipSkipped`]. Leadership is only checked upfront, losing it midway
+/// doesn't interrupt the execution.
+///
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::LeaderOnlyTaskFrame;
+/// # use std::sync::Arc;
+/// # async fn holds_billing_lease() -> bool { true }
+/// let charge = DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) });
+/// let frame = LeaderOnlyTaskFrame::new_with(charge, Arc::new(|| holds_billing_lease()));
+/// ```
+pub struct LeaderOnlyTaskFrame<T: TaskFrame, L: LeaderElector = AlwaysLeader> {
+    frame: T,
+    elector: Arc<L>,
+}
+
+impl<T: TaskFrame> LeaderOnlyTaskFrame<T> {
+    pub fn new(frame: T) -> Self {
+        Self::new_with(frame, Arc::new(AlwaysLeader))
+    }
+}
+
+impl<T: TaskFrame, L: LeaderElector> LeaderOnlyTaskFrame<T, L> {
+    pub fn new_with(frame: T, elector: Arc<L>) -> Self {
+        Self { frame, elector }
+    }
+
+    pub fn elector(&self) -> &Arc<L> {
+        &self.elector
+    }
+}
+
+impl<T: TaskFrame, L: LeaderElector> TaskFrame for LeaderOnlyTaskFrame<T, L> {
+    type Error = T::Error;
+    type Args = T::Args;
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
+        if !self.elector.is_leader().await {
+            ctx.emit::<OnLeadershipSkipped>(&()).await;
+            return Ok(());
+        }
+
+        self.frame.execute(ctx, args).await
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("LeaderOnly", vec![self.frame.describe()])
+    }
+}
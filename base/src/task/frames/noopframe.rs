@@ -1,5 +1,5 @@
 use crate::errors::TaskError;
-use crate::task::{TaskFrame, TaskFrameContext};
+use crate::task::{FrameNode, TaskFrame, TaskFrameContext};
 use std::marker::PhantomData;
 
 #[derive(Debug)]
@@ -27,4 +27,8 @@ impl<E: TaskError, Args: 'static + Send + Sync> TaskFrame for NoOperationTaskFra
     async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
         Ok(())
     }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::leaf("NoOperation")
+    }
 }
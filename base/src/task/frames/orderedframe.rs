@@ -0,0 +1,112 @@
+use crate::task::{FrameNode, TaskFrame, TaskFrameContext};
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::sync::Notify;
+
+struct OrderedState {
+    serving: u64,
+    abandoned: BTreeSet<u64>,
+}
+
+struct TicketGuard<'a> {
+    ticket: u64,
+    state: &'a parking_lot::Mutex<OrderedState>,
+    notify: &'a Notify,
+}
+
+impl Drop for TicketGuard<'_> {
+    fn drop(&mut self) {
+        let mut state = self.state.lock();
+        if state.serving != self.ticket {
+            /*
+                The execution was dropped before its turn came, the ticket is marked so the
+                execution preceding it hands the turn straight to the one after
+            */
+            state.abandoned.insert(self.ticket);
+            return;
+        }
+
+        let mut serving = state.serving + 1;
+        while state.abandoned.remove(&serving) {
+            serving += 1;
+        }
+        state.serving = serving;
+        drop(state);
+
+        self.notify.notify_waiters();
+    }
+}
+
+/// [`OrderedTaskFrame`] runs its frame for a single execution at a time, in the order the executions
+/// entered it (execution N only starts once execution N - 1 completed). Nothing outside of the frame is
+/// serialized, and an execution dropped while waiting for its turn (e.g. by a timeout) hands the turn over
+/// instead of blocking the executions after it.
+///
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::OrderedTaskFrame;
+/// let ordered = OrderedTaskFrame::new(DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async {
+///     Ok::<_, String>(())
+/// }));
+/// assert_eq!(ordered.pending(), 0);
+/// ```
+pub struct OrderedTaskFrame<T: TaskFrame> {
+    frame: T,
+    next_ticket: AtomicU64,
+    state: parking_lot::Mutex<OrderedState>,
+    notify: Notify,
+}
+
+impl<T: TaskFrame> OrderedTaskFrame<T> {
+    pub fn new(frame: T) -> Self {
+        Self {
+            frame,
+            next_ticket: AtomicU64::new(0),
+            state: parking_lot::Mutex::new(OrderedState {
+                serving: 0,
+                abandoned: BTreeSet::new(),
+            }),
+            notify: Notify::new(),
+        }
+    }
+
+    pub fn pending(&self) -> u64 {
+        let issued = self.next_ticket.load(Ordering::SeqCst);
+        issued.saturating_sub(self.state.lock().serving)
+    }
+}
+
+impl<T: TaskFrame> TaskFrame for OrderedTaskFrame<T> {
+    type Error = T::Error;
+    type Args = T::Args;
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
+        let guard = TicketGuard {
+            ticket: self.next_ticket.fetch_add(1, Ordering::SeqCst),
+            state: &self.state,
+            notify: &self.notify,
+        };
+
+        loop {
+            let notified = self.notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            if self.state.lock().serving == guard.ticket {
+                break;
+            }
+
+            notified.await;
+        }
+
+        let result = self.frame.execute(ctx, args).await;
+        drop(guard);
+
+        result
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("Ordered", vec![self.frame.describe()])
+    }
+}
@@ -0,0 +1,83 @@
+use crate::errors::PreconditionTaskFrameError;
+use crate::task::{ConditionalFramePredicate, FrameNode, TaskFrame, TaskFrameContext, TaskHookEvent};
+use crate::utils::macros::define_event;
+use std::borrow::Cow;
+
+define_event!(OnPreconditionFailed, Cow<'static, str>);
+
+/// [`PreconditionTaskFrame`] checks a list of named preconditions before running its frame, emitting
+/// [`OnPreconditionFailed`] for each one which doesn't hold. When any of them fails the frame doesn't run and
+/// the execution fails with [`PreconditionTaskFrameError::PreconditionFailed`], naming **all** of the failed
+/// preconditions rather than just the first.
+///
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::{PreconditionTaskFrame, RestrictTaskFrameContext};
+/// # async fn ping_warehouse() -> Result<(), String> { Ok(()) }
+/// let report = DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) });
+/// let frame = PreconditionTaskFrame::new(report)
+///     .precondition("has owner", |ctx: &RestrictTaskFrameContext| {
+///         let owned = !ctx.metadata().owner.is_empty();
+///         async move { owned }
+///     })
+///     .precondition("warehouse reachable", |_ctx: &RestrictTaskFrameContext| async {
+///         ping_warehouse().await.is_ok()
+///     });
+/// assert_eq!(frame.names(), ["has owner", "warehouse reachable"]);
+/// ```
+pub struct PreconditionTaskFrame<T: TaskFrame> {
+    frame: T,
+    preconditions: Vec<(Cow<'static, str>, Box<dyn ConditionalFramePredicate>)>,
+}
+
+impl<T: TaskFrame> PreconditionTaskFrame<T> {
+    pub fn new(frame: T) -> Self {
+        Self {
+            frame,
+            preconditions: Vec::new(),
+        }
+    }
+
+    /// Appends a precondition named ``name``, which is what failures are reported by.
+    pub fn precondition(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        predicate: impl ConditionalFramePredicate + 'static,
+    ) -> Self {
+        self.preconditions.push((name.into(), Box::new(predicate)));
+        self
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.preconditions.iter().map(|(name, _)| name.as_ref()).collect()
+    }
+}
+
+impl<T: TaskFrame> TaskFrame for PreconditionTaskFrame<T> {
+    type Error = PreconditionTaskFrameError<T::Error>;
+    type Args = T::Args;
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
+        let mut failed = Vec::new();
+        for (name, predicate) in &self.preconditions {
+            if !predicate.execute(ctx.as_restricted()).await {
+                ctx.emit::<OnPreconditionFailed>(name).await;
+                failed.push(name.clone());
+            }
+        }
+
+        if !failed.is_empty() {
+            return Err(PreconditionTaskFrameError::PreconditionFailed(failed));
+        }
+
+        self.frame
+            .execute(ctx, args)
+            .await
+            .map_err(PreconditionTaskFrameError::Inner)
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("Precondition", vec![self.frame.describe()])
+    }
+}
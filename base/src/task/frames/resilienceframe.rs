@@ -0,0 +1,123 @@
+use crate::errors::TaskError;
+use crate::task::{
+    ConstantBackoffStrategy, FallbackTaskFrame, FrameNode, RestrictTaskFrameContext,
+    RetriableTaskFrame, RetryBackoffStrategy, TaskFrame, TaskFrameContext,
+    ThresholdErrorsCountLogic, ThresholdReachBehaviour, ThresholdTaskFrame,
+};
+use async_trait::async_trait;
+use std::num::{NonZeroU32, NonZeroUsize};
+use std::time::Duration;
+use typed_builder::TypedBuilder;
+
+/// The nested chain a [`ResilienceTaskFrame`] generates, from the outermost to the innermost frame.
+pub type ResilienceChain<T, F> = ThresholdTaskFrame<FallbackTaskFrame<RetriableTaskFrame<T>, F>>;
+
+struct CircuitOpenBehaviour<E>(Box<dyn Fn() -> E + Send + Sync>);
+
+#[async_trait]
+impl<E: TaskError> ThresholdReachBehaviour<E> for CircuitOpenBehaviour<E> {
+    async fn results(&self, _ctx: &RestrictTaskFrameContext) -> Result<(), E> {
+        Err((self.0)())
+    }
+}
+
+#[derive(TypedBuilder)]
+#[builder(build_method(into = ResilienceTaskFrame<T, F>))]
+pub struct ResilienceTaskFrameConfig<T: TaskFrame, F: TaskFrame<Args = T::Error>> {
+    primary: T,
+    retries: NonZeroU32,
+
+    #[builder(
+        setter(transform = |backoff: impl RetryBackoffStrategy|
+            Box::new(backoff) as Box<dyn RetryBackoffStrategy>
+        ),
+        default = Box::new(ConstantBackoffStrategy::new(Duration::ZERO))
+    )]
+    backoff: Box<dyn RetryBackoffStrategy>,
+
+    degraded: F,
+
+    /*
+        Counts the executions where both the primary (after its retries) and the degraded
+        frame have failed, once reached the circuit opens for good
+     */
+    failure_threshold: NonZeroUsize,
+
+    #[builder(setter(transform = |on_open: impl Fn() -> F::Error + Send + Sync + 'static|
+        Box::new(on_open) as Box<dyn Fn() -> F::Error + Send + Sync>
+    ))]
+    on_open: Box<dyn Fn() -> F::Error + Send + Sync>,
+}
+
+impl<T, F> From<ResilienceTaskFrameConfig<T, F>> for ResilienceTaskFrame<T, F>
+where
+    T: TaskFrame,
+    F: TaskFrame<Args = T::Error>,
+{
+    fn from(config: ResilienceTaskFrameConfig<T, F>) -> Self {
+        let retry = RetriableTaskFrame::builder()
+            .frame(config.primary)
+            .retries(config.retries)
+            .backoff(config.backoff)
+            .build();
+
+        let circuit = ThresholdTaskFrame::builder()
+            .frame(FallbackTaskFrame::new(retry, config.degraded))
+            .count_behaviour(Box::new(ThresholdErrorsCountLogic))
+            .reach_behaviour(Box::new(CircuitOpenBehaviour(config.on_open)))
+            .threshold(config.failure_threshold)
+            .build();
+
+        Self(circuit)
+    }
+}
+
+/// [`ResilienceTaskFrame`] composes retrying, falling back and circuit breaking out of a single configuration.
+/// Every execution retries the primary frame up to ``retries`` times, hands its last error to the degraded
+/// frame if it still fails, and counts the executions where the degraded frame fails as well towards
+/// ``failure_threshold``. Once reached the circuit opens and every execution fails with ``on_open``, for
+/// good (it is a [`ThresholdTaskFrame`] underneath, so there is no half-open state). The generated chain is
+/// available via [`ResilienceTaskFrame::into_chain`].
+///
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::ResilienceTaskFrame;
+/// # use std::num::{NonZeroU32, NonZeroUsize};
+/// let frame = ResilienceTaskFrame::builder()
+///     .primary(DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async {
+///         Err::<(), _>("origin is unreachable".to_owned())
+///     }))
+///     .retries(NonZeroU32::new(3).unwrap())
+///     .degraded(DynamicTaskFrame::new(|_ctx: &TaskFrameContext, err: &String| {
+///         println!("serving from the cache, {err}");
+///         async { Ok::<_, String>(()) }
+///     }))
+///     .failure_threshold(NonZeroUsize::new(5).unwrap())
+///     .on_open(|| "circuit is open".to_owned())
+///     .build();
+/// ```
+pub struct ResilienceTaskFrame<T: TaskFrame, F: TaskFrame<Args = T::Error>>(ResilienceChain<T, F>);
+
+impl<T: TaskFrame, F: TaskFrame<Args = T::Error>> ResilienceTaskFrame<T, F> {
+    pub fn builder() -> ResilienceTaskFrameConfigBuilder<T, F> {
+        ResilienceTaskFrameConfig::builder()
+    }
+
+    pub fn into_chain(self) -> ResilienceChain<T, F> {
+        self.0
+    }
+}
+
+impl<T: TaskFrame, F: TaskFrame<Args = T::Error>> TaskFrame for ResilienceTaskFrame<T, F> {
+    type Error = F::Error;
+    type Args = T::Args;
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
+        self.0.execute(ctx, args).await
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("Resilience", vec![self.0.describe()])
+    }
+}
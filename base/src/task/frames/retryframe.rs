@@ -1,10 +1,13 @@
 use crate::errors::TaskError;
-use crate::task::{TaskFrame, TaskFrameContext, TaskHookEvent};
+use crate::task::{FrameNode, OnTimeout, TaskFrame, TaskFrameContext, TaskHookEvent};
 use crate::utils::macros::{define_event, define_event_group};
+use crate::utils::{RandomSource, ThreadRandomSource};
 use async_trait::async_trait;
 use std::clone::Clone;
 use std::fmt::Debug;
 use std::num::NonZeroU32;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 use typed_builder::TypedBuilder;
 
@@ -36,6 +39,12 @@ pub trait RetryBackoffStrategy: Send + Sync + 'static {
     fn compute(&self, retry: u32) -> Duration;
 }
 
+impl<S: RetryBackoffStrategy + ?Sized> RetryBackoffStrategy for Box<S> {
+    fn compute(&self, retry: u32) -> Duration {
+        self.as_ref().compute(retry)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ConstantBackoffStrategy(Duration);
 
@@ -122,11 +131,13 @@ enum JitterType {
     DecorrelatedJitter(f64),
 }
 
+// The random source is borrowed for the program's lifetime so the strategy stays copyable
 #[derive(Clone, Copy)]
 pub struct JitterBackoffStrategy<T: RetryBackoffStrategy> {
     backoff: T,
     factor: f64,
     jitter_type: JitterType,
+    random: &'static dyn RandomSource,
 }
 
 impl<T: RetryBackoffStrategy> JitterBackoffStrategy<T> {
@@ -135,6 +146,7 @@ impl<T: RetryBackoffStrategy> JitterBackoffStrategy<T> {
             backoff: strat,
             factor,
             jitter_type: JitterType::FullJitter,
+            random: &ThreadRandomSource,
         }
     }
 
@@ -143,6 +155,7 @@ impl<T: RetryBackoffStrategy> JitterBackoffStrategy<T> {
             backoff: strat,
             factor,
             jitter_type: JitterType::EqualJitter,
+            random: &ThreadRandomSource,
         }
     }
 
@@ -151,8 +164,14 @@ impl<T: RetryBackoffStrategy> JitterBackoffStrategy<T> {
             backoff: strat,
             factor,
             jitter_type: JitterType::DecorrelatedJitter(max),
+            random: &ThreadRandomSource,
         }
     }
+
+    pub fn with_random_source(mut self, random: &'static dyn RandomSource) -> Self {
+        self.random = random;
+        self
+    }
 }
 
 impl<T: RetryBackoffStrategy> RetryBackoffStrategy for JitterBackoffStrategy<T> {
@@ -162,18 +181,18 @@ impl<T: RetryBackoffStrategy> RetryBackoffStrategy for JitterBackoffStrategy<T>
         let base_secs = base.as_secs_f64();
 
         let secs = match self.jitter_type {
-            JitterType::FullJitter => fastrand::f64() * base_secs,
+            JitterType::FullJitter => self.random.f64() * base_secs,
 
             JitterType::EqualJitter => {
                 let half = base_secs / 2.0;
-                half + (fastrand::f64() * half)
+                half + (self.random.f64() * half)
             }
 
             JitterType::DecorrelatedJitter(max) => {
                 // TODO: This is an approximation, might get fixed in the future
                 let upper = (base_secs * 3.0).min(max);
 
-                fastrand::f64() * upper
+                self.random.f64() * upper
             }
         };
 
@@ -181,12 +200,148 @@ impl<T: RetryBackoffStrategy> RetryBackoffStrategy for JitterBackoffStrategy<T>
     }
 }
 
+/// A [`RetryBackoffStrategy`] composed of stages, each stage being a strategy which takes over once
+/// the retry count reaches its threshold (e.g. instant retries for the first 3 retries, exponential
+/// backoff thereafter). Retries before the first threshold aren't delayed.
+///
+/// The inner strategy of a stage is handed the retry count **relative** to its threshold, so an
+/// exponential stage starting at retry 3 computes its first delay as if it was its first retry.
+#[derive(Clone, Default)]
+pub struct StagedBackoffStrategy(Vec<(u32, Arc<dyn RetryBackoffStrategy>)>);
+
+impl StagedBackoffStrategy {
+    pub fn new(mut stages: Vec<(u32, Arc<dyn RetryBackoffStrategy>)>) -> Self {
+        stages.sort_by_key(|(threshold, _)| *threshold);
+        Self(stages)
+    }
+
+    /// Appends a stage taking over from the retry ``threshold`` onward, replacing any stage
+    /// sharing the same threshold.
+    pub fn then(mut self, threshold: u32, strategy: impl RetryBackoffStrategy) -> Self {
+        self.0.retain(|(existing, _)| *existing != threshold);
+        let idx = self.0.partition_point(|(existing, _)| *existing < threshold);
+        self.0.insert(idx, (threshold, Arc::new(strategy)));
+        self
+    }
+
+    pub fn thresholds(&self) -> Vec<u32> {
+        self.0.iter().map(|(threshold, _)| *threshold).collect()
+    }
+}
+
+impl RetryBackoffStrategy for StagedBackoffStrategy {
+    fn compute(&self, retry: u32) -> Duration {
+        let idx = self.0.partition_point(|(threshold, _)| *threshold <= retry);
+        match idx.checked_sub(1).map(|idx| &self.0[idx]) {
+            Some((threshold, strategy)) => strategy.compute(retry - threshold),
+            None => Duration::ZERO,
+        }
+    }
+}
+
+/// A point-in-time copy of a [`SharedRetryBudget`], which is also how it gets persisted (see
+/// [`SharedRetryBudget::from_snapshot`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RetryBudgetSnapshot {
+    pub total: u64,
+    pub consumed: u64,
+}
+
+/// [`SharedRetryBudget`] bounds how many retries may happen in total across **every** execution, unlike
+/// the retries of a [`RetriableTaskFrame`] which reset on each execution (e.g. "retry at most 100 times
+/// over the lifetime of the Task, then give up").
+///
+/// It is cheap to clone and clones share the same budget, so it can be injected into multiple
+/// [`RetriableTaskFrame`] (via ``RetriableTaskFrame::builder().budget(...)``) for them to draw from it
+/// collectively. Once exhausted, failing attempts fail fast without being retried and [`OnRetryBudgetExhausted`]
+/// is emitted. With the ``serde`` feature it (de)serializes as a [`RetryBudgetSnapshot`], which allows
+/// resuming the consumed budget across restarts.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "RetryBudgetSnapshot", into = "RetryBudgetSnapshot")
+)]
+pub struct SharedRetryBudget(Arc<(u64, AtomicU64)>);
+
+impl SharedRetryBudget {
+    pub fn new(total: u64) -> Self {
+        Self::from_snapshot(RetryBudgetSnapshot { total, consumed: 0 })
+    }
+
+    pub fn from_snapshot(snapshot: RetryBudgetSnapshot) -> Self {
+        let consumed = snapshot.consumed.min(snapshot.total);
+        Self(Arc::new((snapshot.total, AtomicU64::new(consumed))))
+    }
+
+    pub fn snapshot(&self) -> RetryBudgetSnapshot {
+        RetryBudgetSnapshot {
+            total: self.total(),
+            consumed: self.consumed(),
+        }
+    }
+
+    /// Consumes a single retry out of the budget, returning ``false`` (and consuming nothing)
+    /// when the budget is already exhausted.
+    pub fn try_consume(&self) -> bool {
+        let total = self.0.0;
+        self.0
+            .1
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |consumed| {
+                (consumed < total).then_some(consumed + 1)
+            })
+            .is_ok()
+    }
+
+    pub fn total(&self) -> u64 {
+        self.0.0
+    }
+
+    pub fn consumed(&self) -> u64 {
+        self.0.1.load(Ordering::SeqCst)
+    }
+
+    pub fn remaining(&self) -> u64 {
+        self.total() - self.consumed()
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining() == 0
+    }
+}
+
+impl From<RetryBudgetSnapshot> for SharedRetryBudget {
+    fn from(snapshot: RetryBudgetSnapshot) -> Self {
+        Self::from_snapshot(snapshot)
+    }
+}
+
+impl From<SharedRetryBudget> for RetryBudgetSnapshot {
+    fn from(budget: SharedRetryBudget) -> Self {
+        budget.snapshot()
+    }
+}
+
 define_event!(OnRetryAttemptStart, u32);
 
 define_event!(OnRetryAttemptEnd, (u32, Option<&'a dyn TaskError>));
 
 define_event_group!(RetryAttemptEvents, OnRetryAttemptStart, OnRetryAttemptEnd);
 
+define_event!(OnRetryBudgetExhausted, RetryBudgetSnapshot);
+
+type AttemptTimeout<E> = (Duration, Box<dyn Fn() -> E + Send + Sync>);
+
+/*
+    Where each attempt gets its frame from, either the same instance for every attempt
+    or a fresh one out of the factory for every attempt
+ */
+enum RetryFrameSource<T> {
+    Instance(T),
+    Factory(Box<dyn Fn() -> T + Send + Sync>),
+}
+
 #[derive(TypedBuilder)]
 #[builder(
     build_method(into = RetriableTaskFrame<T>),
@@ -234,30 +389,88 @@ pub struct RetriableTaskFrameConfig<T: TaskFrame> {
         default = Box::new(())
     )]
     when: Box<dyn RetryErrorFilter<T::Error>>,
+
+    /*
+        Bounds every attempt individually, an attempt exceeding it emits OnTimeout and
+        counts as a failure with the error produced by the supplied function
+     */
+    #[builder(
+        setter(transform = |duration: Duration, on_timeout: impl Fn() -> T::Error + Send + Sync + 'static|
+            Some((duration, Box::new(on_timeout) as Box<dyn Fn() -> T::Error + Send + Sync>))
+        ),
+        default = None
+    )]
+    per_attempt_timeout: Option<AttemptTimeout<T::Error>>,
+
+    /*
+        Every retry (not the first attempt) draws from this budget, once exhausted
+        the failing attempt is returned right away instead of being retried
+     */
+    #[builder(default, setter(strip_option))]
+    budget: Option<SharedRetryBudget>,
+
+    /*
+        Stops retrying once the deadline set by an outer frame (see RestrictTaskFrameContext::deadline)
+        has passed or would pass during the backoff, returning the last error instead
+     */
+    #[builder(default = false)]
+    respect_deadline: bool,
 }
 
 impl<T: TaskFrame> From<RetriableTaskFrameConfig<T>> for RetriableTaskFrame<T> {
     fn from(config: RetriableTaskFrameConfig<T>) -> Self {
         Self {
-            frame: config.frame,
+            frame: RetryFrameSource::Instance(config.frame),
             retries: config.retries,
             backoff_strat: config.backoff,
             when: config.when,
+            per_attempt_timeout: config.per_attempt_timeout,
+            budget: config.budget,
+            respect_deadline: config.respect_deadline,
         }
     }
 }
 
 pub struct RetriableTaskFrame<T: TaskFrame> {
-    frame: T,
+    frame: RetryFrameSource<T>,
     retries: NonZeroU32,
     backoff_strat: Box<dyn RetryBackoffStrategy>,
     when: Box<dyn RetryErrorFilter<T::Error>>,
+    per_attempt_timeout: Option<AttemptTimeout<T::Error>>,
+    budget: Option<SharedRetryBudget>,
+    respect_deadline: bool,
 }
 
 impl<T: TaskFrame> RetriableTaskFrame<T> {
     pub fn builder() -> RetriableTaskFrameConfigBuilder<T> {
         RetriableTaskFrameConfig::builder()
     }
+
+    /// Constructs a [`RetriableTaskFrame`] which builds a **fresh** inner frame out of ``factory`` for every
+    /// attempt (the first one included), instead of executing the same instance over and over.
+    ///
+    /// Prefer it over [`RetriableTaskFrame::builder`] when the inner frame holds single-use state
+    /// (e.g. a request body consumed on execution), so every retry starts clean. Frames without such
+    /// state should use the builder, as it avoids constructing a frame per attempt.
+    pub fn from_factory(
+        factory: impl Fn() -> T + Send + Sync + 'static,
+        retries: NonZeroU32,
+        backoff: impl RetryBackoffStrategy,
+    ) -> Self {
+        Self {
+            frame: RetryFrameSource::Factory(Box::new(factory)),
+            retries,
+            backoff_strat: Box::new(backoff),
+            when: Box::new(()),
+            per_attempt_timeout: None,
+            budget: None,
+            respect_deadline: false,
+        }
+    }
+
+    pub fn budget(&self) -> Option<&SharedRetryBudget> {
+        self.budget.as_ref()
+    }
 }
 
 impl<T: TaskFrame> TaskFrame for RetriableTaskFrame<T> {
@@ -271,7 +484,28 @@ impl<T: TaskFrame> TaskFrame for RetriableTaskFrame<T> {
         for retry in 0u32..=self.retries.get() {
             ctx.emit::<OnRetryAttemptStart>(&retry).await;
 
-            error = self.frame.execute(&ctx, &args).await;
+            let fresh;
+            let frame = match &self.frame {
+                RetryFrameSource::Instance(frame) => frame,
+                RetryFrameSource::Factory(factory) => {
+                    fresh = factory();
+                    &fresh
+                }
+            };
+
+            error = match &self.per_attempt_timeout {
+                Some((duration, on_timeout)) => {
+                    match tokio::time::timeout(*duration, frame.execute(ctx, args)).await {
+                        Ok(result) => result,
+                        Err(_) => {
+                            ctx.emit::<OnTimeout>(duration).await;
+                            Err(on_timeout())
+                        }
+                    }
+                }
+
+                None => frame.execute(ctx, args).await,
+            };
             let erased_err = error.as_ref().map_err(|x| x as &dyn TaskError).err();
 
             ctx.emit::<OnRetryAttemptEnd>(&(retry, erased_err)).await;
@@ -284,7 +518,21 @@ impl<T: TaskFrame> TaskFrame for RetriableTaskFrame<T> {
                 break;
             }
 
+            if let Some(budget) = &self.budget
+                && !budget.try_consume()
+            {
+                ctx.emit::<OnRetryBudgetExhausted>(&budget.snapshot()).await;
+                break;
+            }
+
             let delay = self.backoff_strat.compute(retry);
+            if self.respect_deadline
+                && let Some(deadline) = ctx.deadline()
+                && tokio::time::Instant::now() + delay >= deadline
+            {
+                break;
+            }
+
             if !delay.is_zero() {
                 tokio::time::sleep(delay).await;
             } else {
@@ -294,4 +542,13 @@ impl<T: TaskFrame> TaskFrame for RetriableTaskFrame<T> {
 
         error
     }
+
+    fn describe(&self) -> FrameNode {
+        let inner = match &self.frame {
+            RetryFrameSource::Instance(frame) => frame.describe(),
+            RetryFrameSource::Factory(factory) => factory().describe(),
+        };
+
+        FrameNode::new("Retry", vec![inner])
+    }
 }
@@ -0,0 +1,145 @@
+use crate::task::{FrameNode, TaskFrame, TaskFrameContext, TaskHookEvent};
+use crate::utils::macros::define_event;
+use crate::utils::{RandomSource, ThreadRandomSource};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use typed_builder::TypedBuilder;
+
+/// [`RolloutPercentage`] is the live-adjustable share (in percent) of executions a [`RolloutTaskFrame`]
+/// routes to its new frame. Clones share the same value, so a handle kept aside (e.g. by an admin endpoint)
+/// adjusts every frame built with it.
+///
+/// Values are clamped to ``[0, 100]`` when set, ``NaN`` counts as ``0``.
+#[derive(Debug, Clone)]
+pub struct RolloutPercentage(Arc<AtomicU64>);
+
+impl RolloutPercentage {
+    pub fn new(percentage: f64) -> Self {
+        Self(Arc::new(AtomicU64::new(clamp_percentage(percentage).to_bits())))
+    }
+
+    pub fn get(&self) -> f64 {
+        f64::from_bits(self.0.load(Ordering::Relaxed))
+    }
+
+    pub fn set(&self, percentage: f64) {
+        self.0.store(clamp_percentage(percentage).to_bits(), Ordering::Relaxed);
+    }
+}
+
+impl Default for RolloutPercentage {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+fn clamp_percentage(percentage: f64) -> f64 {
+    match percentage.is_nan() {
+        true => 0.0,
+        false => percentage.clamp(0.0, 100.0),
+    }
+}
+
+/// The branch a [`RolloutTaskFrame`] routed an execution to, as emitted via [`OnRolloutBranch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RolloutBranch {
+    New,
+    Old,
+}
+
+define_event!(OnRolloutBranch, RolloutBranch);
+
+#[derive(TypedBuilder)]
+#[builder(build_method(into = RolloutTaskFrame<N, O>))]
+pub struct RolloutTaskFrameConfig<N: TaskFrame, O: TaskFrame> {
+    new: N,
+    old: O,
+
+    // The share of executions routed to the new frame, starting with none of them
+    #[builder(default)]
+    percentage: RolloutPercentage,
+}
+
+impl<N: TaskFrame, O: TaskFrame> From<RolloutTaskFrameConfig<N, O>> for RolloutTaskFrame<N, O> {
+    fn from(config: RolloutTaskFrameConfig<N, O>) -> Self {
+        Self {
+            new: config.new,
+            old: config.old,
+            percentage: config.percentage,
+            random: Box::new(ThreadRandomSource),
+        }
+    }
+}
+
+/// [`RolloutTaskFrame`] stages the rollout of a change by running its new frame for a [`RolloutPercentage`]
+/// of executions and its old frame for the rest, reporting the branch via [`OnRolloutBranch`]. Every execution
+/// is routed independently off a fresh random draw and reads the percentage anew, so adjusting it takes effect
+/// from the next execution of every frame sharing it.
+///
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::{RolloutPercentage, RolloutTaskFrame};
+/// let billing = |name: &'static str| DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+///     println!("billed by the {name} frame");
+///     async { Ok::<_, String>(()) }
+/// });
+///
+/// let percentage = RolloutPercentage::new(5.0);
+/// let frame = RolloutTaskFrame::builder()
+///     .new(billing("new"))
+///     .old(billing("legacy"))
+///     .percentage(percentage.clone())
+///     .build();
+///
+/// // Later on, once the new frame proved itself
+/// percentage.set(50.0);
+/// assert_eq!(frame.percentage().get(), 50.0);
+/// ```
+pub struct RolloutTaskFrame<N: TaskFrame, O: TaskFrame> {
+    new: N,
+    old: O,
+    percentage: RolloutPercentage,
+    random: Box<dyn RandomSource>,
+}
+
+impl<N: TaskFrame, O: TaskFrame> RolloutTaskFrame<N, O> {
+    pub fn builder() -> RolloutTaskFrameConfigBuilder<N, O> {
+        RolloutTaskFrameConfig::builder()
+    }
+
+    pub fn with_random_source(mut self, random: impl RandomSource) -> Self {
+        self.random = Box::new(random);
+        self
+    }
+
+    pub fn percentage(&self) -> &RolloutPercentage {
+        &self.percentage
+    }
+}
+
+impl<N, O> TaskFrame for RolloutTaskFrame<N, O>
+where
+    N: TaskFrame,
+    O: TaskFrame<Args = N::Args, Error = N::Error>,
+{
+    type Error = N::Error;
+    type Args = N::Args;
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
+        let branch = match self.random.f64() * 100.0 < self.percentage.get() {
+            true => RolloutBranch::New,
+            false => RolloutBranch::Old,
+        };
+
+        ctx.emit::<OnRolloutBranch>(&branch).await;
+        match branch {
+            RolloutBranch::New => self.new.execute(ctx, args).await,
+            RolloutBranch::Old => self.old.execute(ctx, args).await,
+        }
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("Rollout", vec![self.new.describe(), self.old.describe()])
+    }
+}
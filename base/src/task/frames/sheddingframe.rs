@@ -0,0 +1,149 @@
+use crate::task::{FrameNode, TaskFrame, TaskFrameContext, TaskHookEvent};
+use crate::utils::macros::{define_event, define_event_group};
+use crate::utils::{RandomSource, ThreadRandomSource};
+use std::collections::VecDeque;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use typed_builder::TypedBuilder;
+
+define_event!(OnSheddingActivated, f64);
+
+define_event!(OnSheddingDeactivated, f64);
+
+define_event_group!(SheddingEvents, f64 | OnSheddingActivated, OnSheddingDeactivated);
+
+#[derive(TypedBuilder)]
+#[builder(build_method(into = AdaptiveSheddingTaskFrame<T, F>))]
+pub struct AdaptiveSheddingFrameConfig<T: TaskFrame, F: TaskFrame> {
+    frame: T,
+    fallback: F,
+
+    #[builder(default = NonZeroUsize::new(20).unwrap())]
+    window: NonZeroUsize,
+
+    #[builder(default = 0.5)]
+    threshold: f64,
+
+    #[builder(default = 0.9)]
+    max_shed_probability: f64,
+}
+
+impl<T: TaskFrame, F: TaskFrame> From<AdaptiveSheddingFrameConfig<T, F>>
+    for AdaptiveSheddingTaskFrame<T, F>
+{
+    fn from(config: AdaptiveSheddingFrameConfig<T, F>) -> Self {
+        Self {
+            frame: config.frame,
+            fallback: config.fallback,
+            window: config.window,
+            threshold: config.threshold.clamp(0.0, 1.0),
+            max_shed_probability: config.max_shed_probability.clamp(0.0, 1.0),
+            random: Box::new(ThreadRandomSource),
+            outcomes: parking_lot::Mutex::new(VecDeque::with_capacity(config.window.get())),
+            shedding: AtomicBool::new(false),
+        }
+    }
+}
+
+/// [`AdaptiveSheddingTaskFrame`] tracks the success rate of its frame over the last ``window`` executions,
+/// and while that rate sits below ``threshold`` it diverts executions to the fallback frame with a probability
+/// of ``1 - rate`` (capped at ``max_shed_probability``, so the frame keeps being probed and can recover).
+/// Shedding turning on and off is reported through the [`SheddingEvents`].
+///
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::AdaptiveSheddingTaskFrame;
+/// # use std::num::NonZeroUsize;
+/// let frame = AdaptiveSheddingTaskFrame::builder()
+///     .frame(DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async {
+///         Err::<(), _>("prices are unavailable".to_owned())
+///     }))
+///     .fallback(DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }))
+///     .window(NonZeroUsize::new(50).unwrap())
+///     .threshold(0.8)
+///     .build();
+/// assert_eq!(frame.success_rate(), None);
+/// ```
+pub struct AdaptiveSheddingTaskFrame<T: TaskFrame, F: TaskFrame> {
+    frame: T,
+    fallback: F,
+    window: NonZeroUsize,
+    threshold: f64,
+    max_shed_probability: f64,
+    random: Box<dyn RandomSource>,
+    outcomes: parking_lot::Mutex<VecDeque<bool>>,
+    shedding: AtomicBool,
+}
+
+impl<T: TaskFrame, F: TaskFrame> AdaptiveSheddingTaskFrame<T, F> {
+    pub fn builder() -> AdaptiveSheddingFrameConfigBuilder<T, F> {
+        AdaptiveSheddingFrameConfig::builder()
+    }
+
+    pub fn with_random_source(mut self, random: impl RandomSource) -> Self {
+        self.random = Box::new(random);
+        self
+    }
+
+    pub fn success_rate(&self) -> Option<f64> {
+        let outcomes = self.outcomes.lock();
+        if outcomes.len() < self.window.get() {
+            return None;
+        }
+
+        let successes = outcomes.iter().filter(|x| **x).count();
+        Some(successes as f64 / outcomes.len() as f64)
+    }
+
+    fn record(&self, success: bool) -> Option<f64> {
+        {
+            let mut outcomes = self.outcomes.lock();
+            if outcomes.len() == self.window.get() {
+                outcomes.pop_front();
+            }
+            outcomes.push_back(success);
+        }
+
+        self.success_rate()
+    }
+}
+
+impl<T, F> TaskFrame for AdaptiveSheddingTaskFrame<T, F>
+where
+    T: TaskFrame,
+    F: TaskFrame<Args = T::Args, Error = T::Error>,
+{
+    type Error = T::Error;
+    type Args = T::Args;
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
+        if self.shedding.load(Ordering::Relaxed) {
+            let rate = self.success_rate().unwrap_or(1.0);
+            let probability = (1.0 - rate).min(self.max_shed_probability);
+
+            if self.random.f64() < probability {
+                return self.fallback.execute(ctx, args).await;
+            }
+        }
+
+        let result = self.frame.execute(ctx, args).await;
+
+        if let Some(rate) = self.record(result.is_ok()) {
+            let should_shed = rate < self.threshold;
+            if self.shedding.swap(should_shed, Ordering::Relaxed) != should_shed {
+                if should_shed {
+                    ctx.emit::<OnSheddingActivated>(&rate).await;
+                } else {
+                    ctx.emit::<OnSheddingDeactivated>(&rate).await;
+                }
+            }
+        }
+
+        result
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("AdaptiveShedding", vec![self.frame.describe(), self.fallback.describe()])
+    }
+}
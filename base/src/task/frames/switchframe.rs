@@ -0,0 +1,150 @@
+use crate::errors::TaskError;
+use crate::task::{DynTaskFrame, FrameNode, RestrictTaskFrameContext, TaskFrame, TaskFrameContext, TaskHookEvent};
+use crate::utils::macros::define_event;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// The discriminant [`SwitchTaskFrame`] picks its branch by, either a string or an integer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SwitchKey {
+    Str(Cow<'static, str>),
+    Int(i64),
+}
+
+impl Display for SwitchKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SwitchKey::Str(value) => write!(f, "{value:?}"),
+            SwitchKey::Int(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<&'static str> for SwitchKey {
+    fn from(value: &'static str) -> Self {
+        SwitchKey::Str(Cow::Borrowed(value))
+    }
+}
+
+impl From<String> for SwitchKey {
+    fn from(value: String) -> Self {
+        SwitchKey::Str(Cow::Owned(value))
+    }
+}
+
+impl From<i64> for SwitchKey {
+    fn from(value: i64) -> Self {
+        SwitchKey::Int(value)
+    }
+}
+
+impl From<i32> for SwitchKey {
+    fn from(value: i32) -> Self {
+        SwitchKey::Int(value as i64)
+    }
+}
+
+define_event!(OnSwitchBranch, Option<SwitchKey>);
+
+type SwitchDiscriminant = Box<dyn Fn(&RestrictTaskFrameContext) -> Option<SwitchKey> + Send + Sync>;
+
+/// [`SwitchTaskFrame`] is the multi-way counterpart of [`ConditionalTaskFrame`](crate::task::ConditionalTaskFrame),
+/// on every execution its discriminant function reads a [`SwitchKey`] off the context and the branch registered
+/// under it runs, or the default branch when there is no key or no matching branch ([`OnSwitchBranch`] reports
+/// which). Branches may be of different TaskFrame types as long as their error and argument types match, and
+/// string keys never match integer keys (``"1"`` is not ``1``).
+///
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::{RestrictTaskFrameContext, SwitchTaskFrame};
+/// struct Order {
+///     status: String,
+/// }
+///
+/// let handler = |name: &'static str| DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+///     println!("handled by {name}");
+///     async { Ok::<_, String>(()) }
+/// });
+///
+/// let frame = SwitchTaskFrame::new(
+///     |ctx: &RestrictTaskFrameContext| ctx.input::<Order>().map(|order| order.status.clone().into()),
+///     handler("unknown status"),
+/// )
+/// .case("pending", handler("charge"))
+/// .case("shipped", handler("notify"));
+/// ```
+pub struct SwitchTaskFrame<E: TaskError, A: Send + Sync + 'static = ()> {
+    discriminant: SwitchDiscriminant,
+    cases: HashMap<SwitchKey, usize>,
+    branches: Vec<(SwitchKey, Box<dyn DynTaskFrame<E, A>>)>,
+    default: Box<dyn DynTaskFrame<E, A>>,
+}
+
+impl<E: TaskError, A: Send + Sync + 'static> SwitchTaskFrame<E, A> {
+    pub fn new(
+        discriminant: impl Fn(&RestrictTaskFrameContext) -> Option<SwitchKey> + Send + Sync + 'static,
+        default: impl TaskFrame<Error = E, Args = A>,
+    ) -> Self {
+        Self {
+            discriminant: Box::new(discriminant),
+            cases: HashMap::new(),
+            branches: Vec::new(),
+            default: Box::new(default),
+        }
+    }
+
+    /// Registers ``frame`` as the branch executed for ``key``, replacing any branch already registered under it.
+    pub fn case(mut self, key: impl Into<SwitchKey>, frame: impl TaskFrame<Error = E, Args = A>) -> Self {
+        let key = key.into();
+        let frame = Box::new(frame) as Box<dyn DynTaskFrame<E, A>>;
+
+        match self.cases.get(&key) {
+            Some(idx) => self.branches[*idx].1 = frame,
+            None => {
+                self.cases.insert(key.clone(), self.branches.len());
+                self.branches.push((key, frame));
+            }
+        }
+
+        self
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &SwitchKey> {
+        self.branches.iter().map(|(key, _)| key)
+    }
+}
+
+impl<E: TaskError, A: Send + Sync + 'static> TaskFrame for SwitchTaskFrame<E, A> {
+    type Error = E;
+    type Args = A;
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
+        let matched = (self.discriminant)(ctx)
+            .and_then(|key| self.cases.get(&key).map(|idx| &self.branches[*idx]));
+
+        match matched {
+            Some((key, frame)) => {
+                ctx.emit::<OnSwitchBranch>(&Some(key.clone())).await;
+                frame.erased_execute(ctx, args).await
+            }
+
+            None => {
+                ctx.emit::<OnSwitchBranch>(&None).await;
+                self.default.erased_execute(ctx, args).await
+            }
+        }
+    }
+
+    fn describe(&self) -> FrameNode {
+        let mut children: Vec<FrameNode> = self
+            .branches
+            .iter()
+            .map(|(_, frame)| frame.erased().erased_describe())
+            .collect();
+        children.push(self.default.erased().erased_describe());
+
+        FrameNode::new("Switch", children)
+    }
+}
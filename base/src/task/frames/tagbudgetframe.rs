@@ -0,0 +1,116 @@
+use crate::task::{FrameNode, TaskFrame, TaskFrameContext};
+use dashmap::DashMap;
+use std::sync::{Arc, LazyLock};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+static TAG_BUDGETS: LazyLock<DashMap<String, TagBudget>> = LazyLock::new(DashMap::new);
+
+#[derive(Clone)]
+struct TagBudget {
+    permits: usize,
+    semaphore: Arc<Semaphore>,
+}
+
+/// [`TagBudgets`] is the process-wide registry of concurrency budgets consulted by [`TagBudgetTaskFrame`],
+/// a budget caps how many executions of frames tagged with it run at once, across every Task (e.g. all
+/// ``db-heavy`` Tasks sharing 10 permits).
+///
+/// Tags without a budget are unlimited, so frames may be tagged before their budget is set and vice versa.
+pub struct TagBudgets;
+
+impl TagBudgets {
+    /// Sets the budget of ``tag`` to ``permits`` concurrent executions, taking effect from the next
+    /// acquisition. Executions already holding (or waiting on) a permit of the previous budget keep
+    /// counting against it until they finish.
+    pub fn set(tag: &str, permits: usize) {
+        TAG_BUDGETS.insert(
+            tag.to_owned(),
+            TagBudget {
+                permits,
+                semaphore: Arc::new(Semaphore::new(permits)),
+            },
+        );
+    }
+
+    /// Removes the budget of ``tag``, leaving it unlimited from the next acquisition.
+    pub fn remove(tag: &str) {
+        TAG_BUDGETS.remove(tag);
+    }
+
+    /// The number of concurrent executions the budget of ``tag`` allows, ``None`` when it is unlimited.
+    pub fn permits(tag: &str) -> Option<usize> {
+        TAG_BUDGETS.get(tag).map(|budget| budget.permits)
+    }
+
+    /// The number of permits of ``tag`` currently not held by any execution, ``None`` when it is unlimited.
+    pub fn available(tag: &str) -> Option<usize> {
+        TAG_BUDGETS
+            .get(tag)
+            .map(|budget| budget.semaphore.available_permits())
+    }
+
+    fn semaphore(tag: &str) -> Option<Arc<Semaphore>> {
+        TAG_BUDGETS.get(tag).map(|budget| budget.semaphore.clone())
+    }
+}
+
+/// [`TagBudgetTaskFrame`] holds a permit from the budget of each of its tags (see [`TagBudgets`]) while its
+/// frame runs, waiting for one to free up when a budget is exhausted, and releases them even when the execution
+/// is cancelled midway. Tags without a budget are skipped. Permits are always acquired in the lexicographic order
+/// of the tags, so two executions can never each hold a permit the other waits on.
+///
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::{TagBudgetTaskFrame, TagBudgets};
+/// TagBudgets::set("network", 4);
+/// TagBudgets::set("db-heavy", 10);
+///
+/// let reindex = DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) });
+/// let frame = TagBudgetTaskFrame::new(reindex, ["network", "db-heavy"]);
+/// assert_eq!(frame.tags(), ["db-heavy", "network"]);
+/// ```
+pub struct TagBudgetTaskFrame<T: TaskFrame> {
+    frame: T,
+    tags: Vec<String>,
+}
+
+impl<T: TaskFrame> TagBudgetTaskFrame<T> {
+    pub fn new(frame: T, tags: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let mut tags = tags.into_iter().map(Into::into).collect::<Vec<String>>();
+        tags.sort();
+        tags.dedup();
+
+        Self { frame, tags }
+    }
+
+    /// The tags of the frame, in the order their budgets are acquired in.
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+}
+
+impl<T: TaskFrame> TaskFrame for TagBudgetTaskFrame<T> {
+    type Error = T::Error;
+    type Args = T::Args;
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
+        let mut permits = Vec::<OwnedSemaphorePermit>::with_capacity(self.tags.len());
+        for tag in &self.tags {
+            let Some(semaphore) = TagBudgets::semaphore(tag) else {
+                continue;
+            };
+
+            // The semaphore is never closed, the budget being replaced only detaches it from the registry
+            if let Ok(permit) = semaphore.acquire_owned().await {
+                permits.push(permit);
+            }
+        }
+
+        self.frame.execute(ctx, args).await
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("TagBudget", vec![self.frame.describe()])
+    }
+}
@@ -1,5 +1,5 @@
 use crate::errors::TaskError;
-use crate::task::{RestrictTaskFrameContext, TaskFrame, TaskFrameContext};
+use crate::task::{FrameNode, RestrictTaskFrameContext, TaskFrame, TaskFrameContext};
 use async_trait::async_trait;
 use std::num::NonZeroUsize;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -120,4 +120,8 @@ impl<T: TaskFrame> TaskFrame for ThresholdTaskFrame<T> {
 
         res
     }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("Threshold", vec![self.frame.describe()])
+    }
 }
@@ -1,7 +1,8 @@
 use std::marker::PhantomData;
-use crate::errors::TaskError;
+use crate::errors::{ChronographerErrors, TaskError};
 use crate::task::TaskFrame;
-use crate::task::{TaskFrameContext, TaskHookEvent};
+use crate::task::{FrameNode, TaskFrameContext, TaskHookEvent};
+use crate::task::frames::with_deadline;
 use crate::utils::macros::define_event;
 use std::time::Duration;
 
@@ -17,6 +18,32 @@ impl DefaultTimeoutError for String {
     }
 }
 
+impl DefaultTimeoutError for ChronographerErrors {
+    fn default_timeout_error() -> Self {
+        ChronographerErrors::TimedOut
+    }
+}
+
+impl DefaultTimeoutError for Box<dyn TaskError> {
+    fn default_timeout_error() -> Self {
+        Box::new(ChronographerErrors::TimedOut)
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl DefaultTimeoutError for anyhow::Error {
+    fn default_timeout_error() -> Self {
+        anyhow::Error::new(ChronographerErrors::TimedOut)
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl DefaultTimeoutError for eyre::Report {
+    fn default_timeout_error() -> Self {
+        eyre::Report::new(ChronographerErrors::TimedOut)
+    }
+}
+
 #[doc(hidden)]
 pub struct TimeoutMissingBuilder(());
 
@@ -218,7 +245,11 @@ impl<T: TaskFrame> TaskFrame for TimeoutTaskFrame<T> {
 
     async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
         let duration = (self.max_duration)();
-        let result = tokio::time::timeout(duration, self.frame.execute(ctx, &args)).await;
+        let execution = self.frame.execute(ctx, &args);
+        let result = match tokio::time::Instant::now().checked_add(duration) {
+            Some(deadline) => tokio::time::timeout(duration, with_deadline(deadline, execution)).await,
+            None => tokio::time::timeout(duration, execution).await,
+        };
 
         if let Ok(inner) = result {
             return inner;
@@ -227,4 +258,8 @@ impl<T: TaskFrame> TaskFrame for TimeoutTaskFrame<T> {
         ctx.emit::<OnTimeout>(&duration).await;
         Err((self.on_timeout)())
     }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("Timeout", vec![self.frame.describe()])
+    }
 }
\ No newline at end of file
@@ -0,0 +1,142 @@
+use crate::task::frames::with_trace_context;
+use crate::task::{FrameNode, TaskFrame, TaskFrameContext};
+use crate::utils::{RandomSource, ThreadRandomSource};
+use std::fmt::{Display, Formatter};
+
+/// The well-known key a [`TraceContext`] is propagated under, following the W3C Trace Context
+/// specification (e.g. as an HTTP header or a gRPC metadata entry).
+pub const TRACEPARENT_KEY: &str = "traceparent";
+
+/// [`TraceContext`] identifies the trace an execution belongs to alongside its own span within it,
+/// it is set by [`TraceContextTaskFrame`] and read via [`RestrictTaskFrameContext::trace_context`](crate::task::RestrictTaskFrameContext::trace_context).
+///
+/// It renders (via [`Display`]) as a W3C ``traceparent`` value, ready to be attached to outgoing
+/// calls under [`TRACEPARENT_KEY`], and can be parsed back via [`TraceContext::from_traceparent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TraceContext {
+    trace_id: u128,
+    span_id: u64,
+    parent_span_id: Option<u64>,
+}
+
+impl TraceContext {
+    /// Starts a new trace with a random trace id and span id.
+    pub fn generate(random: &dyn RandomSource) -> Self {
+        let trace_id = ((random_u64(random) as u128) << 64) | random_u64(random) as u128;
+
+        Self {
+            trace_id,
+            span_id: random_u64(random),
+            parent_span_id: None,
+        }
+    }
+
+    /// Continues the trace with a new random span id, whose parent is the span of this context.
+    pub fn child(&self, random: &dyn RandomSource) -> Self {
+        Self {
+            trace_id: self.trace_id,
+            span_id: random_u64(random),
+            parent_span_id: Some(self.span_id),
+        }
+    }
+
+    /// Parses a W3C ``traceparent`` value (``00-<trace id>-<span id>-<flags>``), returning ``None``
+    /// when it is malformed or either id is all zeroes. The parsed span becomes the parent of the
+    /// frames continuing it.
+    pub fn from_traceparent(value: &str) -> Option<Self> {
+        let mut parts = value.trim().split('-');
+        let (version, trace_id, span_id, flags) = (parts.next()?, parts.next()?, parts.next()?, parts.next()?);
+        if parts.next().is_some() || version != "00" || trace_id.len() != 32 || span_id.len() != 16 || flags.len() != 2 {
+            return None;
+        }
+
+        let trace_id = u128::from_str_radix(trace_id, 16).ok().filter(|id| *id != 0)?;
+        let span_id = u64::from_str_radix(span_id, 16).ok().filter(|id| *id != 0)?;
+        u8::from_str_radix(flags, 16).ok()?;
+
+        Some(Self {
+            trace_id,
+            span_id,
+            parent_span_id: None,
+        })
+    }
+
+    pub fn trace_id(&self) -> u128 {
+        self.trace_id
+    }
+
+    pub fn span_id(&self) -> u64 {
+        self.span_id
+    }
+
+    pub fn parent_span_id(&self) -> Option<u64> {
+        self.parent_span_id
+    }
+
+    pub fn traceparent(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for TraceContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "00-{:032x}-{:016x}-01", self.trace_id, self.span_id)
+    }
+}
+
+// W3C Trace Context forbids all-zero ids
+fn random_u64(random: &dyn RandomSource) -> u64 {
+    random.u64_below(u64::MAX).max(1)
+}
+
+/// [`TraceContextTaskFrame`] runs its frame within a fresh span, so the calls it makes downstream (HTTP,
+/// gRPC... etc.) can carry the trace on. The span continues the trace of an outer [`TraceContextTaskFrame`]
+/// if there is one, otherwise a [`TraceContext`] supplied as the input of the Task (e.g. parsed off an incoming
+/// request), otherwise a newly generated trace. Like deadlines, it isn't carried into tasks spawned by a frame.
+///
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::{TraceContextTaskFrame, TRACEPARENT_KEY};
+/// let frame = TraceContextTaskFrame::new(DynamicTaskFrame::new(|ctx: &TaskFrameContext, _args: &()| {
+///     let trace = ctx.trace_context().expect("the frame always sets a trace context");
+///     println!("{TRACEPARENT_KEY}: {}", trace.traceparent());
+///     async { Ok::<_, String>(()) }
+/// }));
+/// ```
+pub struct TraceContextTaskFrame<T: TaskFrame> {
+    frame: T,
+    random: Box<dyn RandomSource>,
+}
+
+impl<T: TaskFrame> TraceContextTaskFrame<T> {
+    pub fn new(frame: T) -> Self {
+        Self {
+            frame,
+            random: Box::new(ThreadRandomSource),
+        }
+    }
+
+    pub fn with_random_source(mut self, random: impl RandomSource) -> Self {
+        self.random = Box::new(random);
+        self
+    }
+}
+
+impl<T: TaskFrame> TaskFrame for TraceContextTaskFrame<T> {
+    type Error = T::Error;
+    type Args = T::Args;
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
+        let trace = match ctx.trace_context().or_else(|| ctx.input::<TraceContext>().map(|trace| *trace)) {
+            Some(parent) => parent.child(self.random.as_ref()),
+            None => TraceContext::generate(self.random.as_ref()),
+        };
+
+        with_trace_context(trace, self.frame.execute(ctx, args)).await
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::new("TraceContext", vec![self.frame.describe()])
+    }
+}
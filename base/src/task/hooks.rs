@@ -1,3 +1,11 @@
+pub mod duration; // skipcq: RS-D1001
+pub mod forwarding; // skipcq: RS-D1001
+pub mod rolling; // skipcq: RS-D1001
+
+pub use duration::*;
+pub use forwarding::*;
+pub use rolling::*;
+
 use crate::errors::TaskError;
 #[allow(unused_imports)]
 use crate::task::frames::*;
@@ -8,26 +16,44 @@ use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::{Arc, LazyLock};
+use std::time::{Duration, SystemTime};
 use crate::task::{Sealed, TaskHookLayer};
 
 pub mod events {
     pub use crate::task::OnTaskEnd;
     pub use crate::task::OnTaskStart;
+    pub use crate::task::OnTaskReschedule;
+    pub use crate::task::OnTaskSkipped;
+    pub use crate::task::OnTaskExpired;
     pub use crate::task::frames::ChildTaskFrameEvents;
     pub use crate::task::frames::ConditionalPredicateEvents;
     pub use crate::task::frames::DelayEvents;
+    pub use crate::task::frames::OnDeadlineExceeded;
     pub use crate::task::frames::OnChildTaskFrameEnd;
     pub use crate::task::frames::OnChildTaskFrameStart;
     pub use crate::task::frames::OnDelayEnd;
     pub use crate::task::frames::OnDelayStart;
     pub use crate::task::frames::OnDependencyValidation;
+    pub use crate::task::frames::OnErrorBurst;
     pub use crate::task::frames::OnFallbackEvent;
+    pub use crate::task::frames::OnHealthGateOpen;
+    pub use crate::task::frames::OnKillSwitchTripped;
+    pub use crate::task::frames::OnLeadershipSkipped;
+    pub use crate::task::frames::OnPreconditionFailed;
+    pub use crate::task::frames::OnQuorumReached;
     pub use crate::task::frames::OnFalseyValueEvent;
     pub use crate::task::frames::OnRetryAttemptEnd;
     pub use crate::task::frames::OnRetryAttemptStart;
+    pub use crate::task::frames::OnRetryBudgetExhausted;
+    pub use crate::task::frames::OnRolloutBranch;
     pub use crate::task::frames::OnTimeout;
+    pub use crate::task::frames::OnTaskFrameSelection;
     pub use crate::task::frames::OnTruthyValueEvent;
     pub use crate::task::frames::RetryAttemptEvents;
+    pub use crate::task::frames::OnSheddingActivated;
+    pub use crate::task::frames::OnSheddingDeactivated;
+    pub use crate::task::frames::SheddingEvents;
+    pub use crate::task::frames::OnSwitchBranch;
     pub use crate::task::hooks::OnHookAttach;
     pub use crate::task::hooks::OnHookDetach;
     pub use crate::task::hooks::TaskHookEvent;
@@ -86,6 +112,15 @@ impl TaskHookInstances {
         }
     }
 
+    #[inline(always)]
+    fn len(&self) -> usize {
+        match self {
+            TaskHookInstances::Empty => 0,
+            TaskHookInstances::Single(_) => 1,
+            TaskHookInstances::Multiple(hooks) => hooks.len(),
+        }
+    }
+
     #[inline(always)]
     fn pop(&mut self) -> Option<&'static dyn ErasedTaskHook> {
         match std::mem::take(self) {
@@ -116,6 +151,19 @@ pub(crate) enum TaskHooksPromotion {
 }
 
 impl TaskHooksPromotion {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        match self {
+            TaskHooksPromotion::Empty => 0,
+            TaskHooksPromotion::Single(_, hooks) => hooks.len(),
+            TaskHooksPromotion::Double((_, hooks1), (_, hooks2)) => hooks1.len() + hooks2.len(),
+            TaskHooksPromotion::Triplet((_, hooks1), (_, hooks2), (_, hooks3)) => {
+                hooks1.len() + hooks2.len() + hooks3.len()
+            }
+            TaskHooksPromotion::Multiple(map) => map.values().map(TaskHookInstances::len).sum(),
+        }
+    }
+
     #[inline(always)]
     fn promote(&mut self, hook_id: TypeId, hook: &'static dyn ErasedTaskHook) {
         match self {
@@ -224,74 +272,81 @@ impl TaskHooksPromotion {
     #[inline(always)]
     fn remove(&mut self, hook_id: TypeId) -> Option<&'static dyn ErasedTaskHook> {
         match self {
+            TaskHooksPromotion::Single(id, instances) => {
+                if *id != hook_id {
+                    return None;
+                }
+
+                let instance = instances.pop();
+                if matches!(instances, TaskHookInstances::Empty) {
+                    *self = TaskHooksPromotion::Empty;
+                }
+
+                instance
+            }
+
             TaskHooksPromotion::Double(
                 (id1, instances1),
                 (id2, instances2)
             ) => {
-                if *id1 == hook_id {
-                    if let Some(instance) = instances1.pop() {
-                        return Some(instance)
-                    }
-
-                    let hook2 = std::mem::take(instances2);
-                    *self = TaskHooksPromotion::Single(*id2, hook2);
+                let instance = if *id1 == hook_id {
+                    instances1.pop()
                 } else if *id2 == hook_id {
-                    if let Some(instance) = instances2.pop() {
-                        return Some(instance)
-                    }
+                    instances2.pop()
+                } else {
+                    return None;
+                };
 
-                    let hook1 = std::mem::take(instances1);
-                    *self = TaskHooksPromotion::Single(*id1, hook1);
+                if matches!(instances1, TaskHookInstances::Empty) {
+                    *self = TaskHooksPromotion::Single(*id2, std::mem::take(instances2));
+                } else if matches!(instances2, TaskHookInstances::Empty) {
+                    *self = TaskHooksPromotion::Single(*id1, std::mem::take(instances1));
                 }
 
-                None
+                instance
             }
+
             TaskHooksPromotion::Triplet(
                 (id1, instances1),
                 (id2, instances2),
                 (id3, instances3)
             ) => {
-                if *id1 == hook_id {
-                    if let Some(instance) = instances1.pop() {
-                        return Some(instance)
-                    }
+                let instance = if *id1 == hook_id {
+                    instances1.pop()
+                } else if *id2 == hook_id {
+                    instances2.pop()
+                } else if *id3 == hook_id {
+                    instances3.pop()
+                } else {
+                    return None;
+                };
 
-                    let hooks2 = std::mem::take(instances2);
-                    let hooks3 = std::mem::take(instances3);
+                if matches!(instances1, TaskHookInstances::Empty) {
                     *self = TaskHooksPromotion::Double(
-                        (*id2, hooks2),
-                        (*id3, hooks3)
+                        (*id2, std::mem::take(instances2)),
+                        (*id3, std::mem::take(instances3))
                     );
-                } else if *id2 == hook_id {
-                    if let Some(instance) = instances2.pop() {
-                        return Some(instance)
-                    }
-
-                    let hooks1 = std::mem::take(instances1);
-                    let hooks3 = std::mem::take(instances3);
+                } else if matches!(instances2, TaskHookInstances::Empty) {
                     *self = TaskHooksPromotion::Double(
-                        (*id1, hooks1),
-                        (*id3, hooks3)
+                        (*id1, std::mem::take(instances1)),
+                        (*id3, std::mem::take(instances3))
                     );
-
-                } else if *id3 == hook_id {
-                    if let Some(instance) = instances3.pop() {
-                        return Some(instance)
-                    }
-
-                    let hooks1 = std::mem::take(instances1);
-                    let hooks2 = std::mem::take(instances2);
+                } else if matches!(instances3, TaskHookInstances::Empty) {
                     *self = TaskHooksPromotion::Double(
-                        (*id1, hooks1),
-                        (*id2, hooks2)
+                        (*id1, std::mem::take(instances1)),
+                        (*id2, std::mem::take(instances2))
                     );
                 }
 
-                None
+                instance
             }
 
             TaskHooksPromotion::Multiple(map) => {
-                let instance = map.remove(&hook_id)?.pop();
+                let instances = map.get_mut(&hook_id)?;
+                let instance = instances.pop();
+                if matches!(instances, TaskHookInstances::Empty) {
+                    map.remove(&hook_id);
+                }
 
                 if map.len() == 3 {
                     let mut drained = map.drain();
@@ -309,10 +364,7 @@ impl TaskHooksPromotion {
                 instance
             }
 
-            _ => {
-                *self = TaskHooksPromotion::Empty;
-                None
-            }
+            TaskHooksPromotion::Empty => None
         }
     }
 }
@@ -320,18 +372,22 @@ impl TaskHooksPromotion {
 pub(crate) struct TaskHookContainer(pub DashMap<(TypeId, usize), TaskHooksPromotion>);
 
 impl TaskHookContainer {
-    pub fn attach<E: TaskHookEvent>(
-        &self,
-        ctx: &TaskHookContext,
-        hook: Arc<impl TaskHook<E>>,
-    ) -> impl Future<Output = ()> + Send {
-        let hook_id = hook.type_id();
+    fn register<E: TaskHookEvent, T: TaskHook<E>>(&self, ctx: &TaskHookContext, hook: Arc<T>) {
+        let hook_id = TypeId::of::<T>();
         let erased_hook: &'static dyn ErasedTaskHook =
-            Box::leak(Box::new(ErasedTaskHookWrapper::<E>::new(hook.clone())));
+            Box::leak(Box::new(ErasedTaskHookWrapper::<E>::new(hook)));
 
         self.0.entry((TypeId::of::<E>(), ctx.0))
             .or_insert(TaskHooksPromotion::Empty)
             .promote(hook_id, erased_hook);
+    }
+
+    pub fn attach<E: TaskHookEvent>(
+        &self,
+        ctx: &TaskHookContext,
+        hook: Arc<impl TaskHook<E>>,
+    ) -> impl Future<Output = ()> + Send {
+        self.register::<E, _>(ctx, hook.clone());
 
         async move {
             self.emit::<OnHookAttach<E>>(ctx, &(hook.as_ref() as &dyn TaskHook<E>)).await;
@@ -346,6 +402,22 @@ impl TaskHookContainer {
         entry.as_any().downcast::<T>().ok()
     }
 
+    // Drops every hook attached to ``instance_id``, across all events
+    pub(crate) fn clear_instance(&self, instance_id: usize) {
+        self.0.retain(|(_, id), _| *id != instance_id);
+        IDLE_EMISSION_HOOKS.retain(|(_, id), _| *id != instance_id);
+    }
+
+    /*
+        The count is derived from the hooks stored under the entry rather than a separate counter,
+        attach / detach mutate the entry under its shard lock so the count can't drift from them
+     */
+    pub fn hook_count(&self, event_id: TypeId, instance_id: usize) -> usize {
+        self.0
+            .get(&(event_id, instance_id))
+            .map_or(0, |entry| entry.value().len())
+    }
+
     pub async fn detach<E: TaskHookEvent, T: TaskHook<E>>(&self, ctx: &TaskHookContext) {
         let Some(mut event_category) = self.0.get_mut(&(TypeId::of::<E>(), ctx.0)) else {
             return;
@@ -354,25 +426,13 @@ impl TaskHookContainer {
         let Some(hook) = event_category.remove(TypeId::of::<T>()) else {
             return;
         };
-
-        let typed: Arc<T> = match hook.as_any().downcast::<T>() {
-            Ok(typed) => typed,
-            Err(actual) => panic!(
-                "Failed to downcast stored TaskHook to expected concrete type '{}'. Event ID: '{}'. Expected TypeId: {:?}, actual TypeId: {:?}. \
-                Ensure the hook stored under this event is of the requested type and there are no type mismatches.",
-                std::any::type_name::<T>(),
-                std::any::type_name::<E>(),
-                TypeId::of::<T>(),
-                actual.as_ref().type_id()
-            ),
-        };
+        drop(event_category);
 
         let wrapper_ptr = hook as *const dyn ErasedTaskHook as *const ();
         let wrapper_ptr = wrapper_ptr as *mut ErasedTaskHookWrapper<E>;
         let wrapper_box = unsafe { Box::from_raw(wrapper_ptr) };
-        drop(wrapper_box);
 
-        self.emit::<OnHookDetach<E>>(ctx, &(typed.as_ref() as &dyn TaskHook<E>)).await;
+        self.emit::<OnHookDetach<E>>(ctx, &wrapper_box.hook.as_ref()).await;
     }
 
     pub async fn emit<E: TaskHookEvent>(
@@ -387,7 +447,7 @@ impl TaskHookContainer {
                 TaskHooksPromotion::Single(_, hook) => {
                     let hook = hook.get();
                     drop(entry);
-                    hook.on_emit(ctx, &payload).await;
+                    hook.on_emit(ctx, TypeId::of::<E>(), &payload).await;
                 }
                 TaskHooksPromotion::Double(
                     (_, hook1),
@@ -396,8 +456,8 @@ impl TaskHookContainer {
                     let hook1 = hook1.get();
                     let hook2 = hook2.get();
                     drop(entry);
-                    hook1.on_emit(ctx, &payload).await;
-                    hook2.on_emit(ctx, &payload).await;
+                    hook1.on_emit(ctx, TypeId::of::<E>(), &payload).await;
+                    hook2.on_emit(ctx, TypeId::of::<E>(), &payload).await;
                 }
                 TaskHooksPromotion::Triplet(
                     (_, hook1),
@@ -408,9 +468,9 @@ impl TaskHookContainer {
                     let hook2 = hook2.get();
                     let hook3 = hook3.get();
                     drop(entry);
-                    hook1.on_emit(ctx, &payload).await;
-                    hook2.on_emit(ctx, &payload).await;
-                    hook3.on_emit(ctx, &payload).await;
+                    hook1.on_emit(ctx, TypeId::of::<E>(), &payload).await;
+                    hook2.on_emit(ctx, TypeId::of::<E>(), &payload).await;
+                    hook3.on_emit(ctx, TypeId::of::<E>(), &payload).await;
                 }
                 TaskHooksPromotion::Multiple(vals) => {
                     let mut instances = Vec::with_capacity(vals.len());
@@ -421,7 +481,7 @@ impl TaskHookContainer {
                     drop(entry);
 
                     for hook in instances {
-                        hook.on_emit(ctx, &payload).await;
+                        hook.on_emit(ctx, TypeId::of::<E>(), &payload).await;
                     }
                 }
             }
@@ -435,6 +495,51 @@ pub trait TaskHookEvent: Send + Sync + Default + 'static {
         Self: 'a;
 }
 
+/// Defines a new [`TaskHookEvent`] in one line, generating the marker struct (alongside its
+/// ``Default``, ``Clone``, ``Copy``, ``Debug``, ``PartialEq``, ``Eq`` and ``Hash`` derives) and the
+/// [`TaskHookEvent`] implementation with the supplied payload type.
+///
+/// It is the declarative counterpart to the ``#[event]`` attribute macro, meant for the common case of
+/// a single event carrying a single payload. The payload may borrow via the ``'a`` lifetime.
+///
+/// # Argument(s)
+/// The macro accepts the (optionally attributed and visibility-qualified) name of the event,
+/// followed by the payload type which is handed to every [`TaskHook`] listening to it.
+///
+/// # Example(s)
+/// ```rust
+/// use chronographer::prelude::*;
+///
+/// define_hook_event!(
+///     /// Emitted whenever a cache entry is evicted, carries the key of the entry.
+///     pub OnCacheEviction, &'a str
+/// );
+///
+/// define_hook_event!(OnBatchFlushed, usize);
+///
+/// # fn main() {
+/// let _ = OnCacheEviction::default();
+/// let _ = OnBatchFlushed::default();
+/// # }
+/// ```
+///
+/// # See Also
+/// - [`TaskHookEvent`] - The trait implemented by the generated event.
+/// - [`TaskHook`] - The consumer of the events.
+/// - [`Task::emit_hook_event`](crate::task::Task::emit_hook_event) - Used to emit the generated event.
+#[macro_export]
+macro_rules! define_hook_event {
+    ($(#[$($attrs:tt)*])* $vis: vis $name: ident, $payload: ty $(,)?) => {
+        $(#[$($attrs)*])*
+        #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        $vis struct $name;
+
+        impl $crate::task::hooks::TaskHookEvent for $name {
+            type Payload<'a> = $payload where Self: 'a;
+        }
+    };
+}
+
 pub enum NonEmittable {}
 
 impl TaskHookEvent for () {
@@ -473,13 +578,26 @@ impl<E: TaskHookEvent> ErasedTaskHookWrapper<E> {
 
 #[async_trait]
 pub(crate) trait ErasedTaskHook: Send + Sync {
-    async fn on_emit<'a>(&self, ctx: &TaskHookContext, payload: &'a (dyn Send + Sync));
+    async fn on_emit<'a>(&self, ctx: &TaskHookContext, event_id: TypeId, payload: &'a (dyn Send + Sync));
     fn as_any(&self) -> Arc<dyn Any + Send + Sync>;
 }
 
 #[async_trait]
 impl<E: TaskHookEvent + 'static> ErasedTaskHook for ErasedTaskHookWrapper<E> {
-    async fn on_emit<'a>(&self, ctx: &TaskHookContext, payload: &'a (dyn Send + Sync)) {
+    async fn on_emit<'a>(&self, ctx: &TaskHookContext, event_id: TypeId, payload: &'a (dyn Send + Sync)) {
+        /*
+            The payload is only reinterpreted when it originates from the same event this hook was
+            attached to, a mismatch is logged and the hook is skipped rather than panicking
+            (or reading the payload as the wrong type) inside the emitting Task
+         */
+        if event_id != TypeId::of::<E>() {
+            eprintln!(
+                "Skipping TaskHook attached to event '{}', it received a payload of a different event",
+                std::any::type_name::<E>()
+            );
+            return;
+        }
+
         let payload = unsafe {
             &*(payload as *const (dyn Send + Sync) as *const &<E as TaskHookEvent>::Payload<'a>)
         };
@@ -499,7 +617,16 @@ define_event!(OnTaskEnd, Option<&'a dyn TaskError>);
 
 define_event_group!(TaskLifecycleEvents, OnTaskStart, OnTaskEnd);
 
-macro_rules! define_hook_event {
+// Emitted by the Scheduler whenever it computes a new fire time, carries the previous (if any) and the new one
+define_event!(OnTaskReschedule, (Option<SystemTime>, SystemTime));
+
+// Emitted by the Scheduler when it drops a Task which outlived its max lifetime, carries the age of the Task
+define_event!(OnTaskExpired, Duration);
+
+// Emitted when a fire is skipped because the max concurrent instances are reached, carries the running instances
+define_event!(OnTaskSkipped, usize);
+
+macro_rules! define_hook_lifecycle_event {
     ($(#[$($attrs:tt)*])* $name: ident) => {
         $(#[$($attrs)*])*
         #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -517,9 +644,9 @@ macro_rules! define_hook_event {
     };
 }
 
-define_hook_event!(OnHookAttach);
+define_hook_lifecycle_event!(OnHookAttach);
 
-define_hook_event!(OnHookDetach);
+define_hook_lifecycle_event!(OnHookDetach);
 
 pub trait TaskHookLifecycleEvents<E: TaskHookEvent>:
     for<'a> TaskHookEvent<Payload<'a> = &'a dyn TaskHook<E>>
@@ -529,11 +656,216 @@ pub trait TaskHookLifecycleEvents<E: TaskHookEvent>:
 impl<'a, E: TaskHookEvent> TaskHookLifecycleEvents<E> for OnHookAttach<E> {}
 impl<'a, E: TaskHookEvent> TaskHookLifecycleEvents<E> for OnHookDetach<E> {}
 
+pub trait OwnedPayloadEvent: TaskHookEvent {
+    type Owned: Send + 'static;
+
+    fn to_owned_payload(payload: &Self::Payload<'_>) -> Self::Owned;
+}
+
+impl<E, P> OwnedPayloadEvent for E
+where
+    E: for<'a> TaskHookEvent<Payload<'a> = P>,
+    P: Clone + Send + Sync + 'static,
+{
+    type Owned = P;
+
+    fn to_owned_payload(payload: &P) -> P {
+        payload.clone()
+    }
+}
+
+type EmissionWaiter<E> = Box<dyn for<'a> FnOnce(&<E as TaskHookEvent>::Payload<'a>) + Send + Sync>;
+
+/*
+    A single NextEmissionHook is attached per event and Task instance, every pending
+    ``next_emission`` future registers a waiter in it which is resolved (and removed) on the
+    next emission. Dropping the future removes its waiter, once no waiters remain the hook
+    detaches itself.
+
+    It may still be running within another emission while it detaches, so rather than being
+    reclaimed (as TaskHookContainer::detach does) it is parked in IDLE_EMISSION_HOOKS and
+    re-attached by the next ``next_emission`` on the same event and Task instance
+ */
+static IDLE_EMISSION_HOOKS: LazyLock<DashMap<(TypeId, usize), &'static dyn ErasedTaskHook>> =
+    LazyLock::new(DashMap::new);
+
+struct NextEmissionHook<E: TaskHookEvent> {
+    waiters: parking_lot::Mutex<Vec<(usize, EmissionWaiter<E>)>>,
+    next_id: std::sync::atomic::AtomicUsize,
+}
+
+impl<E: TaskHookEvent> Default for NextEmissionHook<E> {
+    fn default() -> Self {
+        Self {
+            waiters: parking_lot::Mutex::new(Vec::new()),
+            next_id: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+}
+
+impl<E: TaskHookEvent> NextEmissionHook<E> {
+    /*
+        Moves the hook out of the registry if no waiters remain, the check happens under the lock of
+        its entry so a waiter registered concurrently either lands before it or finds the hook parked
+     */
+    fn retire(&self, instance_id: usize) -> bool {
+        let key = (TypeId::of::<E>(), instance_id);
+        let Some(mut entry) = TASKHOOK_REGISTRY.0.get_mut(&key) else {
+            return false;
+        };
+
+        if !self.waiters.lock().is_empty() {
+            return false;
+        }
+
+        let Some(hook) = entry.remove(TypeId::of::<Self>()) else {
+            return false;
+        };
+
+        IDLE_EMISSION_HOOKS.insert(key, hook);
+        true
+    }
+}
+
+#[async_trait]
+impl<E: TaskHookEvent> TaskHook<E> for NextEmissionHook<E> {
+    async fn on_event(&self, ctx: &TaskHookContext, payload: &E::Payload<'_>) {
+        // Retiring before resolving the waiters, so they observe the hook as detached
+        let waiters = std::mem::take(&mut *self.waiters.lock());
+        let retired = self.retire(ctx.0);
+        for (_, waiter) in waiters {
+            waiter(payload);
+        }
+
+        if retired {
+            TASKHOOK_REGISTRY.emit::<OnHookDetach<E>>(ctx, &(self as &dyn TaskHook<E>)).await;
+        }
+    }
+}
+
+struct EmissionWaiterGuard<E: TaskHookEvent>(Arc<NextEmissionHook<E>>, usize, TaskHookContext);
+
+impl<E: TaskHookEvent> Drop for EmissionWaiterGuard<E> {
+    fn drop(&mut self) {
+        let mut waiters = self.0.waiters.lock();
+        let pending = waiters.len();
+        waiters.retain(|(id, _)| *id != self.1);
+        let cancelled = waiters.len() != pending;
+        drop(waiters);
+
+        // A resolved waiter was already taken out by the emission, which retires the hook itself
+        if !cancelled || !self.0.retire(self.2.0) {
+            return;
+        }
+
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            let (hook, ctx) = (self.0.clone(), self.2);
+            runtime.spawn(async move {
+                TASKHOOK_REGISTRY.emit::<OnHookDetach<E>>(&ctx, &(hook.as_ref() as &dyn TaskHook<E>)).await;
+            });
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct TaskHookContext(pub(crate) usize);
 
 impl TaskHookContext {
+    pub fn next_emission<E: OwnedPayloadEvent>(&self) -> impl Future<Output = E::Owned> + Send + use<E> {
+        self.next_emission_with::<E, E::Owned, _>(E::to_owned_payload)
+    }
+
+    /*
+        Registers a waiter resolved on the next emission, attaching the NextEmissionHook first if needed
+        (re-attaching the parked one if there is any). The waiter is pushed under the lock of the entry,
+        so the hook can't retire in between
+     */
+    fn register_emission_waiter<E, R, F>(&self, map: F) -> (Arc<NextEmissionHook<E>>, usize, bool, tokio::sync::oneshot::Receiver<R>)
+    where
+        E: TaskHookEvent,
+        R: Send + 'static,
+        F: for<'a> FnOnce(&E::Payload<'a>) -> R + Send + Sync + 'static,
+    {
+        let key = (TypeId::of::<E>(), self.0);
+        let hook_id = TypeId::of::<NextEmissionHook<E>>();
+        let mut entry = TASKHOOK_REGISTRY.0.entry(key).or_default();
+
+        let (erased, attached) = match entry.fetch(&hook_id) {
+            Some(erased) => (erased, true),
+            None => {
+                let erased = match IDLE_EMISSION_HOOKS.remove(&key) {
+                    Some((_, erased)) => erased,
+                    None => {
+                        let hook = Arc::new(NextEmissionHook::<E>::default());
+                        Box::leak(Box::new(ErasedTaskHookWrapper::<E>::new(hook))) as &'static dyn ErasedTaskHook
+                    }
+                };
+
+                entry.promote(hook_id, erased);
+                (erased, false)
+            }
+        };
+
+        let hook = erased
+            .as_any()
+            .downcast::<NextEmissionHook<E>>()
+            .expect("the hook stored under the NextEmissionHook id is a NextEmissionHook");
+
+        let (sender, receiver) = tokio::sync::oneshot::channel();
+        let id = hook.next_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        hook.waiters.lock().push((id, Box::new(move |payload| {
+            let _ = sender.send(map(payload));
+        })));
+        drop(entry);
+
+        (hook, id, attached, receiver)
+    }
+
+    pub fn next_emission_with<E, R, F>(&self, map: F) -> impl Future<Output = R> + Send + use<E, R, F>
+    where
+        E: TaskHookEvent,
+        R: Send + 'static,
+        F: for<'a> FnOnce(&E::Payload<'a>) -> R + Send + Sync + 'static,
+    {
+        let ctx = *self;
+        let (hook, id, attached, receiver) = self.register_emission_waiter::<E, R, F>(map);
+        let guard = EmissionWaiterGuard(hook, id, ctx);
+
+        async move {
+            if !attached {
+                let hook = guard.0.as_ref() as &dyn TaskHook<E>;
+                TASKHOOK_REGISTRY.emit::<OnHookAttach<E>>(&ctx, &hook).await;
+            }
+
+            let result = receiver.await;
+            drop(guard);
+
+            match result {
+                Ok(value) => value,
+                Err(_) => std::future::pending().await,
+            }
+        }
+    }
+
+    /// Like [`TaskHookContext::next_emission_with`], but hands out the receiving end of a channel resolved on
+    /// the next emission instead of a future. The waiter is registered once this resolves, whether or not the
+    /// receiver is ever awaited, and stays registered until the next emission even when the receiver is dropped.
+    pub async fn next_emission_channel<E, R, F>(&self, map: F) -> tokio::sync::oneshot::Receiver<R>
+    where
+        E: TaskHookEvent,
+        R: Send + 'static,
+        F: for<'a> FnOnce(&E::Payload<'a>) -> R + Send + Sync + 'static,
+    {
+        let (hook, _, attached, receiver) = self.register_emission_waiter::<E, R, F>(map);
+        if !attached {
+            let hook = hook.as_ref() as &dyn TaskHook<E>;
+            TASKHOOK_REGISTRY.emit::<OnHookAttach<E>>(self, &hook).await;
+        }
+
+        receiver
+    }
+
     pub async fn emit<E: TaskHookEvent>(&self, payload: &E::Payload<'_>) {
         TASKHOOK_REGISTRY.emit::<E>(self, payload).await;
     }
@@ -549,6 +881,12 @@ impl TaskHookContext {
     pub fn get_hook<E: TaskHookEvent, T: TaskHook<E>>(&self) -> Option<Arc<T>> {
         TASKHOOK_REGISTRY.get::<E, T>(self.0)
     }
+
+    /// The number of [`TaskHook`] instances currently attached to the event ``E``, useful for
+    /// catching hooks which get attached but never detached in long-lived Tasks.
+    pub fn hook_count<E: TaskHookEvent>(&self) -> usize {
+        TASKHOOK_REGISTRY.hook_count(TypeId::of::<E>(), self.0)
+    }
 }
 
 impl Sealed for TaskHookContext {}
@@ -0,0 +1,115 @@
+use crate::errors::EmaAlphaOutOfRange;
+use crate::task::Task;
+use crate::task::hooks::{OnTaskEnd, OnTaskStart, TaskHook, TaskHookContext, TaskHookEvent};
+use async_trait::async_trait;
+use dashmap::DashMap;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// The key the average execution duration maintained by [`DurationEmaHook`] goes by, for schedules,
+/// dispatchers or exporters reading it alongside other per-Task metrics.
+pub const DURATION_EMA_KEY: &str = "duration_ema";
+
+/// [`DurationEmaHook`] is a [`TaskHook`] for [`OnTaskStart`] and [`OnTaskEnd`] maintaining an exponential
+/// moving average of how long the executions of a Task take (``ema = alpha * duration + (1 - alpha) * ema``,
+/// seeded by the first duration), for schedules or dispatchers to pace themselves by. It has to be attached
+/// to **both** events and keeps an average per Task, so a single hook can be shared by many. Overlapping
+/// executions of a Task only time the latest start, which skews the average towards shorter durations.
+///
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::hooks::DurationEmaHook;
+/// # use chronographer::task::hooks::events::{OnTaskEnd, OnTaskStart};
+/// # use chronographer::task::TaskScheduleImmediate;
+/// # use std::sync::Arc;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let task = Task::new(
+/// #     DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }),
+/// #     TaskScheduleImmediate,
+/// # );
+/// let hook = Arc::new(DurationEmaHook::new(0.2)?);
+/// task.attach_hook::<OnTaskStart>(hook.clone()).await;
+/// task.attach_hook::<OnTaskEnd>(hook.clone()).await;
+///
+/// // None until an execution of the Task has ended
+/// let typical = hook.ema(&task);
+/// # assert_eq!(typical, None);
+/// # Ok(())
+/// # }
+/// ```
+pub struct DurationEmaHook {
+    alpha: f64,
+    started: DashMap<usize, Instant>,
+    averages: DashMap<usize, f64>,
+}
+
+impl DurationEmaHook {
+    /// Constructs a [`DurationEmaHook`] smoothing with ``alpha``, returning an [`EmaAlphaOutOfRange`]
+    /// if ``alpha`` lies outside of ``(0, 1]`` (``NaN`` included).
+    pub fn new(alpha: f64) -> Result<Self, EmaAlphaOutOfRange> {
+        if alpha.is_nan() || alpha <= 0.0 || alpha > 1.0 {
+            return Err(EmaAlphaOutOfRange);
+        }
+
+        Ok(Self {
+            alpha,
+            started: DashMap::new(),
+            averages: DashMap::new(),
+        })
+    }
+
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// The average execution duration of ``task``, ``None`` until one of its executions has ended.
+    pub fn ema<T1>(&self, task: &Task<T1>) -> Option<Duration> {
+        self.ema_of(task.instance_id)
+    }
+
+    /// The average execution duration of the Task with ``instance_id`` (for instance as read from
+    /// its context), ``None`` until one of its executions has ended.
+    pub fn ema_of(&self, instance_id: usize) -> Option<Duration> {
+        self.averages
+            .get(&instance_id)
+            .map(|average| Duration::from_secs_f64(*average))
+    }
+
+    /// Folds ``duration`` into the average of the Task with ``instance_id``, this is what the hook does on
+    /// every [`OnTaskEnd`] but is exposed for recording durations from elsewhere.
+    pub fn record(&self, instance_id: usize, duration: Duration) {
+        let secs = duration.as_secs_f64();
+        self.averages
+            .entry(instance_id)
+            .and_modify(|average| *average = self.alpha * secs + (1.0 - self.alpha) * *average)
+            .or_insert(secs);
+    }
+
+    pub fn reset(&self, instance_id: usize) {
+        self.started.remove(&instance_id);
+        self.averages.remove(&instance_id);
+    }
+}
+
+impl Default for DurationEmaHook {
+    fn default() -> Self {
+        Self::new(0.2).expect("the default alpha lies within (0, 1]")
+    }
+}
+
+#[async_trait]
+impl TaskHook<OnTaskStart> for DurationEmaHook {
+    async fn on_event(&self, ctx: &TaskHookContext, _payload: &<OnTaskStart as TaskHookEvent>::Payload<'_>) {
+        self.started.insert(ctx.0, Instant::now());
+    }
+}
+
+#[async_trait]
+impl TaskHook<OnTaskEnd> for DurationEmaHook {
+    async fn on_event(&self, ctx: &TaskHookContext, _payload: &<OnTaskEnd as TaskHookEvent>::Payload<'_>) {
+        if let Some((_, started)) = self.started.remove(&ctx.0) {
+            self.record(ctx.0, started.elapsed());
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::task::hooks::{OwnedPayloadEvent, TaskHook, TaskHookContext, TaskHookEvent};
+use async_trait::async_trait;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::sync::mpsc::Sender;
+
+/// A single event forwarded by [`ChannelForwardingHook`], holding the instance id of the Task which
+/// emitted it, the type name of the event and an owned copy of its payload.
+///
+/// With the ``serde`` feature it (de)serializes whenever the payload does, so the receiving end can
+/// encode it in whichever format its transport expects.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ForwardedEvent<P> {
+    pub task_id: usize,
+    pub event: Cow<'static, str>,
+    pub payload: P,
+}
+
+/// [`ChannelForwardingHook`] is a [`TaskHook`] mirroring every emission of the event ``E`` as a
+/// [`ForwardedEvent`] down a [``tokio::sync::mpsc::Sender``](Sender), decoupling the events of ChronoGrapher
+/// from whatever transport (a message broker producer, a websocket... etc.) consumes them.
+///
+/// Forwarding never waits on the channel, when it is full or closed the event is dropped and counted
+/// instead (see [`ChannelForwardingHook::dropped`]) so a slow consumer can't stall the Task.
+///
+/// # Example(s)
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::TaskScheduleImmediate;
+/// use chronographer::task::hooks::ChannelForwardingHook;
+/// use chronographer::task::hooks::events::OnTaskSkipped;
+/// use std::sync::Arc;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let task = Task::new(
+/// #     DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }),
+/// #     TaskScheduleImmediate,
+/// # );
+/// let (sender, mut receiver) = tokio::sync::mpsc::channel(1024);
+/// task.attach_hook::<OnTaskSkipped>(Arc::new(ChannelForwardingHook::new(sender))).await;
+///
+/// tokio::spawn(async move {
+///     while let Some(event) = receiver.recv().await {
+///         println!("{} of task {} carried {}", event.event, event.task_id, event.payload);
+///     }
+/// });
+/// # }
+/// ```
+pub struct ChannelForwardingHook<E: OwnedPayloadEvent> {
+    sender: Sender<ForwardedEvent<E::Owned>>,
+    dropped: AtomicUsize,
+    _marker: PhantomData<E>,
+}
+
+impl<E: OwnedPayloadEvent> ChannelForwardingHook<E> {
+    pub fn new(sender: Sender<ForwardedEvent<E::Owned>>) -> Self {
+        Self {
+            sender,
+            dropped: AtomicUsize::new(0),
+            _marker: PhantomData,
+        }
+    }
+
+    /// The number of events dropped so far because the channel was full or closed.
+    pub fn dropped(&self) -> usize {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+#[async_trait]
+impl<E: OwnedPayloadEvent> TaskHook<E> for ChannelForwardingHook<E> {
+    async fn on_event(&self, ctx: &TaskHookContext, payload: &<E as TaskHookEvent>::Payload<'_>) {
+        let event = ForwardedEvent {
+            task_id: ctx.0,
+            event: Cow::Borrowed(std::any::type_name::<E>()),
+            payload: E::to_owned_payload(payload),
+        };
+
+        if self.sender.try_send(event).is_err() {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
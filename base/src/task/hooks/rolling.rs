@@ -0,0 +1,187 @@
+use crate::task::TASK_METADATA;
+use crate::task::hooks::{OnTaskEnd, TaskHook, TaskHookContext, TaskHookEvent};
+use async_trait::async_trait;
+use dashmap::DashMap;
+use std::collections::VecDeque;
+use std::num::NonZeroUsize;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A single time window of [`RollingOutcomeHook`], counting how many executions ended in it
+/// successfully and how many failed. The window spans from ``start`` up to ``start + window``.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutcomeBucket {
+    pub start: SystemTime,
+    pub successes: u64,
+    pub failures: u64,
+}
+
+/// A point-in-time copy of the buckets [`RollingOutcomeHook`] holds for a single label, ordered
+/// from the oldest to the newest bucket. Buckets in which nothing ended are omitted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OutcomeSnapshot {
+    pub buckets: Vec<OutcomeBucket>,
+}
+
+impl OutcomeSnapshot {
+    pub fn successes(&self) -> u64 {
+        self.buckets.iter().map(|bucket| bucket.successes).sum()
+    }
+
+    pub fn failures(&self) -> u64 {
+        self.buckets.iter().map(|bucket| bucket.failures).sum()
+    }
+
+    /// The ratio of successful executions out of all executions in the snapshot, ``None``
+    /// when nothing ended within it.
+    pub fn success_rate(&self) -> Option<f64> {
+        let successes = self.successes();
+        let total = successes + self.failures();
+        (total != 0).then(|| successes as f64 / total as f64)
+    }
+}
+
+/// [`RollingOutcomeHook`] is a [`TaskHook`] for [`OnTaskEnd`] bucketing the outcomes of executions into
+/// fixed time windows (per-minute windows over the last hour by default), answering "is this Task healthy
+/// over the last hour" without any outside metrics pipeline.
+///
+/// Outcomes are keyed by the description of the Task (see [`Task::with_description`](crate::task::Task::with_description)),
+/// so a single hook may be attached to multiple Tasks and every Task sharing a description aggregates
+/// together (Tasks without a description aggregate under the empty description). Only the latest ``buckets``
+/// windows are kept, older ones are evicted as time goes on.
+///
+/// # Example(s)
+/// ```
+/// # use chronographer::prelude::*;
+/// # use chronographer::task::TaskScheduleImmediate;
+/// use chronographer::task::hooks::RollingOutcomeHook;
+/// use chronographer::task::hooks::events::OnTaskEnd;
+/// use std::sync::Arc;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let task = Task::new(
+/// #     DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }),
+/// #     TaskScheduleImmediate,
+/// # )
+/// # .with_description("nightly report");
+/// let hook = Arc::new(RollingOutcomeHook::default());
+/// task.attach_hook::<OnTaskEnd>(hook.clone()).await;
+///
+/// let last_hour = hook.snapshot("nightly report");
+/// println!("{:?}", last_hour.success_rate());
+/// # }
+/// ```
+pub struct RollingOutcomeHook {
+    window: Duration,
+    buckets: NonZeroUsize,
+    outcomes: DashMap<String, VecDeque<OutcomeBucket>>,
+}
+
+impl RollingOutcomeHook {
+    /// Constructs a [`RollingOutcomeHook`] keeping ``buckets`` windows each spanning ``window``.
+    ///
+    /// # Panics
+    /// Panics if ``window`` is zero.
+    pub fn new(window: Duration, buckets: NonZeroUsize) -> Self {
+        assert!(!window.is_zero(), "the window of RollingOutcomeHook must be non-zero");
+
+        Self {
+            window,
+            buckets,
+            outcomes: DashMap::new(),
+        }
+    }
+
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    pub fn buckets(&self) -> NonZeroUsize {
+        self.buckets
+    }
+
+    /// Records an outcome for ``label`` as if it ended at ``at``, this is what the hook does on every
+    /// [`OnTaskEnd`] but is exposed for recording outcomes from elsewhere.
+    pub fn record(&self, label: &str, at: SystemTime, success: bool) {
+        let start = self.bucket_start(at);
+        let mut buckets = self.outcomes.entry(label.to_owned()).or_default();
+
+        let idx = match buckets.iter().rposition(|bucket| bucket.start <= start) {
+            Some(idx) if buckets[idx].start == start => idx,
+            Some(idx) => {
+                buckets.insert(idx + 1, OutcomeBucket { start, successes: 0, failures: 0 });
+                idx + 1
+            }
+            None => {
+                buckets.push_front(OutcomeBucket { start, successes: 0, failures: 0 });
+                0
+            }
+        };
+
+        match success {
+            true => buckets[idx].successes += 1,
+            false => buckets[idx].failures += 1,
+        }
+
+        let oldest = self.oldest_start(buckets.back().unwrap().start);
+        while buckets.front().is_some_and(|bucket| bucket.start < oldest) {
+            buckets.pop_front();
+        }
+    }
+
+    /// Snapshots the buckets of ``label`` which still fall within the retained windows as of now.
+    pub fn snapshot(&self, label: &str) -> OutcomeSnapshot {
+        self.snapshot_at(label, SystemTime::now())
+    }
+
+    /// Snapshots the buckets of ``label`` which still fall within the retained windows as of ``now``.
+    pub fn snapshot_at(&self, label: &str, now: SystemTime) -> OutcomeSnapshot {
+        let Some(buckets) = self.outcomes.get(label) else {
+            return OutcomeSnapshot::default();
+        };
+
+        let newest = self.bucket_start(now);
+        let oldest = self.oldest_start(newest);
+        OutcomeSnapshot {
+            buckets: buckets
+                .iter()
+                .filter(|bucket| bucket.start >= oldest && bucket.start <= newest)
+                .copied()
+                .collect(),
+        }
+    }
+
+    /// Every label which has recorded at least one outcome.
+    pub fn labels(&self) -> Vec<String> {
+        self.outcomes.iter().map(|entry| entry.key().clone()).collect()
+    }
+
+    fn bucket_start(&self, at: SystemTime) -> SystemTime {
+        let since_epoch = at.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let windows = since_epoch.as_nanos() / self.window.as_nanos();
+        UNIX_EPOCH + Duration::from_nanos((windows * self.window.as_nanos()) as u64)
+    }
+
+    fn oldest_start(&self, newest: SystemTime) -> SystemTime {
+        let span = self.window * (self.buckets.get() as u32 - 1);
+        newest.checked_sub(span).unwrap_or(UNIX_EPOCH)
+    }
+}
+
+impl Default for RollingOutcomeHook {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(60), NonZeroUsize::new(60).unwrap())
+    }
+}
+
+#[async_trait]
+impl TaskHook<OnTaskEnd> for RollingOutcomeHook {
+    async fn on_event(&self, ctx: &TaskHookContext, payload: &<OnTaskEnd as TaskHookEvent>::Payload<'_>) {
+        let label = TASK_METADATA
+            .get(&ctx.0)
+            .map(|metadata| metadata.description.clone())
+            .unwrap_or_default();
+
+        self.record(&label, SystemTime::now(), payload.is_none());
+    }
+}
@@ -10,6 +10,14 @@
 //! - [`CronField`] - A field used internally for [`TaskScheduleCron`]
 //! - [`TaskScheduleCalendar`] - A primitive which schedules via a human-readable calendar object.
 //! - [`TaskCalendarField`] - A field of [`TaskScheduleCalendar`] which allows complex scheduling.
+//! - [`TaskScheduleRandomWindow`] - A primitive which schedules at a random time inside a daily window.
+//! - [`TaskScheduleSolar`] - A primitive which schedules relative to sunrise / sunset at a location.
+//! - [`TaskScheduleStartup`] - A wrapper which schedules immediately once, then delegates to another schedule.
+//! - [`TaskScheduleDependency`] - A primitive which schedules once a dependency resolves (via polling).
+//! - [`TaskScheduleDynamicInterval`] - A primitive which schedules per-interval basis, reading the interval on every computation.
+//! - [`TaskScheduleOutcome`] - A wrapper which delegates to a different schedule after a failed execution.
+//! - [`TaskScheduleAt`] - A primitive which schedules once, at a fixed point in time.
+//! - [`TaskScheduleZonedCron`] - A primitive which schedules based on a CRON expression in the local time of a timezone.
 //!
 //! # Example(s)
 //! TODO: Expand upon the Example(s) once you are finished with documenting the other primitives
@@ -25,17 +33,34 @@
 //! - [`TaskCalendarField`] - A field of [`TaskScheduleCalendar`] which allows complex scheduling.
 //! - [`TaskSchedule`](TaskSchedule) - The trait for managing scheduling / trigger logic.
 
+mod at; // skipcq: RS-D1001
 mod cron; // skipcq: RS-D1001
+mod dependency; // skipcq: RS-D1001
+mod dynamic_interval; // skipcq: RS-D1001
 mod immediate;
 mod interval; // skipcq: RS-D1001
+mod outcome; // skipcq: RS-D1001
+mod random_window; // skipcq: RS-D1001
+mod solar; // skipcq: RS-D1001
+mod startup; // skipcq: RS-D1001
+mod zoned_cron; // skipcq: RS-D1001
 
 use std::error::Error;
 use std::time::SystemTime;
 use async_trait::async_trait;
+use crate::errors::OccurrenceRangeInverted;
 
+pub use at::*;
 pub use cron::*;
+pub use dependency::*;
+pub use dynamic_interval::*;
 pub use immediate::*;
 pub use interval::*;
+pub use outcome::*;
+pub use random_window::*;
+pub use solar::*;
+pub use startup::*;
+pub use zoned_cron::*;
 
 /// [`TaskSchedule`] is the main mechanism in which [`Tasks`](crate::task::Task) schedule a future time (based on
 /// a current one) to run, this time is handed to the "[`Scheduler`](crate::scheduler::Scheduler) Side"
@@ -162,4 +187,106 @@ pub trait TaskSchedule: 'static + Send + Sync {
     /// - [`Scheduler`](crate::scheduler::Scheduler) - The side in which it manages the scheduling process of Tasks.
     /// - [`SchedulerClock`](crate::scheduler::clock::SchedulerClock) - The mechanism that supplies the "now" argument with the value
     async fn schedule(&self, now: SystemTime) -> Result<SystemTime, Box<dyn Error + Send + Sync>>;
-}
\ No newline at end of file
+
+    /// Enumerates the fire times which come strictly after ``from`` and no later than ``to``, in
+    /// ascending order. It is used for catching up on missed fire times (see [`CatchUpPolicy`](crate::task::CatchUpPolicy)).
+    ///
+    /// # Semantics
+    /// The default implementation repeatedly feeds the result of [`TaskSchedule::schedule`] back into
+    /// itself, stopping once a time exceeds ``to`` or stops advancing. Schedules which hold state
+    /// across computations (or compute times non-deterministically) should override it, as the
+    /// default implementation would otherwise mutate said state.
+    ///
+    /// At most [`MAX_OCCURRENCES`] fire times are enumerated (the earliest ones), so a fine-grained
+    /// schedule over a long range doesn't allocate a huge vector. To go past the cap, call it again
+    /// starting from the last enumerated fire time.
+    ///
+    /// # Returns
+    /// On success the enumerated fire times (possibly empty), on failure the error
+    /// [`TaskSchedule::schedule`] returned.
+    ///
+    /// # Error(s)
+    /// Returns an [`OccurrenceRangeInverted`] when ``from`` comes after ``to``.
+    ///
+    /// # See Also
+    /// - [`TaskSchedule`] - The main trait that holds this method
+    /// - [`CatchUpPolicy`](crate::task::CatchUpPolicy) - The policy which consumes the enumerated fire times.
+    async fn occurrences(
+        &self,
+        from: SystemTime,
+        to: SystemTime,
+    ) -> Result<Vec<SystemTime>, Box<dyn Error + Send + Sync>> {
+        check_occurrence_range(from, to)?;
+        let mut occurrences = Vec::new();
+        let mut current = from;
+
+        while occurrences.len() < MAX_OCCURRENCES {
+            let next = self.schedule(current).await?;
+            if next <= current || next > to {
+                break;
+            }
+
+            occurrences.push(next);
+            current = next;
+        }
+
+        Ok(occurrences)
+    }
+
+    /// Computes the next fire time after ``now`` (just as [`TaskSchedule::schedule`] does) alongside a
+    /// human-readable reason of how it was reached, for troubleshooting misconfigured schedules (e.g.
+    /// "next match of cron expression ``0 30 9 * * ? *`` after 2030-06-01 10:00:00.0 +00 is 2030-06-02 9:30:00.0 +00").
+    ///
+    /// # Semantics
+    /// The default implementation only states the computed time, the built-in schedules override it
+    /// to describe their reasoning. Times are formatted in UTC.
+    ///
+    /// The default implementation computes the time via [`TaskSchedule::schedule`], schedules which keep
+    /// state across computations have to override it so explaining doesn't affect the next computation.
+    ///
+    /// # See Also
+    /// - [`TaskSchedule`] - The main trait that holds this method
+    /// - [`TaskSchedule::schedule`] - The method whose result is explained.
+    async fn explain(&self, now: SystemTime) -> Result<(SystemTime, String), Box<dyn Error + Send + Sync>> {
+        let next = self.schedule(now).await?;
+        let reason = format!("next fire after {} is {}", display_time(now), display_time(next));
+        Ok((next, reason))
+    }
+}
+
+/// The maximum number of fire times [`TaskSchedule::occurrences`] enumerates in one call.
+pub const MAX_OCCURRENCES: usize = 10_000;
+
+pub(crate) fn check_occurrence_range(
+    from: SystemTime,
+    to: SystemTime,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    if from > to {
+        return Err(Box::new(OccurrenceRangeInverted));
+    }
+
+    Ok(())
+}
+
+/// Formats a [`SystemTime`] as a UTC date and time, used by [`TaskSchedule::explain`].
+pub(crate) fn display_time(at: SystemTime) -> String {
+    time::UtcDateTime::from(at).to_string()
+}
+#[async_trait]
+impl TaskSchedule for Box<dyn TaskSchedule> {
+    async fn schedule(&self, now: SystemTime) -> Result<SystemTime, Box<dyn Error + Send + Sync>> {
+        self.as_ref().schedule(now).await
+    }
+
+    async fn occurrences(
+        &self,
+        from: SystemTime,
+        to: SystemTime,
+    ) -> Result<Vec<SystemTime>, Box<dyn Error + Send + Sync>> {
+        self.as_ref().occurrences(from, to).await
+    }
+
+    async fn explain(&self, now: SystemTime) -> Result<(SystemTime, String), Box<dyn Error + Send + Sync>> {
+        self.as_ref().explain(now).await
+    }
+}
@@ -0,0 +1,92 @@
+//! A standalone module containing only the [`TaskScheduleAt`] scheduling primitive
+
+use crate::task::TaskSchedule;
+use crate::task::schedule::{check_occurrence_range, display_time};
+use async_trait::async_trait;
+use std::error::Error;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::SystemTime;
+
+/// [`TaskScheduleAt`] is a [`TaskSchedule`] used to execute a [Task](crate::task::Task) once, at a fixed
+/// point in time (a one-shot).
+///
+/// # Scheduling Semantics
+/// The first computation returns the fixed time, even when it already lies in the past (in which case the
+/// Task executes right away). Every computation after it errors, as there is nothing left to schedule.
+///
+/// # Schedule Errors
+/// Errors on every computation after the first one. Under the default [`FailoverPolicy`](crate::scheduler::FailoverPolicy)
+/// the Task then stays on the Scheduler without ever firing again, the Task may remove itself instead via
+/// [`TaskFrameContext::cancel_self`](crate::task::TaskFrameContext::cancel_self) (as [`Scheduler::at`](crate::scheduler::Scheduler::at) does).
+///
+/// # Constructor(s)
+/// The only constructor is [`TaskScheduleAt::new`], which accepts the time to execute at.
+///
+/// # Example(s)
+/// ```rust
+/// use chronographer::task::{TaskScheduleAt, TaskSchedule};
+/// use std::time::{Duration, SystemTime};
+/// # use std::error::Error;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+/// let at = SystemTime::now() + Duration::from_secs(60);
+/// let instance = TaskScheduleAt::new(at);
+///
+/// assert_eq!(instance.schedule(SystemTime::now()).await?, at);
+/// assert!(instance.schedule(SystemTime::now()).await.is_err());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # See Also
+/// - [`TaskScheduleImmediate`](crate::task::TaskScheduleImmediate) - For executing right away, on every computation.
+/// - [`TaskSchedule`] - The direct implementor of this trait.
+/// - [`Task`](crate::task::Task) - The main container which the schedule is hosted on.
+#[derive(Debug)]
+pub struct TaskScheduleAt {
+    time: SystemTime,
+    handed_out: AtomicBool,
+}
+
+impl TaskScheduleAt {
+    pub fn new(time: SystemTime) -> Self {
+        Self {
+            time,
+            handed_out: AtomicBool::new(false),
+        }
+    }
+
+    pub fn time(&self) -> SystemTime {
+        self.time
+    }
+}
+
+#[async_trait]
+impl TaskSchedule for TaskScheduleAt {
+    async fn schedule(&self, _time: SystemTime) -> Result<SystemTime, Box<dyn Error + Send + Sync>> {
+        match self.handed_out.swap(true, Ordering::SeqCst) {
+            false => Ok(self.time),
+            true => Err(format!("the one-shot time {} has already been scheduled", display_time(self.time)).into()),
+        }
+    }
+
+    async fn occurrences(
+        &self,
+        from: SystemTime,
+        to: SystemTime,
+    ) -> Result<Vec<SystemTime>, Box<dyn Error + Send + Sync>> {
+        check_occurrence_range(from, to)?;
+        Ok(match from < self.time && self.time <= to {
+            true => vec![self.time],
+            false => Vec::new(),
+        })
+    }
+
+    async fn explain(&self, _now: SystemTime) -> Result<(SystemTime, String), Box<dyn Error + Send + Sync>> {
+        match self.handed_out.load(Ordering::SeqCst) {
+            false => Ok((self.time, format!("one-shot at {}", display_time(self.time)))),
+            true => Err(format!("the one-shot time {} has already been scheduled", display_time(self.time)).into()),
+        }
+    }
+}
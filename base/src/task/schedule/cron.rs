@@ -1,4 +1,5 @@
 use crate::task::TaskSchedule;
+use crate::task::schedule::{MAX_OCCURRENCES, check_occurrence_range, display_time};
 use async_trait::async_trait;
 use chronographer_utils::{
     cron_lexer::{Token, tokenize_from_str},
@@ -6,7 +7,7 @@ use chronographer_utils::{
     errors::{CronError, CronErrorTypes, CronExpressionParserErrors},
 };
 use std::error::Error;
-use std::fmt::{Debug, Formatter, Write};
+use std::fmt::{Debug, Display, Formatter, Write};
 use std::ops::RangeInclusive;
 use std::str::FromStr;
 use std::time::{Duration, SystemTime};
@@ -203,6 +204,38 @@ impl Debug for CronField {
 }
 
 impl CronField {
+    /*
+        Writes the field back in the CRON syntax the parser accepts, ``Last`` offsets are ambiguous
+        without the position (``L-3`` in the day of month field and ``3L`` in the day of week field)
+     */
+    fn write_expression(&self, field_pos: usize, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CronField::Wildcard => f.write_char('*'),
+            CronField::Exact(val) => write!(f, "{val}"),
+            CronField::Range(start, end) => write!(f, "{start}-{end}"),
+            CronField::Step(base, step) => {
+                base.write_expression(field_pos, f)?;
+                write!(f, "/{step}")
+            }
+            CronField::List(vals) => {
+                for (idx, val) in vals.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_char(',')?;
+                    }
+                    val.write_expression(field_pos, f)?;
+                }
+                Ok(())
+            }
+            CronField::Unspecified => f.write_char('?'),
+            CronField::Last(None) => f.write_char('L'),
+            CronField::Last(Some(val)) if field_pos == 5 => write!(f, "{val}L"),
+            CronField::Last(Some(val)) => write!(f, "L-{val}"),
+            CronField::NearestWeekday(0) => f.write_str("LW"),
+            CronField::NearestWeekday(val) => write!(f, "{val}W"),
+            CronField::NthWeekday(val1, val2) => write!(f, "{val1}#{val2}"),
+        }
+    }
+
     fn matches(&self, value: u32) -> bool {
         match self {
             CronField::Wildcard => true,
@@ -347,9 +380,12 @@ impl CronField {
 /// # Trait Implementation(s)
 /// Apart from [`TaskScheduleCron`] implementing the [`TaskSchedule`] trait and [`FromStr`], it implements as well:
 /// - [`Debug`]
+/// - [`Display`] (formats back into the CRON expression)
 /// - [`Clone`]
 /// - [`PartialEq`]
 /// - [`Eq`]
+/// - ``Serialize`` / ``Deserialize`` (gated behind the ``serde`` feature, stored as the CRON expression string,
+///   CRON expressions are always evaluated in UTC so there is no timezone to store)
 ///
 /// # Example(s)
 /// Using the [`TaskScheduleCron::from_str`] constructor for dynamic-based CRON expressions
@@ -427,6 +463,59 @@ impl Debug for TaskScheduleCron {
     }
 }
 
+/// Formats the [`TaskScheduleCron`] back into a CRON expression of the 6 fields
+/// [`TaskScheduleCron::from_str`] parses into an equal [`TaskScheduleCron`]. The year field can't be
+/// parsed, so it is only appended (as a 7th field) when it was set via [`TaskScheduleCron::new`].
+impl Display for TaskScheduleCron {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let fields = [
+            &self.seconds,
+            &self.minute,
+            &self.hour,
+            &self.day_of_month,
+            &self.month,
+            &self.day_of_week,
+            &self.year,
+        ];
+
+        for (field_pos, field) in fields.into_iter().enumerate() {
+            if field_pos == 6 && matches!(field, CronField::Wildcard) {
+                break;
+            }
+
+            if field_pos > 0 {
+                f.write_char(' ')?;
+            }
+            field.write_expression(field_pos, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Serializes as the CRON expression, failing when the year field is set since the expression
+/// couldn't be parsed back (see the [`Display`] implementation).
+#[cfg(feature = "serde")]
+impl serde::Serialize for TaskScheduleCron {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !matches!(self.year, CronField::Wildcard) {
+            return Err(serde::ser::Error::custom(
+                "a TaskScheduleCron restricting the year can't be serialized as a CRON expression",
+            ));
+        }
+
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TaskScheduleCron {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let expression = <String as serde::Deserialize>::deserialize(deserializer)?;
+        TaskScheduleCron::from_str(&expression).map_err(serde::de::Error::custom)
+    }
+}
+
 impl TaskScheduleCron {
     /// Constructs a new [`TaskScheduleCron`] instance from the provided [`CronField`]. This constructor
     /// should rarely be used, it is much preferred to
@@ -500,7 +589,14 @@ impl TaskScheduleCron {
     }
 
     fn next_time_from(&self, current: SystemTime) -> Option<SystemTime> {
-        let current = UtcDateTime::from(current);
+        self.next_wall_time(UtcDateTime::from(current)).map(SystemTime::from)
+    }
+
+    /*
+        The search itself is offset-agnostic, it finds the next matching calendar time after ``current``
+        as if both were wall-clock times (the zoned variant maps them back to instants)
+     */
+    pub(crate) fn next_wall_time(&self, current: UtcDateTime) -> Option<UtcDateTime> {
         let mut dt = current + Duration::from_secs(1);
 
         loop {
@@ -622,7 +718,7 @@ impl TaskScheduleCron {
                 continue;
             }
 
-            return Some(SystemTime::from(dt));
+            return Some(dt);
         }
     }
 
@@ -740,4 +836,38 @@ impl TaskSchedule for TaskScheduleCron {
             .next_time_from(time)
             .ok_or("No valid scheduling time found")?)
     }
+
+    async fn occurrences(
+        &self,
+        from: SystemTime,
+        to: SystemTime,
+    ) -> Result<Vec<SystemTime>, Box<dyn Error + Send + Sync>> {
+        check_occurrence_range(from, to)?;
+        let mut occurrences = Vec::new();
+        let mut current = from;
+
+        while let Some(next) = self.next_time_from(current) {
+            if next > to || occurrences.len() >= MAX_OCCURRENCES {
+                break;
+            }
+
+            occurrences.push(next);
+            current = next;
+        }
+
+        Ok(occurrences)
+    }
+    async fn explain(&self, now: SystemTime) -> Result<(SystemTime, String), Box<dyn Error + Send + Sync>> {
+        let next = self
+            .next_time_from(now)
+            .ok_or_else(|| format!("no match of cron expression `{self}` after {}", display_time(now)))?;
+
+        let reason = format!(
+            "next match of cron expression `{self}` after {} is {}",
+            display_time(now),
+            display_time(next)
+        );
+
+        Ok((next, reason))
+    }
 }
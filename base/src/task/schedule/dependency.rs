@@ -0,0 +1,105 @@
+//! A standalone module containing only the [`TaskScheduleDependency`] scheduling primitive
+
+use crate::task::TaskSchedule;
+use crate::task::schedule::check_occurrence_range;
+use crate::task::dependency::FrameDependency;
+use async_trait::async_trait;
+use std::error::Error;
+use std::time::{Duration, SystemTime};
+
+/// [`TaskScheduleDependency`] is a [`TaskSchedule`] used to execute a [Task](crate::task::Task) once a
+/// [`FrameDependency`] is resolved, bridging event-based dependencies into the time-based scheduling.
+///
+/// # Scheduling Semantics
+/// While the [`FrameDependency`] is resolved, the computed time is the current time (so the Task executes
+/// right away, every time it is rescheduled). While it is unresolved, [`TaskScheduleDependency`] checks the
+/// dependency again every poll interval and hands out the time it resolved at.
+///
+/// This is a **polling approximation**, not a push-based trigger. The Task is executed up to one
+/// poll interval after the dependency resolves, every poll calls [`FrameDependency::is_resolved`] (so
+/// external dependencies should be cheap to check) and the rescheduling of the Task waits while polling.
+/// The resolution time is approximated by adding the time spent polling to the current time.
+///
+/// Missed fire times can't be enumerated for a dependency, so [`TaskSchedule::occurrences`] is
+/// always empty.
+///
+/// # Schedule Errors
+/// [`TaskScheduleDependency`] will **NEVER** return any kind of error.
+///
+/// # Constructor(s)
+/// - [`TaskScheduleDependency::new`] - Constructs it via a [`FrameDependency`] and a poll interval.
+///
+/// # Example(s)
+/// ```rust
+/// use chronographer::task::{TaskScheduleDependency, TaskSchedule};
+/// use chronographer::task::dependency::FrameDependency;
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicBool, Ordering};
+/// use std::time::{Duration, SystemTime};
+/// # use std::error::Error;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+/// let ready = Arc::new(AtomicBool::new(true));
+/// let flag = ready.clone();
+/// let dependency = FrameDependency::external(move || {
+///     let flag = flag.clone();
+///     async move { flag.load(Ordering::Relaxed) }
+/// });
+///
+/// let instance = TaskScheduleDependency::new(dependency, Duration::from_millis(100));
+/// let now = SystemTime::now();
+/// assert_eq!(instance.schedule(now).await?, now);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # See Also
+/// - [`FrameDependency`] - The dependency which is polled.
+/// - [`DependencyTaskFrame`](crate::task::DependencyTaskFrame) - For gating the execution itself on dependencies.
+/// - [`TaskSchedule`] - The direct implementor of this trait.
+/// - [`Task`](crate::task::Task) - The main container which the schedule is hosted on.
+pub struct TaskScheduleDependency {
+    dependency: FrameDependency,
+    poll_interval: Duration,
+}
+
+impl TaskScheduleDependency {
+    pub fn new(dependency: FrameDependency, poll_interval: Duration) -> Self {
+        Self {
+            dependency,
+            poll_interval,
+        }
+    }
+
+    pub fn dependency(&self) -> &FrameDependency {
+        &self.dependency
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+}
+
+#[async_trait]
+impl TaskSchedule for TaskScheduleDependency {
+    async fn schedule(&self, time: SystemTime) -> Result<SystemTime, Box<dyn Error + Send + Sync>> {
+        let mut waited = Duration::ZERO;
+        while !self.dependency.is_resolved().await {
+            tokio::time::sleep(self.poll_interval).await;
+            waited += self.poll_interval;
+        }
+
+        Ok(time + waited)
+    }
+
+    async fn occurrences(
+        &self,
+        from: SystemTime,
+        to: SystemTime,
+    ) -> Result<Vec<SystemTime>, Box<dyn Error + Send + Sync>> {
+        check_occurrence_range(from, to)?;
+
+        Ok(Vec::new())
+    }
+}
@@ -0,0 +1,111 @@
+//! A standalone module containing only the [`TaskScheduleDynamicInterval`] scheduling primitive
+
+use crate::task::TaskSchedule;
+use crate::task::schedule::{check_occurrence_range, display_time};
+use async_trait::async_trait;
+use std::error::Error;
+use std::time::{Duration, SystemTime};
+
+/// [`TaskScheduleDynamicInterval`] is a [`TaskSchedule`] used to execute a [Task](crate::task::Task) in an
+/// interval basis, where the interval is read from a source on **every** computation (e.g. a control knob
+/// shared with the rest of the application), so changing it changes the cadence without rescheduling.
+///
+/// # Scheduling Semantics
+/// On every computation the source is called and the future time is the current time plus the interval
+/// it returned, as [`TaskScheduleInterval`](crate::task::TaskScheduleInterval) does. A change to the source
+/// takes effect from the next computation onward, the already computed fire time is kept.
+///
+/// When the source has no value (returns ``None``) or an invalid one (a zero interval, which would fire
+/// in a tight loop), the fallback interval is used instead.
+///
+/// # Schedule Errors
+/// [`TaskScheduleDynamicInterval`] will **NEVER** return any kind of error.
+///
+/// # Constructor(s)
+/// The only constructor is [`TaskScheduleDynamicInterval::new`], which accepts the fallback interval and the source.
+///
+/// # Example(s)
+/// ```rust
+/// use chronographer::task::{TaskScheduleDynamicInterval, TaskSchedule};
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicU64, Ordering};
+/// use std::time::{Duration, SystemTime};
+/// # use std::error::Error;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+/// let knob = Arc::new(AtomicU64::new(0));
+/// let source = knob.clone();
+/// let instance = TaskScheduleDynamicInterval::new(Duration::from_secs(60), move || {
+///     Some(Duration::from_secs(source.load(Ordering::Relaxed)))
+/// });
+///
+/// let now = SystemTime::now();
+/// assert_eq!(instance.schedule(now).await?, now + Duration::from_secs(60)); // Zero falls back
+///
+/// knob.store(5, Ordering::Relaxed);
+/// assert_eq!(instance.schedule(now).await?, now + Duration::from_secs(5));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # See Also
+/// - [`TaskScheduleInterval`](crate::task::TaskScheduleInterval) - For a fixed interval.
+/// - [`TaskSchedule`] - The direct implementor of this trait.
+/// - [`Task`](crate::task::Task) - The main container which the schedule is hosted on.
+/// - [`Scheduler`](crate::scheduler::Scheduler) - The side in which it manages the scheduling process of Tasks.
+pub struct TaskScheduleDynamicInterval {
+    fallback: Duration,
+    source: Box<dyn Fn() -> Option<Duration> + Send + Sync>,
+}
+
+impl TaskScheduleDynamicInterval {
+    pub fn new(fallback: Duration, source: impl Fn() -> Option<Duration> + Send + Sync + 'static) -> Self {
+        Self {
+            fallback,
+            source: Box::new(source),
+        }
+    }
+
+    pub fn fallback(&self) -> Duration {
+        self.fallback
+    }
+
+    /// The interval the next computation would use, the fallback one if the source
+    /// has no (or an invalid) value.
+    pub fn interval(&self) -> Duration {
+        (self.source)()
+            .filter(|interval| !interval.is_zero())
+            .unwrap_or(self.fallback)
+    }
+}
+
+#[async_trait]
+impl TaskSchedule for TaskScheduleDynamicInterval {
+    async fn schedule(&self, time: SystemTime) -> Result<SystemTime, Box<dyn Error + Send + Sync>> {
+        Ok(time + self.interval())
+    }
+
+    async fn occurrences(
+        &self,
+        from: SystemTime,
+        to: SystemTime,
+    ) -> Result<Vec<SystemTime>, Box<dyn Error + Send + Sync>> {
+        check_occurrence_range(from, to)?;
+
+        // The interval of past fires is unknown, as the source may have changed since
+        Ok(Vec::new())
+    }
+
+    async fn explain(&self, now: SystemTime) -> Result<(SystemTime, String), Box<dyn Error + Send + Sync>> {
+        let interval = self.interval();
+        let next = now + interval;
+        let reason = format!(
+            "{interval:?} (currently read from its source) after {} is {}",
+            display_time(now),
+            display_time(next)
+        );
+
+        Ok((next, reason))
+    }
+}
@@ -3,10 +3,11 @@
 use std::error::Error;
 use std::fmt::Debug;
 use std::ops::Add;
-use std::time::{Duration, SystemTime};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use async_trait::async_trait;
 use crate::errors::IntervalSecondsOutOfRange;
 use crate::task::TaskSchedule;
+use crate::task::schedule::{MAX_OCCURRENCES, check_occurrence_range, display_time};
 
 #[cfg(feature = "chrono")]
 use crate::errors::IntervalTimeDeltaOutOfRange;
@@ -18,6 +19,18 @@ use crate::errors::IntervalTimeDeltaOutOfRange;
 /// [`TaskScheduleInterval`] contains an interval which it uses to calculate the new future time
 /// by taking the current time plus the interval.
 ///
+/// When constructed via [`TaskScheduleInterval::anchored`], the interval is measured from a fixed
+/// epoch instead, the future time is the next multiple of the interval (counted from the epoch)
+/// strictly after the current time. This aligns all [`TaskScheduleInterval`] instances sharing
+/// the same epoch and interval onto the same grid.
+///
+/// When constructed via [`TaskScheduleInterval::aligned`], the interval is measured from midnight (UTC)
+/// of the current day, the future time is the next multiple of the interval which comes strictly
+/// after the current time, capped at the following midnight (so hourly intervals land on ``:00``).
+///
+/// Interval fires missed while the Scheduler stalled are handled by the [`CatchUpPolicy`](crate::task::CatchUpPolicy)
+/// of the Task, [`CatchUpPolicy::RunAtMost`](crate::task::CatchUpPolicy::RunAtMost) caps how many of them fire in a row.
+///
 /// # Schedule Errors
 /// Due to its simplicity, [`TaskScheduleInterval`] will **NEVER** return any kind of error.
 ///
@@ -30,6 +43,10 @@ use crate::errors::IntervalTimeDeltaOutOfRange;
 ///   (for float numbers it **may panic**).
 /// - [`TaskScheduleInterval::timedelta`] - Gated behind the ``chrono`` feature, but supports the construction
 ///   via ``TimeDelta``.
+/// - [`TaskScheduleInterval::anchored`] - Constructs it via a [`Duration`] and an epoch which the interval
+///   grid is aligned to.
+/// - [`TaskScheduleInterval::aligned`] - Constructs it via a [`Duration`] which is aligned to the wall-clock
+///   (measured from midnight).
 ///
 /// There exists the [every!](chronographer::prelude::every) macro for creating easily [`TaskScheduleInterval`] with a short and
 /// readable duration-based syntax, the macro is gated behind the ``macros`` feature and lives in the
@@ -40,6 +57,10 @@ use crate::errors::IntervalTimeDeltaOutOfRange;
 /// - [`Debug`]
 /// - [`Clone`]
 /// - [`Copy`]
+/// - [`PartialEq`]
+/// - [`Eq`]
+/// - ``Serialize`` / ``Deserialize`` (gated behind the ``serde`` feature, stores the interval alongside
+///   how it is aligned)
 ///
 /// # Example(s)
 /// Basic usage of [`TaskScheduleInterval`]:
@@ -106,8 +127,43 @@ use crate::errors::IntervalTimeDeltaOutOfRange;
 /// - [`TaskSchedule`] - The direct implementor of this trait.
 /// - [`Task`](crate::task::Task) - The main container which the schedule is hosted on.
 /// - [`Scheduler`](crate::scheduler::Scheduler) - The side in which it manages the scheduling process of Tasks.
-#[derive(Debug, Clone, Copy)]
-pub struct TaskScheduleInterval(pub(crate) Duration);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaskScheduleInterval {
+    #[cfg_attr(feature = "serde", serde(rename = "interval_nanos", with = "interval_nanos"))]
+    pub(crate) interval: Duration,
+    pub(crate) alignment: IntervalAlignment,
+}
+
+/*
+    Intervals are persisted as a single integer of nanoseconds, which round-trips bit-identically
+    through any format (unlike a float of seconds, which drifts once it passes through a decimal form)
+ */
+#[cfg(feature = "serde")]
+mod interval_nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(interval: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        let nanos = u64::try_from(interval.as_nanos())
+            .map_err(|_| serde::ser::Error::custom("interval too large to be persisted in nanoseconds"))?;
+
+        serializer.serialize_u64(nanos)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        Ok(Duration::from_nanos(u64::deserialize(deserializer)?))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub(crate) enum IntervalAlignment {
+    Relative,
+    Anchored(SystemTime),
+    Midnight,
+}
 
 impl TaskScheduleInterval {
     #[cfg(feature = "chrono")]
@@ -157,7 +213,7 @@ impl TaskScheduleInterval {
     pub fn timedelta(
         interval: chrono::TimeDelta,
     ) -> Result<Self, IntervalTimeDeltaOutOfRange> {
-        Ok(Self(interval.to_std().map_err(|_| { IntervalTimeDeltaOutOfRange })?))
+        Ok(Self::duration(interval.to_std().map_err(|_| { IntervalTimeDeltaOutOfRange })?))
     }
 
     /// A constructor for [`TaskScheduleInterval`] via a [`time::Duration`].
@@ -196,7 +252,7 @@ impl TaskScheduleInterval {
             return Err(IntervalSecondsOutOfRange)
         }
 
-        Ok(Self(Duration::try_from(interval).unwrap()))
+        Ok(Self::duration(Duration::try_from(interval).unwrap()))
     }
 
     /// A constructor for [`TaskScheduleInterval`] via a [`Duration`].
@@ -231,7 +287,97 @@ impl TaskScheduleInterval {
     /// - [`TaskScheduleInterval::from_secs_f64`] - A simpler constructor for floating point second-based intervals.
     /// - [every!](chronographer::prelude::every) - A macro with a readable syntax for defining an interval.
     pub fn duration(interval: Duration) -> Self {
-        Self(interval)
+        Self {
+            interval,
+            alignment: IntervalAlignment::Relative,
+        }
+    }
+
+    /// A constructor for [`TaskScheduleInterval`] via a [`Duration`] which is anchored to an epoch.
+    ///
+    /// Unlike the other constructors, the future time is not computed relative to the current time,
+    /// rather it is the next multiple of ``interval`` measured from ``epoch`` which comes strictly
+    /// after the current time. When the current time lands exactly on a grid point, the next grid
+    /// point is returned. The epoch may be either in the past or in the future.
+    ///
+    /// # Argument(s)
+    /// It accepts two arguments, the [`Duration`] which represents the interval-basis and a
+    /// [`SystemTime`] which represents the epoch the interval grid is aligned to.
+    ///
+    /// # Returns
+    /// The newly constructed [`TaskScheduleInterval`] which is anchored to ``epoch``.
+    ///
+    /// # Example(s)
+    /// ```rust
+    /// use chronographer_base::task::{TaskScheduleInterval, TaskSchedule};
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// # use std::error::Error;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+    /// let interval = TaskScheduleInterval::anchored(Duration::from_secs(10), UNIX_EPOCH);
+    ///
+    /// let from_between = interval.schedule(UNIX_EPOCH + Duration::from_secs(13)).await?;
+    /// let from_grid = interval.schedule(UNIX_EPOCH + Duration::from_secs(20)).await?;
+    ///
+    /// assert_eq!(from_between, UNIX_EPOCH + Duration::from_secs(20));
+    /// assert_eq!(from_grid, UNIX_EPOCH + Duration::from_secs(30));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # See Also
+    /// - [`TaskScheduleInterval`] - The main source which the constructor method is part of.
+    /// - [`TaskScheduleInterval::duration`] - A similar constructor but relative to the current time.
+    /// - [every!](chronographer::prelude::every) - A macro with a readable syntax for defining an interval.
+    pub fn anchored(interval: Duration, epoch: SystemTime) -> Self {
+        Self {
+            interval,
+            alignment: IntervalAlignment::Anchored(epoch),
+        }
+    }
+
+    /// A constructor for [`TaskScheduleInterval`] via a [`Duration`] which is aligned to the wall-clock.
+    ///
+    /// The future time is the next multiple of ``interval`` measured from midnight (UTC) of the current
+    /// day which comes strictly after the current time, the grid restarts at every midnight. As such,
+    /// 15-minute intervals land on ``:00``, ``:15``, ``:30`` and ``:45`` while hourly intervals land on ``:00``.
+    ///
+    /// Intervals which don't evenly divide a day fire at the following midnight instead of their last
+    /// (partial) step, while intervals of a day or longer are measured from the [`UNIX_EPOCH`].
+    ///
+    /// # Argument(s)
+    /// It accepts one argument and that being [`Duration`] which represents the interval-basis.
+    ///
+    /// # Returns
+    /// The newly constructed [`TaskScheduleInterval`] which is aligned to the wall-clock.
+    ///
+    /// # Example(s)
+    /// ```rust
+    /// use chronographer_base::task::{TaskScheduleInterval, TaskSchedule};
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// # use std::error::Error;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+    /// let hourly = TaskScheduleInterval::aligned(Duration::from_secs(3600));
+    ///
+    /// // 10:23:45 on the first day, lands on 11:00:00
+    /// let next = hourly.schedule(UNIX_EPOCH + Duration::from_secs(10 * 3600 + 23 * 60 + 45)).await?;
+    /// assert_eq!(next, UNIX_EPOCH + Duration::from_secs(11 * 3600));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # See Also
+    /// - [`TaskScheduleInterval`] - The main source which the constructor method is part of.
+    /// - [`TaskScheduleInterval::anchored`] - A similar constructor but aligned to an arbitrary epoch.
+    /// - [`TaskScheduleInterval::duration`] - A similar constructor but relative to the current time.
+    pub fn aligned(interval: Duration) -> Self {
+        Self {
+            interval,
+            alignment: IntervalAlignment::Midnight,
+        }
     }
 
     /// A constructor for [`TaskScheduleInterval`] via an integer ``u64``.
@@ -268,7 +414,7 @@ impl TaskScheduleInterval {
     /// - [`TaskScheduleInterval::from_secs_f64`] - A simpler constructor for floating point second-based intervals.
     /// - [every!](chronographer::prelude::every) - A macro with a readable syntax for defining an interval.
     pub fn from_secs(interval: u64) -> Self {
-        Self(Duration::from_secs(interval))
+        Self::duration(Duration::from_secs(interval))
     }
 
     /// A constructor for [`TaskScheduleInterval`] via an ``f64``.
@@ -318,20 +464,113 @@ impl TaskScheduleInterval {
             return Err(IntervalSecondsOutOfRange)
         }
 
-        Ok(Self(Duration::from_secs_f64(interval)))
+        Ok(Self::duration(Duration::from_secs_f64(interval)))
     }
 }
 
 impl From<TaskScheduleInterval> for Duration {
     fn from(value: TaskScheduleInterval) -> Self {
-        value.0
+        value.interval
+    }
+}
+
+fn duration_from_nanos(nanos: u128) -> Duration {
+    Duration::new(
+        (nanos / 1_000_000_000) as u64,
+        (nanos % 1_000_000_000) as u32,
+    )
+}
+
+fn next_grid_point(epoch: SystemTime, interval: Duration, time: SystemTime) -> SystemTime {
+    let step = interval.as_nanos();
+
+    match time.duration_since(epoch) {
+        Ok(elapsed) => {
+            let steps = elapsed.as_nanos() / step + 1;
+            epoch + duration_from_nanos(steps * step)
+        }
+
+        Err(err) => {
+            let ahead = err.duration().as_nanos();
+            let mut steps = ahead / step;
+            if ahead % step == 0 {
+                steps -= 1;
+            }
+
+            epoch - duration_from_nanos(steps * step)
+        }
+    }
+}
+
+const SECS_PER_DAY: u64 = 86_400;
+
+fn next_aligned_point(interval: Duration, time: SystemTime) -> SystemTime {
+    let day = Duration::from_secs(SECS_PER_DAY);
+    let since_epoch = match time.duration_since(UNIX_EPOCH) {
+        Ok(since_epoch) if interval < day => since_epoch,
+        _ => return next_grid_point(UNIX_EPOCH, interval, time),
+    };
+
+    let midnight = UNIX_EPOCH + Duration::from_secs(since_epoch.as_secs() / SECS_PER_DAY * SECS_PER_DAY);
+    next_grid_point(midnight, interval, time).min(midnight + day)
+}
+
+impl TaskScheduleInterval {
+    fn next_point(&self, time: SystemTime) -> SystemTime {
+        if self.interval.is_zero() {
+            return time;
+        }
+
+        match self.alignment {
+            IntervalAlignment::Relative => time.add(self.interval),
+            IntervalAlignment::Anchored(epoch) => next_grid_point(epoch, self.interval, time),
+            IntervalAlignment::Midnight => next_aligned_point(self.interval, time),
+        }
     }
 }
 
 #[async_trait]
 impl TaskSchedule for TaskScheduleInterval {
     async fn schedule(&self, time: SystemTime) -> Result<SystemTime, Box<dyn Error + Send + Sync>> {
-        Ok(time.add(self.0))
+        Ok(self.next_point(time))
+    }
+
+    async fn occurrences(
+        &self,
+        from: SystemTime,
+        to: SystemTime,
+    ) -> Result<Vec<SystemTime>, Box<dyn Error + Send + Sync>> {
+        check_occurrence_range(from, to)?;
+        let mut occurrences = Vec::new();
+        if self.interval.is_zero() {
+            return Ok(occurrences);
+        }
+
+        let mut current = self.next_point(from);
+        while current <= to && occurrences.len() < MAX_OCCURRENCES {
+            occurrences.push(current);
+            current = self.next_point(current);
+        }
+
+        Ok(occurrences)
+    }
+    async fn explain(&self, now: SystemTime) -> Result<(SystemTime, String), Box<dyn Error + Send + Sync>> {
+        let next = self.next_point(now);
+        let basis = match self.alignment {
+            IntervalAlignment::Relative => format!("{:?} after", self.interval),
+            IntervalAlignment::Anchored(epoch) => format!(
+                "next multiple of {:?} (counted from {}) after",
+                self.interval,
+                display_time(epoch)
+            ),
+            IntervalAlignment::Midnight => format!(
+                "next multiple of {:?} (counted from midnight UTC) after",
+                self.interval
+            ),
+        };
+
+        let reason = format!("{basis} {} is {}", display_time(now), display_time(next));
+        Ok((next, reason))
     }
 }
 
@@ -339,7 +578,7 @@ macro_rules! integer_from_impl {
     ($val: ty) => {
         impl From<$val> for TaskScheduleInterval {
             fn from(value: $val) -> Self {
-                TaskScheduleInterval(Duration::from_secs(value as u64))
+                TaskScheduleInterval::from_secs(value as u64)
             }
         }
     };
@@ -0,0 +1,173 @@
+//! A standalone module containing only the [`TaskScheduleOutcome`] scheduling primitive
+
+use crate::task::TaskSchedule;
+use crate::task::hooks::{OnTaskEnd, TaskHook, TaskHookContext, TaskHookEvent};
+use async_trait::async_trait;
+use std::error::Error;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::SystemTime;
+
+const OUTCOME_UNKNOWN: u8 = 0;
+const OUTCOME_SUCCESS: u8 = 1;
+const OUTCOME_FAILURE: u8 = 2;
+
+/// The outcome of an execution, as recorded by [`LastRunOutcome`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RunOutcome {
+    Success,
+    Failure,
+}
+
+/// [`LastRunOutcome`] holds the outcome of the latest execution of a [Task](crate::task::Task), it is the
+/// channel through which [`TaskScheduleOutcome`] learns about executions (schedules only ever see the time).
+///
+/// It is a [`TaskHook`] for [`OnTaskEnd`], recording [`RunOutcome::Success`] when the execution returned
+/// no error and [`RunOutcome::Failure`] otherwise. It may also be written manually via [`LastRunOutcome::set`].
+#[derive(Debug, Default)]
+pub struct LastRunOutcome(AtomicU8);
+
+impl LastRunOutcome {
+    /// The outcome of the latest execution, ``None`` when nothing has been recorded yet.
+    pub fn get(&self) -> Option<RunOutcome> {
+        match self.0.load(Ordering::SeqCst) {
+            OUTCOME_SUCCESS => Some(RunOutcome::Success),
+            OUTCOME_FAILURE => Some(RunOutcome::Failure),
+            _ => None,
+        }
+    }
+
+    pub fn set(&self, outcome: RunOutcome) {
+        let value = match outcome {
+            RunOutcome::Success => OUTCOME_SUCCESS,
+            RunOutcome::Failure => OUTCOME_FAILURE,
+        };
+
+        self.0.store(value, Ordering::SeqCst);
+    }
+
+    pub fn clear(&self) {
+        self.0.store(OUTCOME_UNKNOWN, Ordering::SeqCst);
+    }
+}
+
+#[async_trait]
+impl TaskHook<OnTaskEnd> for LastRunOutcome {
+    async fn on_event(&self, _ctx: &TaskHookContext, payload: &<OnTaskEnd as TaskHookEvent>::Payload<'_>) {
+        self.set(match payload {
+            None => RunOutcome::Success,
+            Some(_) => RunOutcome::Failure,
+        });
+    }
+}
+
+/// [`TaskScheduleOutcome`] is a [`TaskSchedule`] wrapper used to execute a [Task](crate::task::Task) on a
+/// different cadence depending on whether its latest execution failed (e.g. polling every hour, but retrying
+/// after a minute when the poll failed). It is coarser than retrying inside the TaskFrame, as a failed execution
+/// ends and the Task waits for its next fire time like any other.
+///
+/// # Outcome Propagation
+/// The outcome is read from the [`LastRunOutcome`] returned by [`TaskScheduleOutcome::outcome`], which has to be
+/// attached to the Task for [`OnTaskEnd`]. The contract is as follows:
+/// - [`OnTaskEnd`] is emitted at the end of every execution, before the Scheduler computes the next fire time,
+///   so every computation sees the outcome of the execution which just ended.
+/// - A failed execution only reaches the schedule when the Scheduler is configured with
+///   [`FailoverPolicy::Reschedule`](crate::scheduler::FailoverPolicy::Reschedule), the default policy terminates the Task instead.
+/// - An execution which is cancelled midway (or skipped) emits no [`OnTaskEnd`], the previous outcome is kept.
+/// - Until the first outcome is recorded (or when the hook isn't attached), the success schedule is used.
+///
+/// # Scheduling Semantics
+/// Every computation is delegated to the success schedule when the latest execution succeeded, and to the
+/// failure schedule when it failed. Missed fire times (see [`TaskSchedule::occurrences`]) are enumerated by the
+/// success schedule, as the outcome of executions which never happened is unknown.
+///
+/// # Schedule Errors
+/// Forwards whatever error the delegated [`TaskSchedule`] returns.
+///
+/// # Constructor(s)
+/// The only constructor is [`TaskScheduleOutcome::new`], which accepts the success and failure schedules.
+///
+/// # Example(s)
+/// ```
+/// # use chronographer::prelude::*;
+/// use chronographer::task::{TaskScheduleInterval, TaskScheduleOutcome};
+/// use chronographer::task::hooks::events::OnTaskEnd;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// # let poll = DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) });
+/// let schedule = TaskScheduleOutcome::new(
+///     TaskScheduleInterval::from_secs(3600),
+///     TaskScheduleInterval::from_secs(60),
+/// );
+/// let outcome = schedule.outcome();
+///
+/// let task = Task::new(poll, schedule);
+/// task.attach_hook::<OnTaskEnd>(outcome).await;
+/// # }
+/// ```
+///
+/// # See Also
+/// - [`LastRunOutcome`] - The hook carrying the outcome of the latest execution.
+/// - [`RetriableTaskFrame`](crate::task::RetriableTaskFrame) - For retrying within the same execution instead.
+/// - [`TaskSchedule`] - The direct implementor of this trait.
+/// - [`Task`](crate::task::Task) - The main container which the schedule is hosted on.
+pub struct TaskScheduleOutcome<S: TaskSchedule, F: TaskSchedule> {
+    on_success: S,
+    on_failure: F,
+    outcome: Arc<LastRunOutcome>,
+}
+
+impl<S: TaskSchedule, F: TaskSchedule> TaskScheduleOutcome<S, F> {
+    pub fn new(on_success: S, on_failure: F) -> Self {
+        Self {
+            on_success,
+            on_failure,
+            outcome: Arc::new(LastRunOutcome::default()),
+        }
+    }
+
+    /// The hook to attach to the Task for [`OnTaskEnd`], see the outcome propagation contract of [`TaskScheduleOutcome`].
+    pub fn outcome(&self) -> Arc<LastRunOutcome> {
+        self.outcome.clone()
+    }
+
+    pub fn on_success(&self) -> &S {
+        &self.on_success
+    }
+
+    pub fn on_failure(&self) -> &F {
+        &self.on_failure
+    }
+
+    fn failed(&self) -> bool {
+        self.outcome.get() == Some(RunOutcome::Failure)
+    }
+}
+
+#[async_trait]
+impl<S: TaskSchedule, F: TaskSchedule> TaskSchedule for TaskScheduleOutcome<S, F> {
+    async fn schedule(&self, time: SystemTime) -> Result<SystemTime, Box<dyn Error + Send + Sync>> {
+        match self.failed() {
+            true => self.on_failure.schedule(time).await,
+            false => self.on_success.schedule(time).await,
+        }
+    }
+
+    async fn occurrences(
+        &self,
+        from: SystemTime,
+        to: SystemTime,
+    ) -> Result<Vec<SystemTime>, Box<dyn Error + Send + Sync>> {
+        self.on_success.occurrences(from, to).await
+    }
+
+    async fn explain(&self, now: SystemTime) -> Result<(SystemTime, String), Box<dyn Error + Send + Sync>> {
+        let (branch, (next, reason)) = match self.failed() {
+            true => ("failure", self.on_failure.explain(now).await?),
+            false => ("success", self.on_success.explain(now).await?),
+        };
+
+        Ok((next, format!("{reason} (following the {branch} schedule)")))
+    }
+}
@@ -0,0 +1,169 @@
+//! A standalone module containing only the [`TaskScheduleRandomWindow`] scheduling primitive
+
+use crate::errors::RandomWindowOutOfRange;
+use crate::task::TaskSchedule;
+use crate::task::schedule::{check_occurrence_range, display_time};
+use crate::utils::{RandomSource, ThreadRandomSource};
+use async_trait::async_trait;
+use std::error::Error;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const SECS_PER_DAY: u64 = 86_400;
+const NO_DAY: u64 = u64::MAX;
+
+/// [`TaskScheduleRandomWindow`] is a [`TaskSchedule`] used to execute a [Task](crate::task::Task) once
+/// per day at a random time inside a daily window (e.g. somewhere between ``00:00`` and ``06:00``),
+/// which is useful for spreading out load from many Tasks sharing the same window.
+///
+/// # Scheduling Semantics
+/// The window is expressed as two offsets from midnight (UTC), the start is inclusive while the end
+/// is exclusive. On every computation, [`TaskScheduleRandomWindow`] picks a uniformly random time
+/// (with millisecond granularity) inside:
+/// - Today's remaining window, if the current time hasn't passed the end of today's window and no
+///   time has been picked for today yet.
+/// - Tomorrow's window otherwise.
+///
+/// As a result, at most one time is picked per day, the returned times are strictly increasing across
+/// successive computations and never lie before the current time.
+///
+/// > **Note:** Recomputing the time while a previously picked one is still pending (for example via
+/// > [`Scheduler::refresh`](crate::scheduler::Scheduler::refresh)) moves it to tomorrow's window.
+///
+/// Since the picked times are random, [`TaskSchedule::occurrences`] never enumerates any missed fire times.
+///
+/// # Schedule Errors
+/// [`TaskScheduleRandomWindow`] only errors when the current time lies before the [`UNIX_EPOCH`].
+///
+/// # Constructor(s)
+/// The only constructor is [`TaskScheduleRandomWindow::new`], which uses the thread-local random
+/// generator. The [`RandomSource`] can be swapped via [`TaskScheduleRandomWindow::with_random_source`].
+///
+/// # Example(s)
+/// ```rust
+/// use chronographer::task::{TaskScheduleRandomWindow, TaskSchedule};
+/// use std::time::{Duration, UNIX_EPOCH};
+/// # use std::error::Error;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+/// // Somewhere between 00:00 and 06:00 each day
+/// let instance = TaskScheduleRandomWindow::new(Duration::ZERO, Duration::from_secs(6 * 3600))?;
+///
+/// let now = UNIX_EPOCH + Duration::from_secs(12 * 3600); // 12:00 on the first day
+/// let future_time = instance.schedule(now).await?;
+///
+/// assert!(future_time >= UNIX_EPOCH + Duration::from_secs(24 * 3600));
+/// assert!(future_time < UNIX_EPOCH + Duration::from_secs(30 * 3600));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # See Also
+/// - [`RandomSource`] - The source of randomness used for picking the time.
+/// - [`TaskSchedule`] - The direct implementor of this trait.
+/// - [`Task`](crate::task::Task) - The main container which the schedule is hosted on.
+/// - [`Scheduler`](crate::scheduler::Scheduler) - The side in which it manages the scheduling process of Tasks.
+pub struct TaskScheduleRandomWindow {
+    start: Duration,
+    end: Duration,
+    last_day: AtomicU64,
+    random: Box<dyn RandomSource>,
+}
+
+impl TaskScheduleRandomWindow {
+    /// A constructor for [`TaskScheduleRandomWindow`] via the start and end offsets of the daily window.
+    ///
+    /// # Argument(s)
+    /// It accepts two [`Duration`] arguments, ``start`` and ``end``, which are the offsets from
+    /// midnight (UTC) where the window starts (inclusive) and ends (exclusive).
+    ///
+    /// # Returns
+    /// A ``Result`` where on success, it contains the newly constructed [`TaskScheduleRandomWindow`]
+    /// and on failure a [`RandomWindowOutOfRange`].
+    ///
+    /// # Error(s)
+    /// The method returns a [`RandomWindowOutOfRange`] if ``start`` isn't before ``end`` or
+    /// if ``end`` exceeds 24 hours.
+    ///
+    /// # See Also
+    /// - [`TaskScheduleRandomWindow`] - The main source which the constructor method is part of.
+    /// - [`TaskScheduleRandomWindow::with_random_source`] - For swapping out the source of randomness.
+    pub fn new(start: Duration, end: Duration) -> Result<Self, RandomWindowOutOfRange> {
+        if start >= end || end > Duration::from_secs(SECS_PER_DAY) {
+            return Err(RandomWindowOutOfRange);
+        }
+
+        Ok(Self {
+            start,
+            end,
+            last_day: AtomicU64::new(NO_DAY),
+            random: Box::new(ThreadRandomSource),
+        })
+    }
+
+    pub fn with_random_source(mut self, random: impl RandomSource) -> Self {
+        self.random = Box::new(random);
+        self
+    }
+
+    pub fn start(&self) -> Duration {
+        self.start
+    }
+
+    pub fn end(&self) -> Duration {
+        self.end
+    }
+
+    // The day the next time is picked in alongside the lower bound of the window left on it
+    fn next_window(&self, since_epoch: Duration) -> (u64, Duration) {
+        let day = since_epoch.as_secs() / SECS_PER_DAY;
+        let elapsed = since_epoch - Duration::from_secs(day * SECS_PER_DAY);
+
+        if self.last_day.load(Ordering::Relaxed) != day && elapsed < self.end {
+            (day, self.start.max(elapsed))
+        } else {
+            (day + 1, self.start)
+        }
+    }
+}
+
+#[async_trait]
+impl TaskSchedule for TaskScheduleRandomWindow {
+    async fn schedule(&self, time: SystemTime) -> Result<SystemTime, Box<dyn Error + Send + Sync>> {
+        let (day, lower) = self.next_window(time.duration_since(UNIX_EPOCH)?);
+        let span = (self.end - lower).as_millis() as u64;
+        let offset = lower + Duration::from_millis(self.random.u64_below(span.max(1)));
+        self.last_day.store(day, Ordering::Relaxed);
+
+        Ok(UNIX_EPOCH + Duration::from_secs(day * SECS_PER_DAY) + offset)
+    }
+
+    async fn occurrences(
+        &self,
+        from: SystemTime,
+        to: SystemTime,
+    ) -> Result<Vec<SystemTime>, Box<dyn Error + Send + Sync>> {
+        check_occurrence_range(from, to)?;
+
+        // Past random picks cannot be recovered, hence there is nothing to enumerate
+        Ok(Vec::new())
+    }
+
+    /*
+        Picking a time would mark the day as taken (and draw from the random source), so instead
+        the earliest time of the window the next pick lands in is returned
+     */
+    async fn explain(&self, now: SystemTime) -> Result<(SystemTime, String), Box<dyn Error + Send + Sync>> {
+        let (day, lower) = self.next_window(now.duration_since(UNIX_EPOCH)?);
+        let midnight = UNIX_EPOCH + Duration::from_secs(day * SECS_PER_DAY);
+        let reason = format!(
+            "next fire after {} is picked at random between {} and {}",
+            display_time(now),
+            display_time(midnight + lower),
+            display_time(midnight + self.end)
+        );
+
+        Ok((midnight + lower, reason))
+    }
+}
@@ -0,0 +1,236 @@
+//! A standalone module containing only the [`TaskScheduleSolar`] scheduling primitive
+
+use crate::errors::{SolarCoordinatesOutOfRange, SolarEventUnreachable};
+use crate::task::TaskSchedule;
+use crate::task::schedule::display_time;
+use async_trait::async_trait;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const SECS_PER_DAY: f64 = 86_400.0;
+const UNIX_EPOCH_JULIAN_DAY: f64 = 2_440_587.5;
+const J2000_JULIAN_DAY: f64 = 2_451_545.0;
+const J2000_UNIX_DAY: i64 = 10_957;
+const EARTH_OBLIQUITY: f64 = 23.4397;
+
+// The apparent altitude of the sun's center at sunrise / sunset (atmospheric refraction plus its radius)
+const HORIZON_ALTITUDE: f64 = -0.833;
+
+// Polar regions may go up to half a year without a given solar event
+const SEARCH_DAYS: i64 = 370;
+
+/// The solar event a [`TaskScheduleSolar`] fires relative to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SolarEvent {
+    Sunrise,
+    Sunset,
+}
+
+impl SolarEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SolarEvent::Sunrise => "sunrise",
+            SolarEvent::Sunset => "sunset",
+        }
+    }
+}
+
+impl Display for SolarEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// [`TaskScheduleSolar`] is a [`TaskSchedule`] used to execute a [Task](crate::task::Task) relative to
+/// the sunrise or sunset at a given location (e.g. "30 minutes after sunset"), which is useful for
+/// IoT / home-automation use cases.
+///
+/// # Scheduling Semantics
+/// For every day, [`TaskScheduleSolar`] computes the time of the [`SolarEvent`] at the configured
+/// latitude / longitude and shifts it by the offset. The future time is the earliest shifted time
+/// which comes strictly after the current time. Days where the event doesn't occur (polar day or
+/// polar night) are skipped.
+///
+/// # Accuracy
+/// The solar math is self-contained (no external dependency), it uses the sunrise equation built on
+/// the low-precision solar coordinates of the Astronomical Almanac (the same approach as the NOAA
+/// solar calculator). The computed times are within a minute or two of the published times at
+/// latitudes below the polar circles, the error grows close to them. The standard ``-0.833°``
+/// horizon is used, elevation and local weather conditions are not accounted for.
+///
+/// # Schedule Errors
+/// [`TaskScheduleSolar`] errors when the current time lies before the [`UNIX_EPOCH`] or when the
+/// [`SolarEvent`] doesn't occur at all within a year of the current time, which is returned as a
+/// [`SolarEventUnreachable`].
+///
+/// # Constructor(s)
+/// The only constructor is [`TaskScheduleSolar::new`], the offset can be configured via
+/// [`TaskScheduleSolar::after`] and [`TaskScheduleSolar::before`].
+///
+/// # Example(s)
+/// ```rust
+/// use chronographer::task::{SolarEvent, TaskScheduleSolar, TaskSchedule};
+/// use std::time::{Duration, UNIX_EPOCH};
+/// # use std::error::Error;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+/// // 30 minutes after sunset in London
+/// let instance = TaskScheduleSolar::new(51.5074, -0.1278, SolarEvent::Sunset)?
+///     .after(Duration::from_secs(30 * 60));
+///
+/// let now = UNIX_EPOCH + Duration::from_secs(1_908_230_400); // 2030-06-21 00:00:00 UTC
+/// let next = instance.schedule(now).await?;
+///
+/// // Sunset is around 20:21 UTC on that day
+/// assert!(next > now + Duration::from_secs(20 * 3600 + 45 * 60));
+/// assert!(next < now + Duration::from_secs(20 * 3600 + 57 * 60));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # See Also
+/// - [`SolarEvent`] - The solar event which is scheduled relative to.
+/// - [`TaskSchedule`] - The direct implementor of this trait.
+/// - [`Task`](crate::task::Task) - The main container which the schedule is hosted on.
+/// - [`Scheduler`](crate::scheduler::Scheduler) - The side in which it manages the scheduling process of Tasks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TaskScheduleSolar {
+    latitude: f64,
+    longitude: f64,
+    event: SolarEvent,
+    offset_secs: f64,
+}
+
+impl TaskScheduleSolar {
+    /// A constructor for [`TaskScheduleSolar`] via the coordinates of the location and the [`SolarEvent`].
+    ///
+    /// # Argument(s)
+    /// It accepts the ``latitude`` (north is positive) and the ``longitude`` (east is positive) in
+    /// degrees, alongside the ``event`` to schedule relative to. There is no offset by default.
+    ///
+    /// # Returns
+    /// A ``Result`` where on success, it contains the newly constructed [`TaskScheduleSolar`]
+    /// and on failure a [`SolarCoordinatesOutOfRange`].
+    ///
+    /// # Error(s)
+    /// The method returns a [`SolarCoordinatesOutOfRange`] if the ``latitude`` lies outside of
+    /// ``[-90, 90]`` or the ``longitude`` outside of ``[-180, 180]`` (NaN included).
+    ///
+    /// # See Also
+    /// - [`TaskScheduleSolar`] - The main source which the constructor method is part of.
+    pub fn new(latitude: f64, longitude: f64, event: SolarEvent) -> Result<Self, SolarCoordinatesOutOfRange> {
+        if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+            return Err(SolarCoordinatesOutOfRange);
+        }
+
+        Ok(Self {
+            latitude,
+            longitude,
+            event,
+            offset_secs: 0.0,
+        })
+    }
+
+    /// Fires ``offset`` after the [`SolarEvent`], replacing any previously configured offset.
+    pub fn after(mut self, offset: Duration) -> Self {
+        self.offset_secs = offset.as_secs_f64();
+        self
+    }
+
+    /// Fires ``offset`` before the [`SolarEvent`], replacing any previously configured offset.
+    pub fn before(mut self, offset: Duration) -> Self {
+        self.offset_secs = -offset.as_secs_f64();
+        self
+    }
+
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    pub fn event(&self) -> SolarEvent {
+        self.event
+    }
+
+    /// The time of the [`SolarEvent`] on the given day (counted from the [`UNIX_EPOCH`]) as seconds
+    /// since the [`UNIX_EPOCH`], ``None`` if it doesn't occur on that day.
+    fn event_on(&self, day: i64) -> Option<f64> {
+        let mean_noon = (day - J2000_UNIX_DAY) as f64 - self.longitude / 360.0;
+
+        let anomaly = (357.5291 + 0.98560028 * mean_noon).rem_euclid(360.0).to_radians();
+        let center = 1.9148 * anomaly.sin() + 0.0200 * (2.0 * anomaly).sin() + 0.0003 * (3.0 * anomaly).sin();
+        let ecliptic_longitude = (anomaly.to_degrees() + center + 282.9372).rem_euclid(360.0).to_radians();
+
+        let transit = J2000_JULIAN_DAY + mean_noon + 0.0053 * anomaly.sin()
+            - 0.0069 * (2.0 * ecliptic_longitude).sin();
+
+        let declination = (ecliptic_longitude.sin() * EARTH_OBLIQUITY.to_radians().sin()).asin();
+        let latitude = self.latitude.to_radians();
+        let hour_angle = (HORIZON_ALTITUDE.to_radians().sin() - latitude.sin() * declination.sin())
+            / (latitude.cos() * declination.cos());
+
+        if !(-1.0..=1.0).contains(&hour_angle) {
+            return None;
+        }
+
+        let half_day = hour_angle.acos().to_degrees() / 360.0;
+        let julian = match self.event {
+            SolarEvent::Sunrise => transit - half_day,
+            SolarEvent::Sunset => transit + half_day,
+        };
+
+        Some((julian - UNIX_EPOCH_JULIAN_DAY) * SECS_PER_DAY)
+    }
+
+    fn next_point(&self, now: SystemTime) -> Result<SystemTime, Box<dyn Error + Send + Sync>> {
+        let now_secs = now.duration_since(UNIX_EPOCH)?.as_secs_f64();
+        let today = (now_secs / SECS_PER_DAY).floor() as i64;
+
+        // The offset and the longitude may push an event onto a neighbouring UTC day
+        let first_day = today - 1 - (self.offset_secs.abs() / SECS_PER_DAY).ceil() as i64;
+        (first_day..=today + SEARCH_DAYS)
+            .filter_map(|day| self.event_on(day))
+            .map(|secs| secs + self.offset_secs)
+            .find(|secs| *secs > now_secs)
+            .map(|secs| UNIX_EPOCH + Duration::from_secs_f64(secs.max(0.0)))
+            .ok_or_else(|| {
+                Box::new(SolarEventUnreachable {
+                    event: self.event.as_str(),
+                }) as Box<dyn Error + Send + Sync>
+            })
+    }
+}
+
+#[async_trait]
+impl TaskSchedule for TaskScheduleSolar {
+    async fn schedule(&self, time: SystemTime) -> Result<SystemTime, Box<dyn Error + Send + Sync>> {
+        self.next_point(time)
+    }
+
+    async fn explain(&self, now: SystemTime) -> Result<(SystemTime, String), Box<dyn Error + Send + Sync>> {
+        let next = self.next_point(now)?;
+        let offset = if self.offset_secs > 0.0 {
+            format!("{:?} after ", Duration::from_secs_f64(self.offset_secs))
+        } else if self.offset_secs < 0.0 {
+            format!("{:?} before ", Duration::from_secs_f64(-self.offset_secs))
+        } else {
+            String::new()
+        };
+
+        let reason = format!(
+            "next {offset}{} at ({}, {}) after {} is {}",
+            self.event,
+            self.latitude,
+            self.longitude,
+            display_time(now),
+            display_time(next)
+        );
+
+        Ok((next, reason))
+    }
+}
@@ -0,0 +1,99 @@
+//! A standalone module containing only the [`TaskScheduleStartup`] scheduling primitive
+
+use crate::task::TaskSchedule;
+use crate::task::schedule::display_time;
+use async_trait::async_trait;
+use std::error::Error;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::SystemTime;
+
+/// [`TaskScheduleStartup`] is a [`TaskSchedule`] wrapper used to execute a [Task](crate::task::Task)
+/// immediately once the [Scheduler](crate::scheduler::Scheduler) starts, then follow the cadence of
+/// the wrapped [`TaskSchedule`] (similar to CRON's ``@reboot`` combined with a recurring expression).
+///
+/// # Scheduling Semantics
+/// The first computation returns the current time (identical to [`TaskScheduleImmediate`](crate::task::TaskScheduleImmediate)),
+/// every computation afterward is delegated to the inner [`TaskSchedule`].
+///
+/// Whether the first computation happened is tracked in-memory on the instance itself and is
+/// **NOT** persisted. An instance which is constructed again (for example when a Task is restored after a
+/// restart) fires immediately once more, which is exactly the ``@reboot`` semantic. Sharing the same
+/// instance across restarts of a [Scheduler](crate::scheduler::Scheduler) within one process does not.
+///
+/// # Schedule Errors
+/// The first computation **NEVER** errors, the rest forward whatever error the inner [`TaskSchedule`] returns.
+///
+/// # Constructor(s)
+/// The only constructor is [`TaskScheduleStartup::new`], which accepts the inner [`TaskSchedule`].
+///
+/// # Example(s)
+/// ```rust
+/// use chronographer::task::{TaskScheduleInterval, TaskScheduleStartup, TaskSchedule};
+/// use std::time::{Duration, SystemTime};
+/// # use std::error::Error;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+/// // Run now, then every hour
+/// let instance = TaskScheduleStartup::new(TaskScheduleInterval::from_secs(3600));
+/// let now = SystemTime::now();
+///
+/// assert_eq!(instance.schedule(now).await?, now);
+/// assert_eq!(instance.schedule(now).await?, now + Duration::from_secs(3600));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # See Also
+/// - [`TaskScheduleImmediate`](crate::task::TaskScheduleImmediate) - For always executing immediately.
+/// - [`TaskSchedule`] - The direct implementor of this trait.
+/// - [`Task`](crate::task::Task) - The main container which the schedule is hosted on.
+/// - [`Scheduler`](crate::scheduler::Scheduler) - The side in which it manages the scheduling process of Tasks.
+pub struct TaskScheduleStartup<S: TaskSchedule> {
+    inner: S,
+    started: AtomicBool,
+}
+
+impl<S: TaskSchedule> TaskScheduleStartup<S> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            started: AtomicBool::new(false),
+        }
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn has_started(&self) -> bool {
+        self.started.load(Ordering::Relaxed)
+    }
+}
+
+#[async_trait]
+impl<S: TaskSchedule> TaskSchedule for TaskScheduleStartup<S> {
+    async fn schedule(&self, time: SystemTime) -> Result<SystemTime, Box<dyn Error + Send + Sync>> {
+        if !self.started.swap(true, Ordering::Relaxed) {
+            return Ok(time);
+        }
+
+        self.inner.schedule(time).await
+    }
+
+    async fn occurrences(
+        &self,
+        from: SystemTime,
+        to: SystemTime,
+    ) -> Result<Vec<SystemTime>, Box<dyn Error + Send + Sync>> {
+        self.inner.occurrences(from, to).await
+    }
+
+    async fn explain(&self, now: SystemTime) -> Result<(SystemTime, String), Box<dyn Error + Send + Sync>> {
+        if !self.has_started() {
+            return Ok((now, format!("first fire after startup is right away at {}", display_time(now))));
+        }
+
+        self.inner.explain(now).await
+    }
+}
@@ -0,0 +1,309 @@
+//! A standalone module containing the [`TaskScheduleZonedCron`] scheduling primitive and its timezones
+
+use crate::task::TaskSchedule;
+use crate::task::schedule::{MAX_OCCURRENCES, TaskScheduleCron, check_occurrence_range, display_time};
+use async_trait::async_trait;
+use std::error::Error;
+use std::time::{Duration, SystemTime};
+use time::UtcDateTime;
+
+const SECS_PER_DAY: i64 = 86_400;
+
+/// [`CronTimeZone`] supplies the UTC offset in effect at any instant for [`TaskScheduleZonedCron`], which
+/// is all that's needed to evaluate a CRON expression in local time (including across DST transitions).
+///
+/// ChronoGrapher ships no timezone database, [`FixedOffsetZone`] and [`DstZone`] cover fixed offsets and
+/// rule-based daylight saving time respectively, while zones backed by a database (for instance ``chrono-tz``)
+/// can be plugged in by implementing this trait.
+pub trait CronTimeZone: Send + Sync + 'static {
+    /// The offset from UTC (in seconds, positive east of UTC) in effect at ``instant``.
+    fn offset_secs(&self, instant: SystemTime) -> i32;
+}
+
+/// A [`CronTimeZone`] with a fixed offset from UTC (in seconds, positive east of UTC) and no DST.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedOffsetZone(pub i32);
+
+impl CronTimeZone for FixedOffsetZone {
+    fn offset_secs(&self, _instant: SystemTime) -> i32 {
+        self.0
+    }
+}
+
+/// The yearly date and time a [`DstZone`] switches offsets at, following the ``Mm.w.d/time`` rules of
+/// POSIX ``TZ`` strings (e.g. the second Sunday of March at 02:00).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DstTransition {
+    month: u8,
+    week: u8,
+    weekday: u8,
+    at: Duration,
+}
+
+impl DstTransition {
+    /// Constructs a [`DstTransition`] on the ``week``-th ``weekday`` of ``month``, at ``at`` past midnight.
+    ///
+    /// # Argument(s)
+    /// - ``month`` ranges from 1 (January) to 12 (December).
+    /// - ``week`` ranges from 1 to 5, where 5 stands for the **last** ``weekday`` of the month.
+    /// - ``weekday`` ranges from 0 (Sunday) to 6 (Saturday).
+    /// - ``at`` is the wall-clock time of the transition, expressed in the offset in effect **before** it.
+    ///
+    /// # Panics
+    /// Panics if any argument lies outside of its range, or ``at`` is a day or longer.
+    pub fn new(month: u8, week: u8, weekday: u8, at: Duration) -> Self {
+        assert!((1..=12).contains(&month), "the month of a DstTransition must lie within 1..=12");
+        assert!((1..=5).contains(&week), "the week of a DstTransition must lie within 1..=5");
+        assert!(weekday <= 6, "the weekday of a DstTransition must lie within 0..=6");
+        assert!(
+            at < Duration::from_secs(SECS_PER_DAY as u64),
+            "the time of a DstTransition must be shorter than a day"
+        );
+
+        Self { month, week, weekday, at }
+    }
+
+    // The wall-clock time of the transition within ``year``, expressed as if it were UTC
+    fn wall_time(&self, year: i32) -> Option<UtcDateTime> {
+        let month = time::Month::try_from(self.month).ok()?;
+        let first = time::Date::from_calendar_date(year, month, 1).ok()?;
+        let lead = (self.weekday + 7 - first.weekday().number_days_from_sunday()) % 7;
+
+        let mut day = first + time::Duration::days((lead + (self.week - 1) * 7) as i64);
+        while day.month() != month {
+            day -= time::Duration::days(7);
+        }
+
+        Some(day.midnight().as_utc() + self.at)
+    }
+}
+
+/// [`DstZone`] is a rule-based [`CronTimeZone`], observing the standard offset for part of the year and
+/// the daylight offset between the yearly ``start`` and ``end`` [`DstTransition`] (the equivalent of a POSIX
+/// ``TZ`` string such as ``EST5EDT,M3.2.0,M11.1.0``). Zones whose daylight period spans the new year (the
+/// southern hemisphere) are supported as well.
+///
+/// The rules apply to every year alike, historical changes to them aren't modeled.
+///
+/// # Example(s)
+/// ```
+/// use chronographer::task::{DstTransition, DstZone};
+/// use std::time::Duration;
+///
+/// const HOUR: i32 = 3600;
+///
+/// // US Eastern, from the second Sunday of March to the first Sunday of November
+/// let eastern = DstZone::new(
+///     -5 * HOUR,
+///     -4 * HOUR,
+///     DstTransition::new(3, 2, 0, Duration::from_secs(2 * 3600)),
+///     DstTransition::new(11, 1, 0, Duration::from_secs(2 * 3600)),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DstZone {
+    standard: i32,
+    daylight: i32,
+    start: DstTransition,
+    end: DstTransition,
+}
+
+impl DstZone {
+    /// Constructs a [`DstZone`] from the ``standard`` and ``daylight`` offsets (in seconds, positive east of UTC),
+    /// with the daylight offset in effect from ``start`` until ``end``.
+    pub fn new(standard: i32, daylight: i32, start: DstTransition, end: DstTransition) -> Self {
+        Self { standard, daylight, start, end }
+    }
+
+    pub fn standard_offset_secs(&self) -> i32 {
+        self.standard
+    }
+
+    pub fn daylight_offset_secs(&self) -> i32 {
+        self.daylight
+    }
+}
+
+impl CronTimeZone for DstZone {
+    fn offset_secs(&self, instant: SystemTime) -> i32 {
+        let instant = UtcDateTime::from(instant);
+        let year = instant.year();
+
+        let start = self.start.wall_time(year).map(|wall| shift(wall, -self.standard));
+        let end = self.end.wall_time(year).map(|wall| shift(wall, -self.daylight));
+
+        let (Some(start), Some(end)) = (start, end) else {
+            return self.standard;
+        };
+
+        let daylight = match start < end {
+            true => start <= instant && instant < end,
+            false => instant >= start || instant < end,
+        };
+
+        if daylight { self.daylight } else { self.standard }
+    }
+}
+
+fn shift(dt: UtcDateTime, secs: i32) -> UtcDateTime {
+    dt + time::Duration::seconds(secs as i64)
+}
+
+/// [`TaskScheduleZonedCron`] is a [`TaskSchedule`] used to execute a [Task](crate::task::Task) based on a
+/// CRON expression evaluated in the local time of a [`CronTimeZone`], whereas [`TaskScheduleCron`] always
+/// evaluates it in UTC.
+///
+/// # Scheduling Semantics
+/// The CRON expression is matched against the wall-clock time of the zone, every matching wall-clock time
+/// fires exactly once, which takes care of the DST transitions:
+/// - **Spring-forward**, wall-clock times skipped by the transition (e.g. ``02:30`` when the clock jumps from
+///   ``02:00`` to ``03:00``) fire at the next valid instant, the transition itself (``03:00``). Multiple skipped
+///   wall-clock times collapse into that single fire.
+/// - **Fall-back**, wall-clock times occurring twice (e.g. ``01:30`` when the clock goes back from ``02:00`` to
+///   ``01:00``) fire only on their first occurrence. Frequent expressions (e.g. every minute) therefore don't fire
+///   during the repeated hour, as its wall-clock times have all fired already.
+///
+/// As a result a daily expression such as ``0 0 2 * * ?`` fires once on every day, transition days included.
+///
+/// # Schedule Errors
+/// Errors the same way [`TaskScheduleCron`] does, when no future match exists.
+///
+/// # Constructor(s)
+/// The only constructor is [`TaskScheduleZonedCron::new`], which accepts the [`TaskScheduleCron`] and the zone.
+///
+/// # Example(s)
+/// ```
+/// use chronographer::task::{TaskScheduleCron, TaskScheduleZonedCron};
+/// use std::str::FromStr;
+/// # use chronographer::task::{DstTransition, DstZone};
+/// # use std::time::Duration;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let at = Duration::from_secs(2 * 3600);
+/// # let eastern = DstZone::new(-5 * 3600, -4 * 3600, DstTransition::new(3, 2, 0, at), DstTransition::new(11, 1, 0, at));
+///
+/// // Every day at 2:00 AM US Eastern time (see DstZone for constructing the zone)
+/// let schedule = TaskScheduleZonedCron::new(TaskScheduleCron::from_str("0 0 2 * * ?")?, eastern);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # See Also
+/// - [`CronTimeZone`] - The source of the UTC offsets.
+/// - [`TaskScheduleCron`] - For evaluating the CRON expression in UTC.
+/// - [`TaskSchedule`] - The direct implementor of this trait.
+/// - [`Task`](crate::task::Task) - The main container which the schedule is hosted on.
+pub struct TaskScheduleZonedCron<Z: CronTimeZone> {
+    cron: TaskScheduleCron,
+    zone: Z,
+}
+
+impl<Z: CronTimeZone> TaskScheduleZonedCron<Z> {
+    pub fn new(cron: TaskScheduleCron, zone: Z) -> Self {
+        Self { cron, zone }
+    }
+
+    pub fn cron(&self) -> &TaskScheduleCron {
+        &self.cron
+    }
+
+    pub fn zone(&self) -> &Z {
+        &self.zone
+    }
+
+    fn offset_at(&self, instant: UtcDateTime) -> i32 {
+        self.zone.offset_secs(SystemTime::from(instant))
+    }
+
+    /*
+        Maps a wall-clock time back to the earliest instant showing it, probing the offsets a day before
+        and after (a single transition is assumed in between). When neither offset maps back to the
+        wall-clock time, it lies in a gap and the transition instant is searched for instead
+     */
+    fn resolve(&self, wall: UtcDateTime) -> UtcDateTime {
+        let day = time::Duration::seconds(SECS_PER_DAY);
+        let before = self.offset_at(wall - day);
+        let after = self.offset_at(wall + day);
+
+        let earliest = [before, after]
+            .into_iter()
+            .map(|offset| (shift(wall, -offset), offset))
+            .filter(|(instant, offset)| self.offset_at(*instant) == *offset)
+            .map(|(instant, _)| instant)
+            .min();
+
+        if let Some(instant) = earliest {
+            return instant;
+        }
+
+        let (mut low, mut high) = (shift(wall, -before.max(after)), shift(wall, -before.min(after)));
+        let target = self.offset_at(high);
+        while (high - low).whole_seconds() > 1 {
+            let middle = low + (high - low) / 2;
+            match self.offset_at(middle) == target {
+                true => high = middle,
+                false => low = middle,
+            }
+        }
+
+        high
+    }
+
+    fn next_time_from(&self, current: SystemTime) -> Option<SystemTime> {
+        let current = UtcDateTime::from(current);
+        let mut wall = shift(current, self.offset_at(current));
+
+        loop {
+            wall = self.cron.next_wall_time(wall)?;
+            let instant = self.resolve(wall);
+            if instant > current {
+                return Some(SystemTime::from(instant));
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<Z: CronTimeZone> TaskSchedule for TaskScheduleZonedCron<Z> {
+    async fn schedule(&self, time: SystemTime) -> Result<SystemTime, Box<dyn Error + Send + Sync>> {
+        Ok(self
+            .next_time_from(time)
+            .ok_or("No valid scheduling time found")?)
+    }
+
+    async fn occurrences(
+        &self,
+        from: SystemTime,
+        to: SystemTime,
+    ) -> Result<Vec<SystemTime>, Box<dyn Error + Send + Sync>> {
+        check_occurrence_range(from, to)?;
+        let mut occurrences = Vec::new();
+        let mut current = from;
+
+        while let Some(next) = self.next_time_from(current) {
+            if next > to || occurrences.len() >= MAX_OCCURRENCES {
+                break;
+            }
+
+            occurrences.push(next);
+            current = next;
+        }
+
+        Ok(occurrences)
+    }
+
+    async fn explain(&self, now: SystemTime) -> Result<(SystemTime, String), Box<dyn Error + Send + Sync>> {
+        let cron = &self.cron;
+        let next = self.next_time_from(now).ok_or_else(|| {
+            format!("no match of cron expression `{cron}` (in local time) after {}", display_time(now))
+        })?;
+
+        let offset = self.zone.offset_secs(next);
+        let reason = format!(
+            "next match of cron expression `{cron}` (in local time) after {} is {} (UTC offset of {offset} seconds)",
+            display_time(now),
+            display_time(next)
+        );
+
+        Ok((next, reason))
+    }
+}
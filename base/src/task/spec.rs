@@ -0,0 +1,461 @@
+//! Declarative definitions of [`Tasks`](Task) which can be loaded from any configuration format
+//! supported by ``serde`` (TOML, YAML, JSON...). This module is gated behind the ``serde`` feature.
+//!
+//! # Schema
+//! A [`TaskSpec`] consists of:
+//! - ``name`` - The name of the Task.
+//! - ``schedule`` - A [`ScheduleSpec`] tagged by its ``type``, one of ``immediate``,
+//!   ``interval`` (with ``seconds`` and an optional ``anchor`` as UNIX seconds), ``cron``
+//!   (with ``expression``) or ``custom`` (referencing a registered schedule factory by
+//!   ``factory`` alongside its ``params``).
+//! - ``frames`` - The TaskFrame chain (executed sequentially), each entry is a [`FrameSpec`]
+//!   referencing a registered factory by name alongside its ``params``.
+//! - ``priority`` - Optional, the [`TaskPriority`] of the Task, defaults to ``0``.
+//! - ``max_runs`` - Optional, once reached the TaskFrame chain no longer executes.
+//! - ``max_lifetime_secs`` - Optional, seconds after which the Task is dropped by the Scheduler
+//!   (whichever of ``max_runs`` and ``max_lifetime_secs`` is hit first wins).
+//! - ``tags`` - Optional, a list of strings.
+//! - ``description`` / ``owner`` - Optional, the [`TaskMetadata`] of the Task, both default to empty.
+//!
+//! For example in TOML:
+//! ```toml
+//! name = "cleanup"
+//! priority = 5
+//! max_runs = 10
+//! tags = ["maintenance"]
+//!
+//! [schedule]
+//! type = "interval"
+//! seconds = 30
+//!
+//! [[frames]]
+//! factory = "purge_cache"
+//! params = { older_than_secs = 3600 }
+//! ```
+//!
+//! # Registering Factories
+//! Custom TaskFrames are registered in a [`TaskFrameFactoryRegistry`] under a name, the factory
+//! receives the ``params`` of the [`FrameSpec`] and constructs the TaskFrame (or returns an error
+//! if the parameters are invalid). The registry then builds [`Tasks`](Task) via
+//! [`TaskFrameFactoryRegistry::load`].
+//!
+//! # Registering Schedules
+//! User-defined [`TaskSchedules`](TaskSchedule) are registered the same way, in a [`TaskScheduleFactoryRegistry`]
+//! under a name, the factory receiving the ``params`` of a ``custom`` schedule. The registry is attached to the
+//! [`TaskFrameFactoryRegistry`] (via [`TaskFrameFactoryRegistry::register_schedule`] or [`TaskFrameFactoryRegistry::with_schedules`]),
+//! for instance:
+//! ```
+//! # use chronographer::task::spec::{SpecValue, TaskFrameFactoryRegistry};
+//! # use chronographer::task::{TaskSchedule, TaskScheduleInterval};
+//! # let mut registry = TaskFrameFactoryRegistry::new();
+//! registry.register_schedule("every_minutes", |params| {
+//!     let minutes = params.get("minutes").and_then(SpecValue::as_integer).ok_or("missing minutes")?;
+//!     let minutes = u64::try_from(minutes)?;
+//!     Ok(Box::new(TaskScheduleInterval::from_secs(minutes * 60)) as Box<dyn TaskSchedule>)
+//! });
+//! ```
+//! Which restores the following schedule:
+//! ```toml
+//! [schedule]
+//! type = "custom"
+//! factory = "every_minutes"
+//! params = { minutes = 15 }
+//! ```
+//!
+//! # See Also
+//! - [`TaskSpec`] - The declarative definition of a Task.
+//! - [`TaskFrameFactoryRegistry`] - The registry which builds Tasks from their definitions.
+//! - [`TaskScheduleFactoryRegistry`] - The registry which builds user-defined schedules.
+//! - [`TaskSpecError`] - The error returned when a definition cannot be built.
+
+use crate::errors::TaskSpecError;
+use crate::task::{
+    CollectionTaskError, CollectionTaskFrame, ErasedTask, ErasedTaskFrame, Task, TaskMetadata, TaskPriority, TaskSchedule,
+    TaskScheduleCron, TaskScheduleImmediate, TaskScheduleInterval, ThresholdTaskFrame,
+};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::num::NonZeroUsize;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, UNIX_EPOCH};
+
+/// [`SpecValue`] is a single parameter value of a [`FrameSpec`] or a ``custom`` [`ScheduleSpec`], it is
+/// format-agnostic so factories don't depend on whichever format the definition was loaded from.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum SpecValue {
+    /// A boolean, such as ``true``.
+    Bool(bool),
+
+    /// A whole number, such as ``3600``.
+    Integer(i64),
+
+    /// A fractional number, such as ``0.5``.
+    Float(f64),
+
+    /// A string, such as ``"Europe/Athens"``.
+    String(String),
+
+    /// An array of values.
+    List(Vec<SpecValue>),
+
+    /// A nested table / object of values.
+    Table(BTreeMap<String, SpecValue>),
+}
+
+impl SpecValue {
+    /// The value as a boolean, ``None`` if it isn't one.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            SpecValue::Bool(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    /// The value as a whole number, ``None`` if it isn't one.
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            SpecValue::Integer(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    /// The value as a fractional number, whole numbers are widened to one, ``None`` otherwise.
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            SpecValue::Float(val) => Some(*val),
+            SpecValue::Integer(val) => Some(*val as f64),
+            _ => None,
+        }
+    }
+
+    /// The value as a string, ``None`` if it isn't one.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            SpecValue::String(val) => Some(val),
+            _ => None,
+        }
+    }
+}
+
+/// The ``params`` handed to the factories, keyed by their name.
+pub type SpecParams = BTreeMap<String, SpecValue>;
+
+/// [`ScheduleSpec`] is the declarative definition of a [`TaskSchedule`], tagged by its ``type``.
+///
+/// # See Also
+/// - [`TaskSpec`] - The definition containing it.
+/// - [`TaskScheduleFactoryRegistry`] - Where ``custom`` schedules are looked up.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ScheduleSpec {
+    /// Builds a [`TaskScheduleImmediate`].
+    Immediate,
+
+    /// Builds a [`TaskScheduleInterval`] of ``seconds``, anchored to ``anchor`` (as UNIX seconds) if present.
+    Interval {
+        seconds: f64,
+
+        #[serde(default)]
+        anchor: Option<u64>,
+    },
+
+    /// Builds a [`TaskScheduleCron`] by parsing ``expression``.
+    Cron {
+        expression: String,
+    },
+
+    /// Builds a user-defined schedule by handing ``params`` to the schedule factory registered under ``factory``.
+    Custom {
+        factory: String,
+
+        #[serde(default)]
+        params: SpecParams,
+    },
+}
+
+impl ScheduleSpec {
+    /// Builds the schedule, ``custom`` schedules fail with [`TaskSpecError::UnknownScheduleFactory`]
+    /// as there is no registry to look them up in (see [`ScheduleSpec::build_with`]).
+    pub fn build(&self) -> Result<Box<dyn TaskSchedule>, TaskSpecError> {
+        self.build_with(&TaskScheduleFactoryRegistry::default())
+    }
+
+    /// Builds the schedule, ``custom`` schedules are looked up in the supplied registry.
+    pub fn build_with(&self, schedules: &TaskScheduleFactoryRegistry) -> Result<Box<dyn TaskSchedule>, TaskSpecError> {
+        match self {
+            ScheduleSpec::Immediate => Ok(Box::new(TaskScheduleImmediate)),
+            ScheduleSpec::Interval { seconds, anchor } => {
+                let interval = TaskScheduleInterval::from_secs_f64(*seconds)
+                    .map_err(|err| TaskSpecError::InvalidSchedule(Box::new(err)))?;
+
+                Ok(match anchor {
+                    Some(anchor) => Box::new(TaskScheduleInterval::anchored(
+                        interval.into(),
+                        UNIX_EPOCH + Duration::from_secs(*anchor),
+                    )),
+                    None => Box::new(interval),
+                })
+            }
+            ScheduleSpec::Cron { expression } => TaskScheduleCron::from_str(expression)
+                .map(|cron| Box::new(cron) as Box<dyn TaskSchedule>)
+                .map_err(|err| TaskSpecError::InvalidSchedule(Box::new(err))),
+            ScheduleSpec::Custom { factory, params } => schedules.build_schedule(factory, params),
+        }
+    }
+}
+
+/// A factory constructing a user-defined [`TaskSchedule`] from the ``params`` of a ``custom`` [`ScheduleSpec`].
+pub type TaskScheduleFactory =
+    Box<dyn Fn(&SpecParams) -> Result<Box<dyn TaskSchedule>, Box<dyn Error + Send + Sync>> + Send + Sync>;
+
+/// [`TaskScheduleFactoryRegistry`] maps names to [`TaskScheduleFactories`](TaskScheduleFactory), which
+/// ``custom`` [`ScheduleSpecs`](ScheduleSpec) reference via their ``factory``.
+///
+/// # See Also
+/// - [`TaskFrameFactoryRegistry`] - The registry this is usually attached to.
+#[derive(Default)]
+pub struct TaskScheduleFactoryRegistry(HashMap<String, TaskScheduleFactory>);
+
+impl TaskScheduleFactoryRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a schedule factory under ``name``, replacing any previously registered under it.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        factory: impl Fn(&SpecParams) -> Result<Box<dyn TaskSchedule>, Box<dyn Error + Send + Sync>>
+            + Send
+            + Sync
+            + 'static,
+    ) -> &mut Self {
+        self.0.insert(name.into(), Box::new(factory));
+        self
+    }
+
+    /// Whether a schedule factory is registered under ``name``.
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    /// Builds a schedule via the factory registered under ``name``, failing with
+    /// [`TaskSpecError::UnknownScheduleFactory`] if there is none or [`TaskSpecError::InvalidSchedule`]
+    /// if the factory rejects the ``params``.
+    pub fn build_schedule(&self, name: &str, params: &SpecParams) -> Result<Box<dyn TaskSchedule>, TaskSpecError> {
+        let factory = self
+            .0
+            .get(name)
+            .ok_or_else(|| TaskSpecError::UnknownScheduleFactory(name.to_owned()))?;
+
+        factory(params).map_err(TaskSpecError::InvalidSchedule)
+    }
+}
+
+/// [`FrameSpec`] is a single entry of the TaskFrame chain in a [`TaskSpec`], referencing a TaskFrame
+/// factory registered in the [`TaskFrameFactoryRegistry`].
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct FrameSpec {
+    /// The name the factory is registered under.
+    pub factory: String,
+
+    /// The parameters handed to the factory, defaults to empty.
+    #[serde(default)]
+    pub params: SpecParams,
+}
+
+/// [`TaskSpec`] is the declarative definition of a [`Task`], deserialized from any format supported
+/// by ``serde`` and built via [`TaskFrameFactoryRegistry::load`] (see the [module docs](self) for the schema).
+///
+/// # See Also
+/// - [`ScheduleSpec`] - The definition of its schedule.
+/// - [`FrameSpec`] - The definition of each TaskFrame in its chain.
+/// - [`LoadedTask`] - What it is built into.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct TaskSpec {
+    /// The name of the Task.
+    pub name: String,
+
+    /// The schedule of the Task.
+    pub schedule: ScheduleSpec,
+
+    /// The TaskFrame chain, executed sequentially.
+    pub frames: Vec<FrameSpec>,
+
+    /// The [`TaskPriority`] of the Task, defaults to ``0``.
+    #[serde(default)]
+    pub priority: i32,
+
+    /// The number of runs after which the TaskFrame chain no longer executes, unlimited by default.
+    #[serde(default)]
+    pub max_runs: Option<NonZeroUsize>,
+
+    /// The seconds after which the Task is dropped by the Scheduler, unlimited by default.
+    #[serde(default)]
+    pub max_lifetime_secs: Option<u64>,
+
+    /// The tags of the Task, defaults to empty.
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    /// The [`TaskMetadata`] description, defaults to empty.
+    #[serde(default)]
+    pub description: String,
+
+    /// The [`TaskMetadata`] owner, defaults to empty.
+    #[serde(default)]
+    pub owner: String,
+}
+
+/// [`LoadedTask`] is a [`Task`] built from a [`TaskSpec`], carrying along the name and tags of the
+/// definition so they can be used when scheduling it.
+pub struct LoadedTask {
+    /// The name of the definition.
+    pub name: String,
+
+    /// The tags of the definition.
+    pub tags: Vec<String>,
+
+    /// The built Task.
+    pub task: ErasedTask<CollectionTaskError>,
+}
+
+/// A factory constructing a TaskFrame from the ``params`` of a [`FrameSpec`].
+pub type TaskFrameFactory = Box<
+    dyn Fn(&SpecParams) -> Result<Arc<dyn ErasedTaskFrame<()>>, Box<dyn Error + Send + Sync>>
+        + Send
+        + Sync,
+>;
+
+/// [`TaskFrameFactoryRegistry`] maps names to [`TaskFrameFactories`](TaskFrameFactory), which the
+/// [`FrameSpecs`](FrameSpec) of a [`TaskSpec`] reference via their ``factory``. It also holds the
+/// [`TaskScheduleFactoryRegistry`] used for ``custom`` schedules, and builds [`Tasks`](Task) via
+/// [`TaskFrameFactoryRegistry::load`].
+///
+/// # See Also
+/// - [`TaskSpec`] - The definitions it builds.
+/// - [`TaskSpecError`] - The error returned when a definition cannot be built.
+#[derive(Default)]
+pub struct TaskFrameFactoryRegistry {
+    frames: HashMap<String, TaskFrameFactory>,
+    schedules: TaskScheduleFactoryRegistry,
+}
+
+impl TaskFrameFactoryRegistry {
+    /// Creates an empty registry, with an empty schedule registry as well.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the registry ``custom`` schedules are looked up in.
+    pub fn with_schedules(mut self, schedules: TaskScheduleFactoryRegistry) -> Self {
+        self.schedules = schedules;
+        self
+    }
+
+    /// Registers a schedule factory under ``name`` in the attached schedule registry.
+    pub fn register_schedule(
+        &mut self,
+        name: impl Into<String>,
+        factory: impl Fn(&SpecParams) -> Result<Box<dyn TaskSchedule>, Box<dyn Error + Send + Sync>>
+            + Send
+            + Sync
+            + 'static,
+    ) -> &mut Self {
+        self.schedules.register(name, factory);
+        self
+    }
+
+    /// The registry ``custom`` schedules are looked up in.
+    pub fn schedules(&self) -> &TaskScheduleFactoryRegistry {
+        &self.schedules
+    }
+
+    /// Registers a TaskFrame factory under ``name``, replacing any previously registered under it.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        factory: impl Fn(&SpecParams) -> Result<Arc<dyn ErasedTaskFrame<()>>, Box<dyn Error + Send + Sync>>
+            + Send
+            + Sync
+            + 'static,
+    ) -> &mut Self {
+        self.frames.insert(name.into(), Box::new(factory));
+        self
+    }
+
+    /// Whether a TaskFrame factory is registered under ``name``.
+    pub fn contains(&self, name: &str) -> bool {
+        self.frames.contains_key(name)
+    }
+
+    /// Builds a single TaskFrame, failing with [`TaskSpecError::UnknownFactory`] if its factory isn't
+    /// registered or [`TaskSpecError::FactoryFailed`] if the factory rejects the ``params``.
+    pub fn build_frame(&self, spec: &FrameSpec) -> Result<Arc<dyn ErasedTaskFrame<()>>, TaskSpecError> {
+        let factory = self
+            .frames
+            .get(&spec.factory)
+            .ok_or_else(|| TaskSpecError::UnknownFactory(spec.factory.clone()))?;
+
+        factory(&spec.params).map_err(|error| TaskSpecError::FactoryFailed {
+            name: spec.factory.clone(),
+            error,
+        })
+    }
+
+    /// Builds the [`Task`] a [`TaskSpec`] defines, failing with [`TaskSpecError::EmptyFrameChain`] if it
+    /// has no TaskFrames or with the error of whichever TaskFrame or schedule cannot be built.
+    pub fn load(&self, spec: &TaskSpec) -> Result<LoadedTask, TaskSpecError> {
+        if spec.frames.is_empty() {
+            return Err(TaskSpecError::EmptyFrameChain(spec.name.clone()));
+        }
+
+        let frames = spec
+            .frames
+            .iter()
+            .map(|frame| self.build_frame(frame))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let schedule = spec.schedule.build_with(&self.schedules)?;
+        let chain = CollectionTaskFrame::sequential(frames);
+        let priority = TaskPriority(spec.priority);
+        let metadata = TaskMetadata {
+            description: spec.description.clone(),
+            owner: spec.owner.clone(),
+        };
+
+        let task = match spec.max_runs {
+            Some(max_runs) => Task::new(
+                ThresholdTaskFrame::builder()
+                    .frame(chain)
+                    .threshold(max_runs)
+                    .build(),
+                schedule,
+            )
+            .with_priority(priority)
+            .with_metadata(metadata)
+            .into_erased(),
+            None => Task::new(chain, schedule)
+                .with_priority(priority)
+                .with_metadata(metadata)
+                .into_erased(),
+        };
+
+        let task = match spec.max_lifetime_secs {
+            Some(secs) => task.with_max_lifetime(Duration::from_secs(secs)),
+            None => task,
+        };
+
+        Ok(LoadedTask {
+            name: spec.name.clone(),
+            tags: spec.tags.clone(),
+            task,
+        })
+    }
+}
@@ -1,4 +1,6 @@
+pub mod random;
 pub mod timing_wheel;
+pub use random::*;
 pub use timing_wheel::*;
 
 pub(crate) mod macros {
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+/// [`RandomSource`] is the source of randomness used by jittering and sampling logic throughout
+/// ChronoGrapher, it exists so the randomness can be swapped out (e.g. for a seeded deterministic
+/// source in order to make tests reproducible).
+///
+/// # Implementation(s)
+/// - [`ThreadRandomSource`] - The default, it uses the thread-local generator.
+/// - [`SeededRandomSource`] - A deterministic generator constructed from a seed.
+pub trait RandomSource: Send + Sync + 'static {
+    /// Produces a random ``f64`` in the range of ``[0, 1)``.
+    fn f64(&self) -> f64;
+
+    /// Produces a random ``u64`` in the range of ``[0, bound)``, a zero ``bound`` is treated as ``1``.
+    fn u64_below(&self, bound: u64) -> u64 {
+        ((self.f64() * bound as f64) as u64).min(bound.saturating_sub(1))
+    }
+}
+
+impl<R: RandomSource + ?Sized> RandomSource for Arc<R> {
+    fn f64(&self) -> f64 {
+        self.as_ref().f64()
+    }
+
+    fn u64_below(&self, bound: u64) -> u64 {
+        self.as_ref().u64_below(bound)
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+pub struct ThreadRandomSource;
+
+impl RandomSource for ThreadRandomSource {
+    fn f64(&self) -> f64 {
+        fastrand::f64()
+    }
+
+    fn u64_below(&self, bound: u64) -> u64 {
+        fastrand::u64(..bound.max(1))
+    }
+}
+
+pub struct SeededRandomSource(parking_lot::Mutex<fastrand::Rng>);
+
+impl SeededRandomSource {
+    pub fn new(seed: u64) -> Self {
+        Self(parking_lot::Mutex::new(fastrand::Rng::with_seed(seed)))
+    }
+}
+
+impl RandomSource for SeededRandomSource {
+    fn f64(&self) -> f64 {
+        self.0.lock().f64()
+    }
+
+    fn u64_below(&self, bound: u64) -> u64 {
+        self.0.lock().u64(..bound.max(1))
+    }
+}
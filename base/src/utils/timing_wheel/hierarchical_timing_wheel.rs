@@ -28,8 +28,10 @@ impl<T> Default for HierarchicalTimingWheel<T> {
 }
 
 impl<T> HierarchicalTimingWheel<T> {
+    /// Inserts ``value`` to expire after ``delay``, delays shorter than a millisecond (including zero)
+    /// expire on the next tick, as the slot of the current tick has already been read.
     pub fn insert(&mut self, value: T, delay: Duration) {
-        let millis = delay.as_millis();
+        let millis = delay.as_millis().max(1);
         let slots = [
             (millis & 0xFF) as u8,
             ((millis >> 8) & 0xFF) as u8,
@@ -84,9 +86,16 @@ impl<T> HierarchicalTimingWheel<T> {
                 let level = &mut levels[idx];
                 let (expired, wrapped) = level.tick();
                 for mut entry in expired {
-                    entry.level -= 1;
-
-                    let next_level = entry.level as usize;
+                    // With no offset left on the lower levels the entry is due on this very tick
+                    let Some(next_level) = (0..entry.level as usize)
+                        .rev()
+                        .find(|level| entry.precomputed[*level] != 0)
+                    else {
+                        results.push(entry.value);
+                        continue;
+                    };
+
+                    entry.level = next_level as u8;
                     let target = &mut levels[next_level];
 
                     let current = target.current() as u8;
@@ -41,25 +41,55 @@ pub mod prelude {
     pub use crate::macros::*;
 
     // Core
-    pub use crate::errors::TaskError;
+    pub use crate::errors::{ChronographerErrors, TaskError};
     pub use crate::task::{RestrictTaskFrameContext, Task, TaskFrameContext};
 
     // Common frames
+    pub use crate::task::auditframe::AuditTaskFrame;
+    pub use crate::task::blockingframe::BlockingTaskFrame;
+    pub use crate::task::chunkedframe::ChunkedTaskFrame;
+    pub use crate::task::coalesceframe::CoalesceErrorsTaskFrame;
+    pub use crate::task::sheddingframe::AdaptiveSheddingTaskFrame;
     pub use crate::task::collectionframe::CollectionTaskFrame;
+    pub use crate::task::collectionframe::AggregateError;
+    pub use crate::task::collectionframe::GroupedTaskFramesAggregate;
     pub use crate::task::collectionframe::GroupedTaskFramesQuitOnFailure;
     pub use crate::task::collectionframe::GroupedTaskFramesQuitOnSuccess;
     pub use crate::task::collectionframe::GroupedTaskFramesSilent;
+    pub use crate::task::collectionframe::HealthSelectionExecStrategy;
     pub use crate::task::collectionframe::ParallelExecStrategy;
+    pub use crate::task::collectionframe::QuorumExecStrategy;
+    pub use crate::task::collectionframe::QuorumTaskFrame;
     pub use crate::task::collectionframe::SelectFrameAccessor;
+    pub use crate::task::collectionframe::SelectTaskFrame;
     pub use crate::task::collectionframe::SelectionExecStrategy;
     pub use crate::task::collectionframe::SequentialExecStrategy;
+    pub use crate::task::collectionframe::WeightedSelectFrameAccessor;
+    pub use crate::task::deadlineframe::DeadlineTaskFrame;
     pub use crate::task::delayframe::DelayTaskFrame;
     pub use crate::task::dependencyframe::DependencyTaskFrame;
     pub use crate::task::dynamicframe::DynamicTaskFrame;
     pub use crate::task::fallbackframe::FallbackTaskFrame;
+    pub use crate::task::finalizerframe::FinalizerTaskFrame;
+    pub use crate::task::healthgateframe::GateOnHealthTaskFrame;
+    pub use crate::task::keyedframe::KeyedSerializeTaskFrame;
+    pub use crate::task::killswitchframe::KillSwitchTaskFrame;
+    pub use crate::task::killswitchframe::KillSwitches;
+    pub use crate::task::leaderframe::LeaderOnlyTaskFrame;
+    pub use crate::task::orderedframe::OrderedTaskFrame;
+    pub use crate::task::preconditionframe::PreconditionTaskFrame;
+    pub use crate::task::resilienceframe::ResilienceTaskFrame;
     pub use crate::task::retryframe::RetriableTaskFrame;
+    pub use crate::task::rolloutframe::RolloutPercentage;
+    pub use crate::task::rolloutframe::RolloutTaskFrame;
+    pub use crate::task::switchframe::SwitchKey;
+    pub use crate::task::switchframe::SwitchTaskFrame;
+    pub use crate::task::tagbudgetframe::TagBudgetTaskFrame;
+    pub use crate::task::tagbudgetframe::TagBudgets;
     pub use crate::task::thresholdframe::ThresholdTaskFrame;
     pub use crate::task::timeoutframe::TimeoutTaskFrame;
+    pub use crate::task::traceframe::TraceContext;
+    pub use crate::task::traceframe::TraceContextTaskFrame;
 
     // Scheduling / Triggering
     pub use crate::task::schedule::TaskSchedule;
@@ -74,6 +104,7 @@ pub mod prelude {
     pub use crate::scheduler::LiveScheduler;
     pub use crate::scheduler::Scheduler;
     pub use crate::scheduler::SchedulerConfig;
+    pub use crate::scheduler::TaskHandle;
 
     #[cfg(feature = "anyhow")]
     pub use crate::scheduler::DefaultLiveAnyhowScheduler;
@@ -88,6 +119,7 @@ pub mod prelude {
     pub use crate::scheduler::EyreSchedulerConfig;
 
     // TaskHooks / TaskHookEvents
+    pub use crate::define_hook_event;
     pub use crate::task::hooks::{NonObserverTaskHook, TaskHook, events::*};
 
     // Utils / Misc
@@ -95,6 +127,7 @@ pub mod prelude {
     pub use crate::task::dependency::*;
     pub use crate::task::retryframe::{
         ConstantBackoffStrategy, ExponentialBackoffStrategy, JitterBackoffStrategy,
-        LinearBackoffStrategy, RetryBackoffStrategy,
+        LinearBackoffStrategy, RetryBackoffStrategy, SharedRetryBudget, StagedBackoffStrategy,
     };
+    pub use crate::utils::{RandomSource, SeededRandomSource, ThreadRandomSource};
 } // skipcq: RS-D1001
@@ -1,4 +1,5 @@
 #![cfg(test)]
 mod macros;
 mod schedule;
+mod scheduler;
 mod task;
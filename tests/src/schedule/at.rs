@@ -0,0 +1,33 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chronographer::task::{TaskSchedule, TaskScheduleAt};
+
+#[tokio::test]
+async fn test_schedule_at_fires_once() {
+    let at = UNIX_EPOCH + Duration::from_secs(1_000);
+    let instance = TaskScheduleAt::new(at);
+
+    assert_eq!(instance.schedule(UNIX_EPOCH).await.unwrap(), at);
+    assert!(instance.schedule(at).await.is_err());
+    assert!(instance.explain(at).await.is_err());
+}
+
+#[tokio::test]
+async fn test_schedule_at_in_the_past() {
+    let at = UNIX_EPOCH + Duration::from_secs(1_000);
+    let instance = TaskScheduleAt::new(at);
+
+    assert_eq!(instance.schedule(SystemTime::now()).await.unwrap(), at);
+}
+
+#[tokio::test]
+async fn test_schedule_at_occurrences() {
+    let at = UNIX_EPOCH + Duration::from_secs(1_000);
+    let instance = TaskScheduleAt::new(at);
+
+    let within = instance.occurrences(UNIX_EPOCH, at).await.unwrap();
+    assert_eq!(within, vec![at]);
+
+    let after = instance.occurrences(at, at + Duration::from_secs(60)).await.unwrap();
+    assert!(after.is_empty());
+}
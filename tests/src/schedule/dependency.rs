@@ -0,0 +1,62 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, UNIX_EPOCH};
+
+use chronographer::task::dependency::FrameDependency;
+use chronographer::task::{TaskSchedule, TaskScheduleDependency};
+
+fn flag_dependency(flag: &Arc<AtomicBool>, polls: &Arc<AtomicUsize>) -> FrameDependency {
+    let flag = flag.clone();
+    let polls = polls.clone();
+    FrameDependency::external(move || {
+        let flag = flag.clone();
+        let polls = polls.clone();
+        async move {
+            polls.fetch_add(1, Ordering::SeqCst);
+            flag.load(Ordering::SeqCst)
+        }
+    })
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_resolved_schedules_now() {
+    let flag = Arc::new(AtomicBool::new(true));
+    let polls = Arc::new(AtomicUsize::new(0));
+    let instance = TaskScheduleDependency::new(flag_dependency(&flag, &polls), Duration::from_secs(5));
+
+    let now = UNIX_EPOCH + Duration::from_secs(100);
+    assert_eq!(instance.schedule(now).await.unwrap(), now);
+    assert_eq!(polls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_unresolved_polls_until_resolved() {
+    let flag = Arc::new(AtomicBool::new(false));
+    let polls = Arc::new(AtomicUsize::new(0));
+    let instance = Arc::new(TaskScheduleDependency::new(flag_dependency(&flag, &polls), Duration::from_secs(5)));
+
+    let now = UNIX_EPOCH + Duration::from_secs(100);
+    let handle = tokio::spawn({
+        let instance = instance.clone();
+        async move { instance.schedule(now).await.unwrap() }
+    });
+
+    tokio::time::sleep(Duration::from_secs(12)).await;
+    assert!(!handle.is_finished());
+    flag.store(true, Ordering::SeqCst);
+
+    let resolved = handle.await.unwrap();
+    assert_eq!(resolved, now + Duration::from_secs(15));
+    assert_eq!(polls.load(Ordering::SeqCst), 4);
+}
+
+#[tokio::test]
+async fn test_no_occurrences() {
+    let flag = Arc::new(AtomicBool::new(false));
+    let polls = Arc::new(AtomicUsize::new(0));
+    let instance = TaskScheduleDependency::new(flag_dependency(&flag, &polls), Duration::from_secs(5));
+
+    let missed = instance.occurrences(UNIX_EPOCH, UNIX_EPOCH + Duration::from_secs(3600)).await.unwrap();
+    assert!(missed.is_empty());
+    assert_eq!(polls.load(Ordering::SeqCst), 0);
+}
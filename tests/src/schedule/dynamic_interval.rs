@@ -0,0 +1,53 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, UNIX_EPOCH};
+
+use chronographer::task::{TaskSchedule, TaskScheduleDynamicInterval};
+
+const NO_VALUE: u64 = u64::MAX;
+
+fn knob_schedule(knob: &Arc<AtomicU64>) -> TaskScheduleDynamicInterval {
+    let knob = knob.clone();
+    TaskScheduleDynamicInterval::new(Duration::from_secs(60), move || {
+        match knob.load(Ordering::Relaxed) {
+            NO_VALUE => None,
+            secs => Some(Duration::from_secs(secs)),
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_follows_the_source() {
+    let knob = Arc::new(AtomicU64::new(10));
+    let instance = knob_schedule(&knob);
+    let now = UNIX_EPOCH + Duration::from_secs(100);
+
+    assert_eq!(instance.schedule(now).await.unwrap(), now + Duration::from_secs(10));
+
+    knob.store(30, Ordering::Relaxed);
+    assert_eq!(instance.schedule(now).await.unwrap(), now + Duration::from_secs(30));
+}
+
+#[tokio::test]
+async fn test_falls_back_on_missing_or_invalid_values() {
+    let knob = Arc::new(AtomicU64::new(NO_VALUE));
+    let instance = knob_schedule(&knob);
+    let now = UNIX_EPOCH + Duration::from_secs(100);
+
+    assert_eq!(instance.schedule(now).await.unwrap(), now + Duration::from_secs(60));
+
+    knob.store(0, Ordering::Relaxed);
+    assert_eq!(instance.interval(), instance.fallback());
+    assert_eq!(instance.schedule(now).await.unwrap(), now + Duration::from_secs(60));
+}
+
+#[tokio::test]
+async fn test_never_enumerates_occurrences() {
+    let instance = knob_schedule(&Arc::new(AtomicU64::new(1)));
+    let occurrences = instance
+        .occurrences(UNIX_EPOCH, UNIX_EPOCH + Duration::from_secs(100))
+        .await
+        .unwrap();
+
+    assert!(occurrences.is_empty());
+}
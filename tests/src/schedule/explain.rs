@@ -0,0 +1,83 @@
+use std::str::FromStr;
+use std::time::{Duration, UNIX_EPOCH};
+
+use chronographer::task::{
+    TaskSchedule, TaskScheduleCron, TaskScheduleImmediate, TaskScheduleInterval, TaskScheduleRandomWindow,
+    TaskScheduleStartup,
+};
+use chronographer::utils::SeededRandomSource;
+
+#[tokio::test]
+async fn test_explain_matches_schedule() {
+    let now = UNIX_EPOCH + Duration::from_secs(1_906_538_400);
+    let schedules: Vec<Box<dyn TaskSchedule>> = vec![
+        Box::new(TaskScheduleImmediate),
+        Box::new(TaskScheduleInterval::from_secs(30)),
+        Box::new(TaskScheduleCron::from_str("0 30 9 * * ?").unwrap()),
+    ];
+
+    for schedule in schedules {
+        let (next, reason) = schedule.explain(now).await.unwrap();
+        assert_eq!(next, schedule.schedule(now).await.unwrap());
+        assert!(!reason.is_empty());
+    }
+}
+
+#[tokio::test]
+async fn test_default_explain_states_the_times() {
+    let now = UNIX_EPOCH + Duration::from_secs(1_906_538_400);
+    let (_, reason) = TaskScheduleImmediate.explain(now).await.unwrap();
+
+    assert!(reason.starts_with("next fire after 2030-06-01"), "got {reason}");
+}
+
+#[tokio::test]
+async fn test_cron_explain_names_the_expression() {
+    // 2030-06-01 10:00:00 UTC
+    let now = UNIX_EPOCH + Duration::from_secs(1_906_538_400);
+    let cron = TaskScheduleCron::from_str("0 30 9 * * ?").unwrap();
+    let (next, reason) = cron.explain(now).await.unwrap();
+
+    assert!(reason.contains(&format!("`{cron}`")), "got {reason}");
+    assert!(reason.contains("2030-06-02"), "got {reason}");
+    assert_eq!(next, cron.schedule(now).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_interval_explain_describes_alignment() {
+    let now = UNIX_EPOCH + Duration::from_secs(13);
+
+    let (_, relative) = TaskScheduleInterval::from_secs(10).explain(now).await.unwrap();
+    assert!(relative.starts_with("10s after"), "got {relative}");
+
+    let anchored = TaskScheduleInterval::anchored(Duration::from_secs(10), UNIX_EPOCH);
+    let (next, reason) = anchored.explain(now).await.unwrap();
+    assert_eq!(next, UNIX_EPOCH + Duration::from_secs(20));
+    assert!(reason.contains("counted from 1970-01-01"), "got {reason}");
+}
+
+#[tokio::test]
+async fn test_explain_leaves_stateful_schedules_untouched() {
+    let now = UNIX_EPOCH + Duration::from_secs(1_906_538_400);
+
+    let startup = TaskScheduleStartup::new(TaskScheduleInterval::from_secs(30));
+    let (next, _) = startup.explain(now).await.unwrap();
+    assert_eq!(next, now);
+    assert_eq!(startup.schedule(now).await.unwrap(), now);
+
+    let window = || {
+        TaskScheduleRandomWindow::new(Duration::ZERO, Duration::from_secs(6 * 3600))
+            .unwrap()
+            .with_random_source(SeededRandomSource::new(7))
+    };
+
+    // 2030-06-01 02:00:00 UTC, inside the window
+    let now = UNIX_EPOCH + Duration::from_secs(1_906_509_600);
+    let explained = window();
+    let (earliest, reason) = explained.explain(now).await.unwrap();
+    let picked = explained.schedule(now).await.unwrap();
+
+    assert_eq!(earliest, now);
+    assert!(reason.contains("picked at random"), "got {reason}");
+    assert_eq!(picked, window().schedule(now).await.unwrap());
+}
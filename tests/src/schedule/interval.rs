@@ -0,0 +1,172 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use chronographer::errors::OccurrenceRangeInverted;
+use chronographer::task::{MAX_OCCURRENCES, TaskSchedule, TaskScheduleCron, TaskScheduleInterval};
+use std::str::FromStr;
+
+#[tokio::test]
+async fn test_anchored_between_grid_points() {
+    let instance = TaskScheduleInterval::anchored(Duration::from_secs(10), UNIX_EPOCH);
+    let resolve = instance.schedule(UNIX_EPOCH + Duration::from_secs(13)).await.unwrap();
+
+    assert_eq!(resolve, UNIX_EPOCH + Duration::from_secs(20));
+}
+
+#[tokio::test]
+async fn test_anchored_on_grid_point() {
+    let instance = TaskScheduleInterval::anchored(Duration::from_secs(10), UNIX_EPOCH);
+    let resolve = instance.schedule(UNIX_EPOCH + Duration::from_secs(20)).await.unwrap();
+
+    assert_eq!(resolve, UNIX_EPOCH + Duration::from_secs(30));
+}
+
+#[tokio::test]
+async fn test_anchored_epoch_in_future() {
+    let epoch = UNIX_EPOCH + Duration::from_secs(100);
+    let instance = TaskScheduleInterval::anchored(Duration::from_secs(10), epoch);
+
+    let between = instance.schedule(UNIX_EPOCH + Duration::from_secs(73)).await.unwrap();
+    let on_grid = instance.schedule(UNIX_EPOCH + Duration::from_secs(70)).await.unwrap();
+
+    assert_eq!(between, UNIX_EPOCH + Duration::from_secs(80));
+    assert_eq!(on_grid, UNIX_EPOCH + Duration::from_secs(80));
+}
+
+#[tokio::test]
+async fn test_anchored_shares_grid() {
+    let first = TaskScheduleInterval::anchored(Duration::from_secs(7), UNIX_EPOCH);
+    let second = TaskScheduleInterval::anchored(Duration::from_secs(7), UNIX_EPOCH);
+
+    let a = first.schedule(UNIX_EPOCH + Duration::from_secs(15)).await.unwrap();
+    let b = second.schedule(UNIX_EPOCH + Duration::from_millis(19_500)).await.unwrap();
+
+    assert_eq!(a, UNIX_EPOCH + Duration::from_secs(21));
+    assert_eq!(a, b);
+}
+
+#[tokio::test]
+async fn test_occurrences_relative() {
+    let instance = TaskScheduleInterval::from_secs(10);
+    let occurrences = instance
+        .occurrences(UNIX_EPOCH + Duration::from_secs(3), UNIX_EPOCH + Duration::from_secs(33))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        occurrences,
+        vec![
+            UNIX_EPOCH + Duration::from_secs(13),
+            UNIX_EPOCH + Duration::from_secs(23),
+            UNIX_EPOCH + Duration::from_secs(33),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_occurrences_anchored() {
+    let instance = TaskScheduleInterval::anchored(Duration::from_secs(10), UNIX_EPOCH);
+    let occurrences = instance
+        .occurrences(UNIX_EPOCH + Duration::from_secs(3), UNIX_EPOCH + Duration::from_secs(35))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        occurrences,
+        vec![
+            UNIX_EPOCH + Duration::from_secs(10),
+            UNIX_EPOCH + Duration::from_secs(20),
+            UNIX_EPOCH + Duration::from_secs(30),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_occurrences_none_missed() {
+    let instance = TaskScheduleInterval::from_secs(10);
+    let occurrences = instance
+        .occurrences(UNIX_EPOCH, UNIX_EPOCH + Duration::from_secs(9))
+        .await
+        .unwrap();
+
+    assert!(occurrences.is_empty());
+}
+
+#[tokio::test]
+async fn test_occurrences_inverted_range() {
+    let instance = TaskScheduleInterval::from_secs(10);
+    let error = instance
+        .occurrences(UNIX_EPOCH + Duration::from_secs(30), UNIX_EPOCH)
+        .await
+        .unwrap_err();
+
+    assert!(error.downcast_ref::<OccurrenceRangeInverted>().is_some());
+}
+
+#[tokio::test]
+async fn test_occurrences_capped() {
+    let instance = TaskScheduleInterval::from_secs(1);
+    let to = UNIX_EPOCH + Duration::from_secs(MAX_OCCURRENCES as u64 * 3);
+    let occurrences = instance.occurrences(UNIX_EPOCH, to).await.unwrap();
+
+    assert_eq!(occurrences.len(), MAX_OCCURRENCES);
+    assert_eq!(occurrences.last(), Some(&(UNIX_EPOCH + Duration::from_secs(MAX_OCCURRENCES as u64))));
+
+    // Paging from the last enumerated fire time continues where the cap stopped
+    let next = instance.occurrences(*occurrences.last().unwrap(), to).await.unwrap();
+    assert_eq!(next.first(), Some(&(UNIX_EPOCH + Duration::from_secs(MAX_OCCURRENCES as u64 + 1))));
+}
+
+#[tokio::test]
+async fn test_cron_occurrences_capped() {
+    let instance = TaskScheduleCron::from_str("* * * * * ?").unwrap();
+    let to = UNIX_EPOCH + Duration::from_secs(MAX_OCCURRENCES as u64 * 2);
+    let occurrences = instance.occurrences(UNIX_EPOCH, to).await.unwrap();
+
+    assert_eq!(occurrences.len(), MAX_OCCURRENCES);
+    assert!(instance.occurrences(to, UNIX_EPOCH).await.is_err());
+}
+
+const DAY_SECS: u64 = 86_400;
+
+#[tokio::test]
+async fn test_aligned_quarter_hour_across_day_boundary() {
+    let instance = TaskScheduleInterval::aligned(Duration::from_secs(15 * 60));
+
+    // 23:52:10 on the first day
+    let before_midnight = UNIX_EPOCH + Duration::from_secs(DAY_SECS - 7 * 60 - 50);
+    let midnight = instance.schedule(before_midnight).await.unwrap();
+    assert_eq!(midnight, UNIX_EPOCH + Duration::from_secs(DAY_SECS));
+
+    let after_midnight = instance.schedule(midnight).await.unwrap();
+    assert_eq!(after_midnight, UNIX_EPOCH + Duration::from_secs(DAY_SECS + 15 * 60));
+
+    // 00:20:00 on the second day
+    let between = instance.schedule(UNIX_EPOCH + Duration::from_secs(DAY_SECS + 20 * 60)).await.unwrap();
+    assert_eq!(between, UNIX_EPOCH + Duration::from_secs(DAY_SECS + 30 * 60));
+}
+
+#[tokio::test]
+async fn test_aligned_hourly_across_day_boundary() {
+    let instance = TaskScheduleInterval::aligned(Duration::from_secs(3600));
+
+    // 23:00:00 on the first day
+    let on_boundary = instance.schedule(UNIX_EPOCH + Duration::from_secs(DAY_SECS - 3600)).await.unwrap();
+    assert_eq!(on_boundary, UNIX_EPOCH + Duration::from_secs(DAY_SECS));
+
+    // 23:59:59 on the first day
+    let last_second = instance.schedule(UNIX_EPOCH + Duration::from_secs(DAY_SECS - 1)).await.unwrap();
+    assert_eq!(last_second, UNIX_EPOCH + Duration::from_secs(DAY_SECS));
+
+    // 00:30:00 on the second day
+    let next_day = instance.schedule(UNIX_EPOCH + Duration::from_secs(DAY_SECS + 1800)).await.unwrap();
+    assert_eq!(next_day, UNIX_EPOCH + Duration::from_secs(DAY_SECS + 3600));
+}
+
+#[tokio::test]
+async fn test_aligned_uneven_interval_restarts_at_midnight() {
+    let instance = TaskScheduleInterval::aligned(Duration::from_secs(7 * 3600));
+
+    // 22:00:00 on the first day, the next step (28:00) is cut off by midnight
+    let resolve = instance.schedule(UNIX_EPOCH + Duration::from_secs(22 * 3600)).await.unwrap();
+    assert_eq!(resolve, UNIX_EPOCH + Duration::from_secs(DAY_SECS));
+}
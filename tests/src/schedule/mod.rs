@@ -1,2 +1,15 @@
 mod virtual_clock_test;
-mod immediate;
\ No newline at end of file
+mod replay_clock_test;
+mod progressive_clock_test;
+mod dependency;
+mod immediate;
+mod interval;
+mod persistence;
+mod random_window;
+mod startup;
+mod explain;
+mod solar;
+mod dynamic_interval;
+mod outcome;
+mod zoned_cron;
+mod at;
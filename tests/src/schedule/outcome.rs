@@ -0,0 +1,63 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, UNIX_EPOCH};
+
+use chronographer::prelude::*;
+use chronographer::task::hooks::events::OnTaskEnd;
+use chronographer::task::{RunOutcome, TaskSchedule, TaskScheduleInterval, TaskScheduleOutcome};
+
+fn instance() -> TaskScheduleOutcome<TaskScheduleInterval, TaskScheduleInterval> {
+    TaskScheduleOutcome::new(TaskScheduleInterval::from_secs(3600), TaskScheduleInterval::from_secs(60))
+}
+
+#[tokio::test]
+async fn test_success_schedule_until_an_outcome_is_known() {
+    let instance = instance();
+
+    assert_eq!(instance.outcome().get(), None);
+    assert_eq!(instance.schedule(UNIX_EPOCH).await.unwrap(), UNIX_EPOCH + Duration::from_secs(3600));
+}
+
+#[tokio::test]
+async fn test_switches_schedule_on_recorded_outcome() {
+    let instance = instance();
+    let outcome = instance.outcome();
+
+    outcome.set(RunOutcome::Failure);
+    assert_eq!(instance.schedule(UNIX_EPOCH).await.unwrap(), UNIX_EPOCH + Duration::from_secs(60));
+    let (_, reason) = instance.explain(UNIX_EPOCH).await.unwrap();
+    assert!(reason.ends_with("(following the failure schedule)"), "{reason}");
+
+    outcome.set(RunOutcome::Success);
+    assert_eq!(instance.schedule(UNIX_EPOCH).await.unwrap(), UNIX_EPOCH + Duration::from_secs(3600));
+
+    outcome.clear();
+    assert_eq!(outcome.get(), None);
+}
+
+#[tokio::test]
+async fn test_hook_records_task_outcome() {
+    let instance = instance();
+    let outcome = instance.outcome();
+    let fail = Arc::new(AtomicBool::new(true));
+
+    let flag = fail.clone();
+    let task = Task::new(
+        DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+            let fail = flag.load(Ordering::SeqCst);
+            async move { if fail { Err("poll failed".to_string()) } else { Ok(()) } }
+        }),
+        instance,
+    )
+    .into_erased();
+    task.attach_hook::<OnTaskEnd>(outcome.clone()).await;
+
+    let _ = task.run().await;
+    assert_eq!(outcome.get(), Some(RunOutcome::Failure));
+    assert_eq!(task.schedule().schedule(UNIX_EPOCH).await.unwrap(), UNIX_EPOCH + Duration::from_secs(60));
+
+    fail.store(false, Ordering::SeqCst);
+    task.run().await.unwrap();
+    assert_eq!(outcome.get(), Some(RunOutcome::Success));
+    assert_eq!(task.schedule().schedule(UNIX_EPOCH).await.unwrap(), UNIX_EPOCH + Duration::from_secs(3600));
+}
@@ -0,0 +1,71 @@
+use std::str::FromStr;
+use std::time::{Duration, UNIX_EPOCH};
+
+use chronographer::task::{TaskScheduleCron, TaskScheduleInterval};
+
+fn round_trip_cron(expression: &str) -> TaskScheduleCron {
+    let cron = TaskScheduleCron::from_str(expression).unwrap();
+    let value = toml::Value::try_from(&cron).unwrap();
+    let restored: TaskScheduleCron = value.try_into().unwrap();
+
+    assert_eq!(restored, cron, "{expression} did not round-trip");
+    restored
+}
+
+fn round_trip_interval(interval: TaskScheduleInterval) {
+    let value = toml::Value::try_from(interval).unwrap();
+    let restored: TaskScheduleInterval = value.try_into().unwrap();
+
+    assert_eq!(restored, interval);
+}
+
+#[test]
+fn test_cron_serializes_as_expression() {
+    let cron = TaskScheduleCron::from_str("0 15 10 ? * 6L").unwrap();
+    let value = toml::Value::try_from(&cron).unwrap();
+
+    assert_eq!(value.as_str(), Some("0 15 10 ? * 6L"));
+}
+
+#[test]
+fn test_cron_round_trip() {
+    round_trip_cron("* * * * * *");
+    round_trip_cron("0 0 12 * * ?");
+    round_trip_cron("0 0/5 14 * * ?");
+    round_trip_cron("0 15 10 ? * 2-6");
+    round_trip_cron("0 0,15,30,45 * * * ?");
+    round_trip_cron("0 0 12 L-3 * ?");
+    round_trip_cron("0 0 12 LW * ?");
+    round_trip_cron("0 0 12 15W * ?");
+    round_trip_cron("0 0 12 ? * 6#3");
+}
+
+#[test]
+fn test_cron_rejects_invalid_expression() {
+    let value = toml::Value::String("61 * * * * *".to_owned());
+    assert!(value.try_into::<TaskScheduleCron>().is_err());
+}
+
+#[test]
+fn test_interval_round_trip() {
+    round_trip_interval(TaskScheduleInterval::from_secs(30));
+    round_trip_interval(TaskScheduleInterval::duration(Duration::from_millis(1500)));
+    round_trip_interval(TaskScheduleInterval::anchored(
+        Duration::from_secs(10),
+        UNIX_EPOCH + Duration::from_secs(7),
+    ));
+    round_trip_interval(TaskScheduleInterval::aligned(Duration::from_secs(900)));
+}
+
+#[test]
+fn test_interval_persists_as_integer_nanoseconds() {
+    let interval = TaskScheduleInterval::from_secs_f64(0.1).unwrap();
+    let value = toml::Value::try_from(interval).unwrap();
+
+    assert_eq!(value.get("interval_nanos").and_then(|nanos| nanos.as_integer()), Some(100_000_000));
+
+    let encoded = toml::to_string(&interval).unwrap();
+    let restored: TaskScheduleInterval = toml::from_str(&encoded).unwrap();
+    assert_eq!(Duration::from(restored), Duration::from(interval), "the interval should restore bit-identically");
+    assert_eq!(restored, interval);
+}
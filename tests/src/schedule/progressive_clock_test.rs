@@ -0,0 +1,30 @@
+use chronographer::scheduler::clock::{DEFAULT_CLOCK_RESOLUTION, ProgressiveClock, SchedulerClock};
+use std::time::Duration;
+use tokio::time::Instant;
+
+#[tokio::test(start_paused = true)]
+async fn test_default_resolution() {
+    let clock = ProgressiveClock::default();
+    assert_eq!(clock.resolution(), DEFAULT_CLOCK_RESOLUTION);
+}
+
+#[tokio::test]
+#[should_panic]
+async fn test_sub_millisecond_resolution_panics() {
+    ProgressiveClock::with_resolution(Duration::from_micros(500));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_coarse_resolution_catches_up_on_elapsed_millis() {
+    let clock = ProgressiveClock::with_resolution(Duration::from_millis(10));
+    let start = Instant::now();
+
+    for _ in 0..20 {
+        clock.tick().await;
+    }
+
+    // Every wakeup hands out the 10 elapsed milliseconds at once, the 20th tick lands on the second wakeup
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_millis(20), "ticked ahead of time ({elapsed:?})");
+    assert!(elapsed < Duration::from_millis(30), "ticks weren't batched ({elapsed:?})");
+}
@@ -0,0 +1,60 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use chronographer::errors::RandomWindowOutOfRange;
+use chronographer::task::{TaskSchedule, TaskScheduleRandomWindow};
+use chronographer::utils::SeededRandomSource;
+
+const HOUR: Duration = Duration::from_secs(3600);
+const DAY: Duration = Duration::from_secs(86_400);
+
+fn window() -> TaskScheduleRandomWindow {
+    TaskScheduleRandomWindow::new(Duration::ZERO, HOUR * 6)
+        .unwrap()
+        .with_random_source(SeededRandomSource::new(7))
+}
+
+#[test]
+fn test_invalid_window() {
+    assert_eq!(TaskScheduleRandomWindow::new(HOUR * 6, HOUR).err(), Some(RandomWindowOutOfRange));
+    assert_eq!(TaskScheduleRandomWindow::new(HOUR, DAY + HOUR).err(), Some(RandomWindowOutOfRange));
+}
+
+#[tokio::test]
+async fn test_picks_remaining_window_today() {
+    let instance = window();
+    let now = UNIX_EPOCH + HOUR * 4;
+    let resolve = instance.schedule(now).await.unwrap();
+
+    assert!(resolve >= now);
+    assert!(resolve < UNIX_EPOCH + HOUR * 6);
+}
+
+#[tokio::test]
+async fn test_picks_tomorrow_after_window() {
+    let instance = window();
+    let resolve = instance.schedule(UNIX_EPOCH + HOUR * 12).await.unwrap();
+
+    assert!(resolve >= UNIX_EPOCH + DAY);
+    assert!(resolve < UNIX_EPOCH + DAY + HOUR * 6);
+}
+
+#[tokio::test]
+async fn test_successive_results_strictly_increase() {
+    let instance = window();
+    let mut now = UNIX_EPOCH + HOUR;
+    let mut previous = None;
+
+    for _ in 0..32 {
+        let resolve = instance.schedule(now).await.unwrap();
+        assert!(resolve >= now, "{resolve:?} lies in the past of {now:?}");
+        if let Some(previous) = previous {
+            assert!(resolve > previous, "{resolve:?} isn't after {previous:?}");
+        }
+
+        let elapsed = resolve.duration_since(UNIX_EPOCH).unwrap().as_secs() % DAY.as_secs();
+        assert!(elapsed < (HOUR * 6).as_secs());
+
+        previous = Some(resolve);
+        now = resolve;
+    }
+}
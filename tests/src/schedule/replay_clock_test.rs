@@ -0,0 +1,48 @@
+use chronographer::scheduler::clock::{ReplayClock, SchedulerClock};
+use std::time::{Duration, UNIX_EPOCH};
+
+fn secs(n: u64) -> std::time::SystemTime {
+    UNIX_EPOCH + Duration::from_secs(n)
+}
+
+#[tokio::test]
+async fn test_now_advances_through_sequence() {
+    let clock = ReplayClock::new(vec![secs(1), secs(2), secs(3)]);
+    assert_eq!(clock.now(), secs(1));
+    assert_eq!(clock.now(), secs(2));
+    assert_eq!(clock.now(), secs(3));
+    assert!(clock.is_exhausted());
+    assert_eq!(clock.now(), secs(3));
+}
+
+#[tokio::test]
+async fn test_idle_to_consumes_until_target() {
+    let clock = ReplayClock::new(vec![secs(0), secs(5), secs(10), secs(15)]);
+    clock.idle_to(secs(7)).await;
+    assert_eq!(clock.now(), secs(10));
+    assert_eq!(clock.now(), secs(15));
+}
+
+#[tokio::test]
+async fn test_idle_to_jumps_when_exhausted() {
+    let clock = ReplayClock::new(vec![secs(0), secs(1)]);
+    clock.idle_to(secs(60)).await;
+    assert!(clock.is_exhausted());
+    assert_eq!(clock.now(), secs(60));
+}
+
+#[tokio::test]
+async fn test_out_of_order_entries_are_clamped() {
+    let clock = ReplayClock::new(vec![secs(10), secs(4), secs(12)]);
+    assert_eq!(clock.now(), secs(10));
+    assert_eq!(clock.now(), secs(10));
+    assert_eq!(clock.now(), secs(12));
+}
+
+#[tokio::test]
+async fn test_tick_pends_when_exhausted() {
+    let clock = ReplayClock::new(vec![secs(0), secs(1)]);
+    clock.tick().await;
+    let pending = tokio::time::timeout(Duration::from_millis(20), clock.tick()).await;
+    assert!(pending.is_err());
+}
@@ -0,0 +1,101 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chronographer::errors::SolarCoordinatesOutOfRange;
+use chronographer::task::{SolarEvent, TaskSchedule, TaskScheduleSolar};
+
+const MINUTE: Duration = Duration::from_secs(60);
+const HOUR: Duration = Duration::from_secs(3600);
+const DAY: Duration = Duration::from_secs(86_400);
+
+// 2030-06-21 00:00:00 UTC
+const SOLSTICE: Duration = Duration::from_secs(1_908_230_400);
+
+fn london(event: SolarEvent) -> TaskScheduleSolar {
+    TaskScheduleSolar::new(51.5074, -0.1278, event).unwrap()
+}
+
+fn assert_near(actual: SystemTime, expected: SystemTime) {
+    let drift = actual
+        .duration_since(expected)
+        .unwrap_or_else(|err| err.duration());
+
+    assert!(drift <= MINUTE * 3, "{actual:?} isn't within 3 minutes of {expected:?}");
+}
+
+#[test]
+fn test_invalid_coordinates() {
+    assert_eq!(
+        TaskScheduleSolar::new(91.0, 0.0, SolarEvent::Sunrise).err(),
+        Some(SolarCoordinatesOutOfRange)
+    );
+    assert_eq!(
+        TaskScheduleSolar::new(0.0, -181.0, SolarEvent::Sunset).err(),
+        Some(SolarCoordinatesOutOfRange)
+    );
+    assert_eq!(
+        TaskScheduleSolar::new(f64::NAN, 0.0, SolarEvent::Sunset).err(),
+        Some(SolarCoordinatesOutOfRange)
+    );
+}
+
+#[tokio::test]
+async fn test_sunrise_and_sunset() {
+    let now = UNIX_EPOCH + SOLSTICE;
+
+    let sunrise = london(SolarEvent::Sunrise).schedule(now).await.unwrap();
+    assert_near(sunrise, now + HOUR * 3 + MINUTE * 43);
+
+    let sunset = london(SolarEvent::Sunset).schedule(now).await.unwrap();
+    assert_near(sunset, now + HOUR * 20 + MINUTE * 21);
+}
+
+#[tokio::test]
+async fn test_offsets() {
+    let now = UNIX_EPOCH + SOLSTICE;
+
+    let after = london(SolarEvent::Sunset).after(MINUTE * 30).schedule(now).await.unwrap();
+    assert_near(after, now + HOUR * 20 + MINUTE * 51);
+
+    let before = london(SolarEvent::Sunrise).before(HOUR).schedule(now).await.unwrap();
+    assert_near(before, now + HOUR * 2 + MINUTE * 43);
+}
+
+#[tokio::test]
+async fn test_moves_to_the_next_day_once_passed() {
+    let instance = london(SolarEvent::Sunrise);
+    let now = UNIX_EPOCH + SOLSTICE + HOUR * 12;
+
+    let next = instance.schedule(now).await.unwrap();
+    assert!(next > UNIX_EPOCH + SOLSTICE + DAY);
+    assert!(next < UNIX_EPOCH + SOLSTICE + DAY + HOUR * 4);
+}
+
+#[tokio::test]
+async fn test_event_on_the_next_utc_day() {
+    // New York sets after midnight UTC during the summer
+    let instance = TaskScheduleSolar::new(40.7128, -74.006, SolarEvent::Sunset).unwrap();
+    let now = UNIX_EPOCH + SOLSTICE;
+
+    let sunset = instance.schedule(now).await.unwrap();
+    assert_near(sunset, now + MINUTE * 30);
+}
+
+#[tokio::test]
+async fn test_skips_polar_day() {
+    // The sun doesn't set in Tromsø until late July
+    let instance = TaskScheduleSolar::new(69.65, 18.96, SolarEvent::Sunset).unwrap();
+    let now = UNIX_EPOCH + SOLSTICE;
+
+    let sunset = instance.schedule(now).await.unwrap();
+    assert!(sunset > now + DAY * 25);
+    assert!(sunset < now + DAY * 45);
+}
+
+#[tokio::test]
+async fn test_explain() {
+    let instance = london(SolarEvent::Sunset).after(MINUTE * 30);
+    let (next, reason) = instance.explain(UNIX_EPOCH + SOLSTICE).await.unwrap();
+
+    assert_eq!(next, instance.schedule(UNIX_EPOCH + SOLSTICE).await.unwrap());
+    assert!(reason.starts_with("next 1800s after sunset at (51.5074, -0.1278) after 2030-06-21"));
+}
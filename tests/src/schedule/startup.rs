@@ -0,0 +1,26 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use chronographer::task::{TaskSchedule, TaskScheduleInterval, TaskScheduleStartup};
+
+#[tokio::test]
+async fn test_first_call_is_immediate() {
+    let instance = TaskScheduleStartup::new(TaskScheduleInterval::from_secs(60));
+    let now = UNIX_EPOCH + Duration::from_secs(5);
+
+    assert!(!instance.has_started());
+    assert_eq!(instance.schedule(now).await.unwrap(), now);
+    assert!(instance.has_started());
+}
+
+#[tokio::test]
+async fn test_delegates_after_first_call() {
+    let instance = TaskScheduleStartup::new(TaskScheduleInterval::from_secs(60));
+    let now = UNIX_EPOCH + Duration::from_secs(5);
+
+    instance.schedule(now).await.unwrap();
+
+    for _ in 0..3 {
+        let resolve = instance.schedule(now).await.unwrap();
+        assert_eq!(resolve, now + Duration::from_secs(60));
+    }
+}
@@ -0,0 +1,114 @@
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chronographer::task::{
+    CronTimeZone, DstTransition, DstZone, FixedOffsetZone, TaskSchedule, TaskScheduleCron, TaskScheduleZonedCron,
+};
+
+const HOUR: i32 = 3600;
+
+fn at(secs: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(secs)
+}
+
+// US Eastern, in 2026 DST starts on March 8th and ends on November 1st
+fn eastern() -> DstZone {
+    DstZone::new(
+        -5 * HOUR,
+        -4 * HOUR,
+        DstTransition::new(3, 2, 0, Duration::from_secs(2 * 3600)),
+        DstTransition::new(11, 1, 0, Duration::from_secs(2 * 3600)),
+    )
+}
+
+fn eastern_cron(expression: &str) -> TaskScheduleZonedCron<DstZone> {
+    TaskScheduleZonedCron::new(TaskScheduleCron::from_str(expression).unwrap(), eastern())
+}
+
+#[test]
+fn test_dst_zone_offsets() {
+    let zone = eastern();
+    assert_eq!(zone.offset_secs(at(1_772_953_199)), -5 * HOUR); // 2026-03-08 01:59:59 EST
+    assert_eq!(zone.offset_secs(at(1_772_953_200)), -4 * HOUR); // 2026-03-08 03:00:00 EDT
+    assert_eq!(zone.offset_secs(at(1_793_512_799)), -4 * HOUR); // 2026-11-01 01:59:59 EDT
+    assert_eq!(zone.offset_secs(at(1_793_512_800)), -5 * HOUR); // 2026-11-01 01:00:00 EST
+
+    // Central European, the last Sunday of March / October, transitioning at 01:00 UTC
+    let central = DstZone::new(
+        HOUR,
+        2 * HOUR,
+        DstTransition::new(3, 5, 0, Duration::from_secs(2 * 3600)),
+        DstTransition::new(10, 5, 0, Duration::from_secs(3 * 3600)),
+    );
+    assert_eq!(central.offset_secs(at(1_774_745_999)), HOUR);
+    assert_eq!(central.offset_secs(at(1_774_746_000)), 2 * HOUR);
+    assert_eq!(central.offset_secs(at(1_792_889_999)), 2 * HOUR);
+    assert_eq!(central.offset_secs(at(1_792_890_000)), HOUR);
+}
+
+#[tokio::test]
+async fn test_spring_forward_fires_at_the_next_valid_instant() {
+    // Daily at 02:00, which doesn't exist on 2026-03-08
+    let schedule = eastern_cron("0 0 2 * * ?");
+    let occurrences = schedule
+        .occurrences(at(1_772_841_600), at(1_773_057_600)) // 2026-03-07 00:00 UTC to 2026-03-09 12:00 UTC
+        .await
+        .unwrap();
+
+    assert_eq!(
+        occurrences,
+        vec![
+            at(1_772_866_800), // 02:00 EST
+            at(1_772_953_200), // 03:00 EDT, the transition
+            at(1_773_036_000), // 02:00 EDT
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_fall_back_fires_once() {
+    // Daily at 01:30, which occurs twice on 2026-11-01
+    let schedule = eastern_cron("0 30 1 * * ?");
+    let occurrences = schedule
+        .occurrences(at(1_793_404_800), at(1_793_620_800)) // 2026-10-31 00:00 UTC to 2026-11-02 12:00 UTC
+        .await
+        .unwrap();
+
+    assert_eq!(
+        occurrences,
+        vec![
+            at(1_793_424_600), // 01:30 EDT
+            at(1_793_511_000), // 01:30 EDT, the first occurrence
+            at(1_793_601_000), // 01:30 EST
+        ]
+    );
+
+    // Starting within the repeated hour doesn't fire the second occurrence
+    let next = schedule.schedule(at(1_793_514_000)).await.unwrap(); // 01:20 EST
+    assert_eq!(next, at(1_793_601_000));
+}
+
+#[tokio::test]
+async fn test_hourly_skips_the_repeated_hour() {
+    let schedule = eastern_cron("0 0 * * * ?");
+    let occurrences = schedule
+        .occurrences(at(1_793_507_400), at(1_793_518_800)) // 2026-11-01 00:30 EDT to 02:40 EST
+        .await
+        .unwrap();
+
+    assert_eq!(
+        occurrences,
+        vec![
+            at(1_793_509_200), // 01:00 EDT
+            at(1_793_516_400), // 02:00 EST
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_fixed_offset_zone() {
+    let schedule = TaskScheduleZonedCron::new(TaskScheduleCron::from_str("0 0 9 * * ?").unwrap(), FixedOffsetZone(HOUR));
+    let next = schedule.schedule(at(1_772_841_600)).await.unwrap(); // 2026-03-07 00:00 UTC
+
+    assert_eq!(next, at(1_772_841_600 + 8 * 3600));
+}
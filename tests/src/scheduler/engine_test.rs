@@ -0,0 +1,159 @@
+use chronographer::scheduler::SchedulerConfig;
+use chronographer::scheduler::clock::{AdvanceableSchedulerClock, SchedulerClock, VirtualClock};
+use chronographer::scheduler::engine::{DefaultSchedulerEngine, SchedulerEngine};
+use chronographer::scheduler::task_dispatcher::DefaultTaskDispatcher;
+use chronographer::scheduler::task_store::{EphemeralSchedulerTaskStore, SchedulerTaskStore};
+use chronographer::task::{ErasedTask, NoOperationTaskFrame, Task, TaskPriority, TaskScheduleImmediate};
+use std::sync::Arc;
+use std::time::Duration;
+
+struct VirtualSchedulerConfig;
+
+impl SchedulerConfig for VirtualSchedulerConfig {
+    type TaskError = String;
+
+    type SchedulerTaskStore = EphemeralSchedulerTaskStore<Self>;
+    type SchedulerTaskDispatcher = DefaultTaskDispatcher<Self>;
+    type SchedulerEngine = DefaultSchedulerEngine<Self>;
+    type SchedulerClock = VirtualClock;
+}
+
+fn task(priority: TaskPriority) -> Arc<ErasedTask<String>> {
+    let frame = NoOperationTaskFrame::<String, ()>::default();
+    Arc::new(Task::new(frame, TaskScheduleImmediate).with_priority(priority).into_erased())
+}
+
+#[tokio::test]
+async fn test_same_tick_retrieval_by_priority() {
+    let store = EphemeralSchedulerTaskStore::<VirtualSchedulerConfig>::default();
+    let engine = DefaultSchedulerEngine::<VirtualSchedulerConfig>::default();
+
+    let low = store.store(task(TaskPriority::LOW)).unwrap();
+    let high = store.store(task(TaskPriority::HIGH)).unwrap();
+
+    let due = engine.clock().now() + Duration::from_millis(5);
+    engine.schedule(&low, due, TaskPriority::LOW).await.unwrap();
+    engine.schedule(&high, due, TaskPriority::HIGH).await.unwrap();
+
+    // Let the engine loop park on the clock before advancing it
+    tokio::task::yield_now().await;
+    engine.clock().advance(Duration::from_millis(10));
+
+    let retrieved = loop {
+        let batch = engine.retrieve().await;
+        if !batch.is_empty() {
+            break batch;
+        }
+    };
+
+    assert_eq!(retrieved, vec![high, low]);
+}
+
+#[tokio::test]
+async fn test_rescheduling_supersedes_pending_entry() {
+    let store = EphemeralSchedulerTaskStore::<VirtualSchedulerConfig>::default();
+    let engine = DefaultSchedulerEngine::<VirtualSchedulerConfig>::default();
+
+    let key = store.store(task(TaskPriority::NORMAL)).unwrap();
+
+    let now = engine.clock().now();
+    engine.schedule(&key, now + Duration::from_millis(20), TaskPriority::NORMAL).await.unwrap();
+    engine.schedule(&key, now + Duration::from_millis(5), TaskPriority::NORMAL).await.unwrap();
+
+    tokio::task::yield_now().await;
+    engine.clock().advance(Duration::from_millis(30));
+
+    // Every tick hands out exactly one (possibly empty) batch
+    let mut fired = Vec::new();
+    for tick in 0..30 {
+        if engine.retrieve().await.contains(&key) {
+            fired.push(tick);
+        }
+    }
+
+    assert_eq!(fired.len(), 1);
+    assert!(fired[0] < 10);
+}
+
+#[tokio::test]
+async fn test_peek_next_orders_pending_keys_without_consuming_them() {
+    let store = EphemeralSchedulerTaskStore::<VirtualSchedulerConfig>::default();
+    let engine = DefaultSchedulerEngine::<VirtualSchedulerConfig>::default();
+
+    let late = store.store(task(TaskPriority::NORMAL)).unwrap();
+    let low = store.store(task(TaskPriority::LOW)).unwrap();
+    let high = store.store(task(TaskPriority::HIGH)).unwrap();
+
+    let now = engine.clock().now();
+    engine.schedule(&late, now + Duration::from_millis(20), TaskPriority::NORMAL).await.unwrap();
+    engine.schedule(&low, now + Duration::from_millis(5), TaskPriority::LOW).await.unwrap();
+    engine.schedule(&high, now + Duration::from_millis(5), TaskPriority::HIGH).await.unwrap();
+
+    // A single tick lets the engine take in the scheduled keys
+    tokio::task::yield_now().await;
+    engine.clock().advance(Duration::from_millis(1));
+    assert!(engine.retrieve().await.is_empty());
+
+    let peeked = engine.peek_next(2);
+    assert_eq!(peeked, vec![(high, now + Duration::from_millis(5)), (low, now + Duration::from_millis(5))]);
+    assert_eq!(engine.peek_next(10).len(), 3);
+    assert_eq!(engine.peek_next(2), peeked, "peeking shouldn't disturb the pending keys");
+
+    engine.clock().advance(Duration::from_millis(9));
+    let retrieved = loop {
+        let batch = engine.retrieve().await;
+        if !batch.is_empty() {
+            break batch;
+        }
+    };
+
+    assert_eq!(retrieved, vec![high, low]);
+    assert_eq!(engine.peek_next(10), vec![(late, now + Duration::from_millis(20))]);
+}
+
+#[tokio::test]
+async fn test_past_instants_fire_on_the_next_tick() {
+    let store = EphemeralSchedulerTaskStore::<VirtualSchedulerConfig>::default();
+    let engine = DefaultSchedulerEngine::<VirtualSchedulerConfig>::default();
+
+    let missed = store.store(task(TaskPriority::NORMAL)).unwrap();
+    let now = engine.clock().now();
+    engine.schedule(&missed, now - Duration::from_secs(5), TaskPriority::NORMAL).await.unwrap();
+
+    tokio::task::yield_now().await;
+    engine.clock().advance(Duration::from_millis(1));
+
+    assert_eq!(engine.retrieve().await, vec![missed]);
+}
+
+#[tokio::test]
+async fn test_removed_keys_leave_peek_next_and_never_fire() {
+    let store = EphemeralSchedulerTaskStore::<VirtualSchedulerConfig>::default();
+    let engine = DefaultSchedulerEngine::<VirtualSchedulerConfig>::default();
+
+    let kept = store.store(task(TaskPriority::NORMAL)).unwrap();
+    let removed = store.store(task(TaskPriority::HIGH)).unwrap();
+
+    let now = engine.clock().now();
+    engine.schedule(&kept, now + Duration::from_millis(5), TaskPriority::NORMAL).await.unwrap();
+    engine.schedule(&removed, now + Duration::from_millis(5), TaskPriority::HIGH).await.unwrap();
+
+    tokio::task::yield_now().await;
+    engine.clock().advance(Duration::from_millis(1));
+    assert!(engine.retrieve().await.is_empty());
+    assert_eq!(engine.peek_next(10).len(), 2);
+
+    engine.remove(&removed).await;
+    assert_eq!(engine.peek_next(10), vec![(kept.clone(), now + Duration::from_millis(5))]);
+
+    engine.clock().advance(Duration::from_millis(9));
+    let retrieved = loop {
+        let batch = engine.retrieve().await;
+        if !batch.is_empty() {
+            break batch;
+        }
+    };
+
+    assert_eq!(retrieved, vec![kept]);
+    assert!(engine.peek_next(10).is_empty());
+}
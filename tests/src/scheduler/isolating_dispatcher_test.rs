@@ -0,0 +1,77 @@
+use chronographer::prelude::*;
+use chronographer::scheduler::clock::ProgressiveClock;
+use chronographer::scheduler::engine::DefaultSchedulerEngine;
+use chronographer::scheduler::task_dispatcher::{IsolatingDispatcher, SchedulerTaskDispatcher};
+use chronographer::scheduler::task_store::{EphemeralSchedulerTaskStore, SchedulerTaskStore};
+use chronographer::task::{ErasedTask, TaskScheduleImmediate};
+use std::sync::{Arc, Mutex};
+
+struct IsolatingSchedulerConfig;
+
+impl SchedulerConfig for IsolatingSchedulerConfig {
+    type TaskError = String;
+
+    type SchedulerTaskStore = EphemeralSchedulerTaskStore<Self>;
+    type SchedulerTaskDispatcher = IsolatingDispatcher<Self>;
+    type SchedulerEngine = DefaultSchedulerEngine<Self>;
+    type SchedulerClock = ProgressiveClock;
+}
+
+fn thread_recording_task(seen: Arc<Mutex<Option<String>>>, isolated: bool) -> Arc<ErasedTask<String>> {
+    let task = Task::new(
+        DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+            let seen = seen.clone();
+            async move {
+                let name = std::thread::current().name().map(str::to_owned);
+                *seen.lock().unwrap() = Some(name.unwrap_or_default());
+                Ok::<_, String>(())
+            }
+        }),
+        TaskScheduleImmediate,
+    );
+
+    let task = if isolated { task.with_isolation() } else { task };
+    Arc::new(task.into_erased())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_isolated_tasks_run_on_dedicated_runtime() {
+    let store = EphemeralSchedulerTaskStore::<IsolatingSchedulerConfig>::default();
+    let dispatcher = IsolatingDispatcher::<IsolatingSchedulerConfig>::new(1).unwrap();
+
+    let isolated_seen = Arc::new(Mutex::new(None));
+    let shared_seen = Arc::new(Mutex::new(None));
+
+    let isolated = thread_recording_task(isolated_seen.clone(), true);
+    let shared = thread_recording_task(shared_seen.clone(), false);
+
+    let isolated_key = store.store(isolated.clone()).unwrap();
+    let shared_key = store.store(shared.clone()).unwrap();
+
+    dispatcher.dispatch(&isolated_key, isolated).await.unwrap();
+    dispatcher.dispatch(&shared_key, shared).await.unwrap();
+
+    assert_eq!(isolated_seen.lock().unwrap().as_deref(), Some("chronographer-isolated"));
+    assert_ne!(shared_seen.lock().unwrap().as_deref(), Some("chronographer-isolated"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_isolated_task_errors_are_propagated() {
+    let store = EphemeralSchedulerTaskStore::<IsolatingSchedulerConfig>::default();
+    let dispatcher = IsolatingDispatcher::<IsolatingSchedulerConfig>::new(1).unwrap();
+
+    let task = Arc::new(
+        Task::new(
+            DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async {
+                Err::<(), _>("isolated failure".to_string())
+            }),
+            TaskScheduleImmediate,
+        )
+        .with_isolation()
+        .into_erased(),
+    );
+
+    let key = store.store(task.clone()).unwrap();
+    let result = dispatcher.dispatch(&key, task).await;
+    assert_eq!(result, Err("isolated failure".to_string()));
+}
@@ -0,0 +1,918 @@
+use async_trait::async_trait;
+use chronographer::prelude::*;
+use chronographer::scheduler::clock::{AdvanceableSchedulerClock, VirtualClock};
+use chronographer::scheduler::engine::DefaultSchedulerEngine;
+use chronographer::scheduler::task_dispatcher::DefaultTaskDispatcher;
+use chronographer::scheduler::task_store::EphemeralSchedulerTaskStore;
+use chronographer::scheduler::{DefaultLiveScheduler, LiveScheduler, Scheduler, SchedulerConfig, SchedulerShutdownSummary};
+use chronographer::task::{CatchUpPolicy, TaskFrame, TaskHookContext, TaskScheduleInterval};
+use chronographer::utils::SeededRandomSource;
+use std::error::Error;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant, SystemTime};
+
+#[derive(Default)]
+struct RescheduleRecorder(std::sync::Mutex<Vec<(Option<SystemTime>, SystemTime)>>);
+
+#[async_trait]
+impl TaskHook<OnTaskReschedule> for RescheduleRecorder {
+    async fn on_event(&self, _ctx: &TaskHookContext, payload: &<OnTaskReschedule as TaskHookEvent>::Payload<'_>) {
+        self.0.lock().unwrap().push(*payload);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_reschedule_event_carries_previous_and_new_time() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    let recorder = Arc::new(RescheduleRecorder::default());
+
+    let task = Task::new(
+        DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }),
+        TaskScheduleInterval::duration(Duration::from_millis(20)),
+    );
+    task.attach_hook::<OnTaskReschedule>(recorder.clone()).await;
+
+    scheduler.schedule(task).await.unwrap();
+    scheduler.start().await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    scheduler.abort().await;
+
+    let events = recorder.0.lock().unwrap().clone();
+    assert!(events.len() >= 2, "expected multiple reschedules, got {}", events.len());
+    assert_eq!(events[0].0, None);
+
+    for pair in events.windows(2) {
+        assert_eq!(pair[1].0, Some(pair[0].1));
+        assert!(pair[1].1 > pair[0].1);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_drain_holds_back_fires_until_left() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    let runs = Arc::new(AtomicUsize::new(0));
+
+    let counter = runs.clone();
+    let task = Task::new(
+        DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+            let counter = counter.clone();
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok::<_, String>(())
+            }
+        }),
+        TaskScheduleInterval::duration(Duration::from_millis(20)),
+    );
+
+    scheduler.schedule(task).await.unwrap();
+    scheduler.start().await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    scheduler.enter_drain().await;
+    assert!(scheduler.is_draining().await);
+
+    // Let any execution which was already handed out complete
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let drained = runs.load(Ordering::SeqCst);
+    assert!(drained > 0);
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert_eq!(runs.load(Ordering::SeqCst), drained);
+
+    scheduler.leave_drain().await;
+    assert!(!scheduler.is_draining().await);
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    scheduler.abort().await;
+
+    assert!(runs.load(Ordering::SeqCst) > drained);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_high_water_mark_defers_rescheduling() {
+    let scheduler = DefaultLiveScheduler::<String>::builder()
+        .store(Default::default())
+        .engine(Default::default())
+        .dispatcher(Default::default())
+        .high_water_mark(1)
+        .build();
+
+    // Only the first run is slow, afterwards the scheduler never saturates again
+    let saturating = Arc::new(AtomicBool::new(true));
+    let slow = Task::new(
+        DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+            let saturating = saturating.clone();
+            async move {
+                if saturating.swap(false, Ordering::SeqCst) {
+                    tokio::time::sleep(Duration::from_millis(300)).await;
+                }
+
+                Ok::<_, String>(())
+            }
+        }),
+        TaskScheduleInterval::duration(Duration::from_millis(10)),
+    );
+
+    let runs = Arc::new(AtomicUsize::new(0));
+    let counter = runs.clone();
+    let fast = Task::new(
+        DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+            let counter = counter.clone();
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok::<_, String>(())
+            }
+        }),
+        TaskScheduleInterval::duration(Duration::from_millis(20)),
+    );
+
+    scheduler.schedule(slow).await.unwrap();
+    scheduler.schedule(fast).await.unwrap();
+    scheduler.start().await;
+
+    /*
+        The slow Task saturates the scheduler, so the fast one can't reschedule after its first run
+        (which may itself be held back, if its dispatch lands on the worker awaiting the slow Task)
+     */
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(scheduler.is_saturated());
+    assert!(runs.load(Ordering::SeqCst) <= 1);
+
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    scheduler.abort().await;
+    assert!(runs.load(Ordering::SeqCst) > 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_cancel_self_removes_task_after_current_run() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    let runs = Arc::new(AtomicUsize::new(0));
+    let finished = Arc::new(AtomicUsize::new(0));
+
+    let (counter, done) = (runs.clone(), finished.clone());
+    let task = Task::new(
+        DynamicTaskFrame::new(move |ctx: &TaskFrameContext, _args: &()| {
+            let run = counter.fetch_add(1, Ordering::SeqCst) + 1;
+            if run == 2 {
+                ctx.cancel_self();
+            }
+
+            let done = done.clone();
+            async move {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                done.fetch_add(1, Ordering::SeqCst);
+                Ok::<_, String>(())
+            }
+        }),
+        TaskScheduleInterval::duration(Duration::from_millis(20)),
+    );
+
+    let key = scheduler.schedule(task).await.unwrap();
+    scheduler.start().await;
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    scheduler.abort().await;
+
+    assert_eq!(runs.load(Ordering::SeqCst), 2);
+    assert_eq!(finished.load(Ordering::SeqCst), 2);
+    assert!(!scheduler.exists(&key).await);
+}
+
+#[derive(Default)]
+struct ExpiryRecorder(std::sync::Mutex<Vec<Duration>>);
+
+#[async_trait]
+impl TaskHook<OnTaskExpired> for ExpiryRecorder {
+    async fn on_event(&self, _ctx: &TaskHookContext, payload: &<OnTaskExpired as TaskHookEvent>::Payload<'_>) {
+        self.0.lock().unwrap().push(*payload);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_task_dropped_after_max_lifetime() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    let runs = Arc::new(AtomicUsize::new(0));
+    let recorder = Arc::new(ExpiryRecorder::default());
+
+    let counter = runs.clone();
+    let task = Task::new(
+        DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+            let counter = counter.clone();
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok::<_, String>(())
+            }
+        }),
+        TaskScheduleInterval::duration(Duration::from_millis(20)),
+    )
+    .with_max_lifetime(Duration::from_millis(100));
+    task.attach_hook::<OnTaskExpired>(recorder.clone()).await;
+
+    let key = scheduler.schedule(task).await.unwrap();
+    scheduler.start().await;
+    tokio::time::sleep(Duration::from_millis(250)).await;
+
+    let runs_at_expiry = runs.load(Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    scheduler.abort().await;
+
+    assert!(runs_at_expiry > 0);
+    assert_eq!(runs.load(Ordering::SeqCst), runs_at_expiry);
+    assert!(!scheduler.exists(&key).await);
+
+    let expiries = recorder.0.lock().unwrap().clone();
+    assert_eq!(expiries.len(), 1);
+    assert!(expiries[0] > Duration::from_millis(100));
+}
+
+fn counting_task(counter: Arc<AtomicUsize>) -> Task<impl TaskFrame<Args = (), Error = String>> {
+    Task::new(
+        DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+            let counter = counter.clone();
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok::<_, String>(())
+            }
+        }),
+        TaskScheduleInterval::duration(Duration::from_millis(20)),
+    )
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_readiness_gate_holds_gated_tasks() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    let gated_runs = Arc::new(AtomicUsize::new(0));
+    let free_runs = Arc::new(AtomicUsize::new(0));
+
+    scheduler.schedule(counting_task(gated_runs.clone()).with_readiness_gate()).await.unwrap();
+    scheduler.schedule(counting_task(free_runs.clone())).await.unwrap();
+    scheduler.start().await;
+    tokio::time::sleep(Duration::from_millis(150)).await;
+
+    assert!(!scheduler.is_ready().await);
+    assert_eq!(gated_runs.load(Ordering::SeqCst), 0);
+    assert!(free_runs.load(Ordering::SeqCst) > 0);
+
+    scheduler.set_ready().await;
+    tokio::time::timeout(Duration::from_secs(1), scheduler.wait_ready())
+        .await
+        .expect("the gate should be open");
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    scheduler.abort().await;
+
+    assert!(scheduler.is_ready().await);
+    assert!(gated_runs.load(Ordering::SeqCst) > 0);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_metrics_record_dispatch_latency() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    let runs = Arc::new(AtomicUsize::new(0));
+
+    assert_eq!(scheduler.metrics().await.samples(), 0);
+
+    scheduler.schedule(counting_task(runs.clone())).await.unwrap();
+    scheduler.start().await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    scheduler.abort().await;
+
+    let metrics = scheduler.metrics().await;
+    assert!(metrics.samples() > 0);
+    assert!(metrics.samples() as usize >= runs.load(Ordering::SeqCst));
+    assert!(metrics.latency_percentile(0.5) <= metrics.latency_percentile(0.99));
+    assert!(metrics.latency_percentile(1.0) <= metrics.max_latency());
+    assert_eq!(
+        metrics.histogram().iter().map(|(_, count)| count).sum::<u64>(),
+        metrics.samples()
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_override_next_fire_applies_once() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    let recorder = Arc::new(RescheduleRecorder::default());
+    let runs = Arc::new(AtomicUsize::new(0));
+
+    let counter = runs.clone();
+    let task = Task::new(
+        DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+            let counter = counter.clone();
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok::<_, String>(())
+            }
+        }),
+        TaskScheduleInterval::duration(Duration::from_secs(60)),
+    );
+    task.attach_hook::<OnTaskReschedule>(recorder.clone()).await;
+
+    let key = scheduler.schedule(task).await.unwrap();
+    scheduler.start().await;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let nudged = SystemTime::now() + Duration::from_millis(50);
+    scheduler.override_next_fire(&key, nudged).await;
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    scheduler.abort().await;
+
+    assert_eq!(runs.load(Ordering::SeqCst), 1, "only the overridden fire should have run");
+
+    let events = recorder.0.lock().unwrap().clone();
+    assert!(events.len() >= 3, "expected the initial, overridden and resumed fires, got {events:?}");
+    assert_eq!(events[1].1, nudged);
+
+    let resumed = events[2].1.duration_since(nudged).unwrap();
+    assert!(resumed >= Duration::from_secs(59), "the schedule should resume afterwards, got {resumed:?}");
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_tasks_fire_on_current_thread_runtime() {
+    let scheduler = LiveScheduler::<DefaultSchedulerConfig<String>>::builder()
+        .store(Default::default())
+        .engine(Default::default())
+        .dispatcher(Default::default())
+        .workers(1)
+        .build();
+
+    let runs = Arc::new(AtomicUsize::new(0));
+    scheduler.schedule(counting_task(runs.clone())).await.unwrap();
+    scheduler.start().await;
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    scheduler.abort().await;
+
+    assert!(runs.load(Ordering::SeqCst) >= 2, "expected repeated fires, got {}", runs.load(Ordering::SeqCst));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_wait_idle_resolves_once_nothing_is_due_or_running() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    let finished = Arc::new(AtomicUsize::new(0));
+
+    let counter = finished.clone();
+    let task = Task::new(
+        DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+            let counter = counter.clone();
+            async move {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok::<_, String>(())
+            }
+        }),
+        TaskScheduleInterval::duration(Duration::from_secs(60)),
+    );
+
+    let key = scheduler.schedule(task).await.unwrap();
+    scheduler.start().await;
+    tokio::time::timeout(Duration::from_secs(1), scheduler.wait_idle())
+        .await
+        .expect("a Task due in a minute should leave the scheduler idle");
+
+    scheduler.override_next_fire(&key, SystemTime::now()).await;
+    tokio::time::timeout(Duration::from_secs(1), scheduler.wait_idle())
+        .await
+        .expect("the scheduler should become idle after the nudged fire");
+    scheduler.abort().await;
+
+    assert_eq!(finished.load(Ordering::SeqCst), 1, "wait_idle should only resolve after the execution finished");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_diagnostics_dump_task_and_scheduler_state() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    let runs = Arc::new(AtomicUsize::new(0));
+
+    let key = scheduler
+        .schedule(counting_task(runs.clone()).with_description("report").with_history(4))
+        .await
+        .unwrap();
+    scheduler.start().await;
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    scheduler.enter_drain().await;
+
+    let diagnostics = scheduler.diagnostics().await;
+    scheduler.abort().await;
+
+    assert!(diagnostics.draining);
+    assert!(!diagnostics.ready);
+    assert!(diagnostics.metrics.samples() > 0);
+    assert_eq!(diagnostics.tasks.len(), 1);
+
+    let task = &diagnostics.tasks[0];
+    assert_eq!(task.id, usize::from(key));
+    assert_eq!(task.description, "report");
+    assert!(task.next_fire.is_some());
+    assert!(task.last_run.as_ref().is_some_and(|run| run.is_success()));
+}
+
+struct EndCounter(Arc<AtomicUsize>);
+
+#[async_trait]
+impl TaskHook<OnTaskEnd> for EndCounter {
+    async fn on_event(&self, _ctx: &TaskHookContext, _payload: &<OnTaskEnd as TaskHookEvent>::Payload<'_>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_default_task_timeout_bounds_executions() {
+    let scheduler = DefaultLiveScheduler::<String>::builder()
+        .store(Default::default())
+        .engine(Default::default())
+        .dispatcher(Default::default())
+        .default_task_timeout(Duration::from_millis(50), || "timed out".to_owned())
+        .build();
+    assert_eq!(scheduler.default_task_timeout(), Some(Duration::from_millis(50)));
+
+    let slow_frame = || {
+        DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async {
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            Ok::<_, String>(())
+        })
+    };
+
+    // Both Tasks fire once within the test, a second run of the opted-out one couldn't finish before the abort
+    let bounded = Task::new(slow_frame(), TaskScheduleInterval::duration(Duration::from_millis(100)));
+    let timed_out = bounded.next_emission::<OnTimeout>();
+
+    let finished = Arc::new(AtomicUsize::new(0));
+    let opted_out = Task::new(slow_frame(), TaskScheduleInterval::duration(Duration::from_millis(100)))
+        .without_default_timeout();
+    let counter = finished.clone();
+    opted_out.attach_hook::<OnTaskEnd>(Arc::new(EndCounter(counter))).await;
+    let not_timed_out = opted_out.next_emission::<OnTimeout>();
+
+    scheduler.schedule(bounded).await.unwrap();
+    scheduler.schedule(opted_out).await.unwrap();
+    scheduler.start().await;
+
+    let duration = tokio::time::timeout(Duration::from_secs(1), timed_out)
+        .await
+        .expect("the default timeout should have fired");
+    assert_eq!(duration, Duration::from_millis(50));
+
+    tokio::time::sleep(Duration::from_millis(400)).await;
+    scheduler.abort().await;
+
+    assert_eq!(finished.load(Ordering::SeqCst), 1, "the opted-out Task should run to completion");
+    assert!(
+        tokio::time::timeout(Duration::from_millis(10), not_timed_out).await.is_err(),
+        "the opted-out Task shouldn't time out"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_task_handle_pauses_resumes_and_cancels() {
+    let scheduler = Arc::new(DefaultLiveScheduler::<String>::default());
+    let runs = Arc::new(AtomicUsize::new(0));
+
+    let handle = scheduler.schedule_with_handle(counting_task(runs.clone())).await.unwrap();
+    scheduler.start().await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(handle.next_fire().await.unwrap().is_some());
+
+    handle.pause().await.unwrap();
+    assert!(handle.is_paused().await.unwrap());
+
+    // Let any execution which was already handed out complete
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let paused = runs.load(Ordering::SeqCst);
+    assert!(paused > 0);
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert_eq!(runs.load(Ordering::SeqCst), paused);
+
+    handle.resume().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert!(runs.load(Ordering::SeqCst) > paused);
+
+    handle.cancel().await.unwrap();
+    assert!(!handle.exists().await);
+    assert!(!scheduler.exists(handle.key()).await);
+    scheduler.abort().await;
+
+    drop(scheduler);
+    assert!(handle.pause().await.is_err(), "the handle shouldn't keep the scheduler alive");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_task_handle_run_now_fires_immediately() {
+    let scheduler = Arc::new(DefaultLiveScheduler::<String>::default());
+    let runs = Arc::new(AtomicUsize::new(0));
+
+    let counter = runs.clone();
+    let task = Task::new(
+        DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+            let counter = counter.clone();
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok::<_, String>(())
+            }
+        }),
+        TaskScheduleInterval::duration(Duration::from_secs(60)),
+    );
+
+    let handle = scheduler.schedule_with_handle(task).await.unwrap();
+    scheduler.start().await;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(runs.load(Ordering::SeqCst), 0);
+
+    handle.run_now().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    scheduler.abort().await;
+
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_on_empty_waits_for_removed_tasks_to_finish() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    tokio::time::timeout(Duration::from_millis(50), scheduler.on_empty())
+        .await
+        .expect("an empty scheduler should resolve right away");
+
+    let finished = Arc::new(AtomicUsize::new(0));
+    for _ in 0..3 {
+        let done = finished.clone();
+        let task = Task::new(
+            DynamicTaskFrame::new(move |ctx: &TaskFrameContext, _args: &()| {
+                ctx.cancel_self();
+
+                let done = done.clone();
+                async move {
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    done.fetch_add(1, Ordering::SeqCst);
+                    Ok::<_, String>(())
+                }
+            }),
+            TaskScheduleInterval::duration(Duration::from_millis(20)),
+        );
+
+        scheduler.schedule(task).await.unwrap();
+    }
+
+    scheduler.start().await;
+    tokio::time::timeout(Duration::from_secs(1), scheduler.on_empty())
+        .await
+        .expect("every Task removes itself after its first run");
+    scheduler.abort().await;
+
+    assert_eq!(finished.load(Ordering::SeqCst), 3, "on_empty should only resolve after the executions finished");
+    assert!(scheduler.list().await.is_empty());
+}
+
+// Fires once at the given time, then an hour after every computation
+struct FiresAt(SystemTime);
+
+#[async_trait]
+impl TaskSchedule for FiresAt {
+    async fn schedule(&self, now: SystemTime) -> Result<SystemTime, Box<dyn Error + Send + Sync>> {
+        match now < self.0 {
+            true => Ok(self.0),
+            false => Ok(now + Duration::from_secs(3600)),
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_dispatch_jitter_spreads_simultaneous_fires() {
+    let scheduler = DefaultLiveScheduler::<String>::builder()
+        .store(Default::default())
+        .engine(Default::default())
+        .dispatcher(Default::default())
+        .dispatch_jitter(Duration::from_millis(100))
+        .build()
+        .with_random_source(SeededRandomSource::new(11));
+
+    let dispatched = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let fire_at = SystemTime::now() + Duration::from_millis(100);
+    for _ in 0..5 {
+        let dispatched = dispatched.clone();
+        let task = Task::new(
+            DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+                dispatched.lock().unwrap().push(Instant::now());
+                async { Ok::<_, String>(()) }
+            }),
+            FiresAt(fire_at),
+        );
+
+        scheduler.schedule(task).await.unwrap();
+    }
+
+    scheduler.start().await;
+    tokio::time::sleep(Duration::from_millis(400)).await;
+    scheduler.abort().await;
+
+    let dispatched = dispatched.lock().unwrap().clone();
+    assert_eq!(dispatched.len(), 5);
+
+    let first = dispatched.iter().min().unwrap();
+    let last = dispatched.iter().max().unwrap();
+    assert!(
+        last.duration_since(*first) >= Duration::from_millis(5),
+        "the fires should be spread out, got {:?}",
+        last.duration_since(*first)
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_closure_shorthands_schedule_tasks() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    let (repeated, once) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+
+    let counter = repeated.clone();
+    let every = scheduler
+        .every(Duration::from_millis(20), move |_ctx| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async { Ok(()) }
+        })
+        .await
+        .unwrap();
+
+    let counter = once.clone();
+    let at = scheduler
+        .at(SystemTime::now() + Duration::from_millis(50), move |_ctx| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async { Ok(()) }
+        })
+        .await
+        .unwrap();
+
+    let invalid = scheduler.cron("not a cron expression", |_ctx| async { Ok(()) }).await;
+    assert!(invalid.is_err());
+
+    scheduler.start().await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    scheduler.abort().await;
+
+    assert!(repeated.load(Ordering::SeqCst) >= 2);
+    assert!(scheduler.exists(&every).await);
+    assert_eq!(once.load(Ordering::SeqCst), 1);
+    assert!(!scheduler.exists(&at).await, "the one-shot Task should remove itself after firing");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_run_at_most_caps_catch_up_bursts() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    let starts = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let recorded = starts.clone();
+    let task = Task::new(
+        DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+            let first = {
+                let mut starts = recorded.lock().unwrap();
+                starts.push(Instant::now());
+                starts.len() == 1
+            };
+
+            async move {
+                // Overrunning the first execution misses around ten fire times
+                if first {
+                    tokio::time::sleep(Duration::from_millis(500)).await;
+                }
+
+                Ok::<_, String>(())
+            }
+        }),
+        TaskScheduleInterval::duration(Duration::from_millis(50)),
+    )
+    .with_catch_up(CatchUpPolicy::RunAtMost(NonZeroUsize::new(3).unwrap()));
+
+    scheduler.schedule(task).await.unwrap();
+    scheduler.start().await;
+    tokio::time::sleep(Duration::from_millis(800)).await;
+    scheduler.abort().await;
+
+    let starts = starts.lock().unwrap().clone();
+    assert!(starts.len() >= 5, "expected the normal cadence to resume, got {} runs", starts.len());
+
+    let rapid = starts
+        .windows(2)
+        .filter(|pair| pair[1] - pair[0] < Duration::from_millis(25))
+        .count();
+    assert_eq!(rapid, 2, "only three missed fire times should fire back to back");
+}
+
+struct VirtualSchedulerConfig;
+
+impl SchedulerConfig for VirtualSchedulerConfig {
+    type TaskError = String;
+
+    type SchedulerTaskStore = EphemeralSchedulerTaskStore<Self>;
+    type SchedulerTaskDispatcher = DefaultTaskDispatcher<Self>;
+    type SchedulerEngine = DefaultSchedulerEngine<Self>;
+    type SchedulerClock = VirtualClock;
+}
+
+fn counting_every(counter: Arc<AtomicUsize>, secs: u64) -> Task<impl TaskFrame<Args = (), Error = String>> {
+    Task::new(
+        DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async { Ok::<_, String>(()) }
+        }),
+        TaskScheduleInterval::from_secs(secs),
+    )
+}
+
+#[tokio::test]
+async fn test_tick_drives_the_scheduler_step_by_step() {
+    let scheduler = LiveScheduler::<VirtualSchedulerConfig>::default();
+    assert!(scheduler.tick().await.is_none(), "nothing should be ticked without Tasks");
+
+    let (first_runs, second_runs) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+    let first = scheduler
+        .schedule(counting_every(first_runs.clone(), 10))
+        .await
+        .unwrap();
+    let second = scheduler
+        .schedule(counting_every(second_runs.clone(), 15))
+        .await
+        .unwrap();
+
+    // The first tick idles on the clock until the earliest fire time is reached
+    let (ticked, _) = tokio::join!(scheduler.tick(), async {
+        scheduler.clock().advance(Duration::from_secs(10));
+    });
+    assert_eq!(ticked, Some((first, Ok(()))));
+
+    scheduler.clock().advance(Duration::from_secs(5));
+    assert_eq!(scheduler.tick().await, Some((second, Ok(()))));
+
+    scheduler.clock().advance(Duration::from_secs(5));
+    assert_eq!(scheduler.tick().await, Some((first, Ok(()))));
+
+    assert_eq!(first_runs.load(Ordering::SeqCst), 2);
+    assert_eq!(second_runs.load(Ordering::SeqCst), 1);
+    assert!(!scheduler.has_started().await);
+}
+
+#[tokio::test]
+async fn test_max_lifetime_follows_the_scheduler_clock() {
+    let scheduler = LiveScheduler::<VirtualSchedulerConfig>::default();
+    let runs = Arc::new(AtomicUsize::new(0));
+    let recorder = Arc::new(ExpiryRecorder::default());
+
+    let task = counting_every(runs.clone(), 10).with_max_lifetime(Duration::from_secs(15));
+    task.attach_hook::<OnTaskExpired>(recorder.clone()).await;
+    let key = scheduler.schedule(task).await.unwrap();
+
+    let (ticked, _) = tokio::join!(scheduler.tick(), async {
+        scheduler.clock().advance(Duration::from_secs(10));
+    });
+    assert_eq!(ticked, Some((key, Ok(()))));
+
+    // No wall time passes, only the virtual clock moves past the lifetime
+    scheduler.clock().advance(Duration::from_secs(10));
+    assert!(scheduler.tick().await.is_none());
+
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+    assert!(!scheduler.exists(&key).await);
+    assert_eq!(*recorder.0.lock().unwrap(), vec![Duration::from_secs(20)]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_schedule_with_completion_resolves_on_next_completion() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    let runs = Arc::new(AtomicUsize::new(0));
+
+    let counter = runs.clone();
+    let task = Task::new(
+        DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+            let run = counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                match run {
+                    0 => Err("upstream unavailable".to_owned()),
+                    _ => Ok(()),
+                }
+            }
+        }),
+        TaskScheduleInterval::duration(Duration::from_millis(20)),
+    );
+
+    let (key, completion) = scheduler.schedule_with_completion(task).await.unwrap();
+    scheduler.start().await;
+
+    let outcome = tokio::time::timeout(Duration::from_secs(1), completion)
+        .await
+        .expect("the first execution should complete")
+        .unwrap();
+
+    let tasks = scheduler.list().await;
+    let (_, task) = tasks.iter().find(|(handle, _)| *handle == key).unwrap();
+    assert_eq!(task.hook_count::<OnTaskEnd>(), 0);
+    scheduler.abort().await;
+
+    assert_eq!(outcome, Err("upstream unavailable".to_owned()));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_start_resolves_once_started_and_is_idempotent() {
+    let scheduler = Arc::new(DefaultLiveScheduler::<String>::default());
+    assert!(!scheduler.has_started().await);
+
+    let waiter = {
+        let scheduler = scheduler.clone();
+        tokio::spawn(async move { scheduler.started().await })
+    };
+
+    let (first, second) = (scheduler.clone(), scheduler.clone());
+    tokio::join!(
+        async move {
+            first.start().await;
+            assert!(first.has_started().await);
+        },
+        async move {
+            second.start().await;
+            assert!(second.has_started().await);
+        }
+    );
+
+    tokio::time::timeout(Duration::from_secs(1), waiter)
+        .await
+        .expect("started() should resolve once the scheduler has started")
+        .unwrap();
+
+    // Scheduling right after start is picked up without any settling delay
+    let runs = Arc::new(AtomicUsize::new(0));
+    let counter = runs.clone();
+    scheduler
+        .schedule(Task::new(
+            DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                async { Ok::<_, String>(()) }
+            }),
+            TaskScheduleImmediate,
+        ))
+        .await
+        .unwrap();
+
+    tokio::time::timeout(Duration::from_secs(1), async {
+        while runs.load(Ordering::SeqCst) == 0 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("the Task should run once scheduled");
+
+    scheduler.abort().await;
+    assert!(!scheduler.has_started().await);
+}
+
+fn sleeping_task(duration: Duration) -> Task<impl TaskFrame<Args = (), Error = String>> {
+    Task::new(
+        DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| async move {
+            tokio::time::sleep(duration).await;
+            Ok::<_, String>(())
+        }),
+        TaskScheduleInterval::duration(Duration::from_millis(10)),
+    )
+}
+
+async fn wait_in_flight(scheduler: &DefaultLiveScheduler<String>) {
+    tokio::time::timeout(Duration::from_secs(1), async {
+        while scheduler.in_flight() == 0 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("the Task should be in flight");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_stop_and_clear_drains_in_flight_executions() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    let key = scheduler.schedule(sleeping_task(Duration::from_millis(50))).await.unwrap();
+    scheduler.start().await;
+    wait_in_flight(&scheduler).await;
+
+    let summary = scheduler.stop_and_clear(Duration::from_secs(1)).await;
+    assert_eq!(summary, SchedulerShutdownSummary { drained: 1, aborted: 0, timed_out: false });
+    assert!(!scheduler.has_started().await);
+    assert!(!scheduler.exists(&key).await);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_stop_and_clear_aborts_once_the_timeout_elapses() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    let key = scheduler.schedule(sleeping_task(Duration::from_secs(5))).await.unwrap();
+    scheduler.start().await;
+    wait_in_flight(&scheduler).await;
+
+    let started = Instant::now();
+    let summary = scheduler.stop_and_clear(Duration::from_millis(50)).await;
+    assert!(started.elapsed() < Duration::from_secs(1));
+    assert_eq!(summary, SchedulerShutdownSummary { drained: 0, aborted: 1, timed_out: true });
+    assert!(!scheduler.exists(&key).await);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_stop_and_clear_before_start_only_clears() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    let key = scheduler.schedule(sleeping_task(Duration::ZERO)).await.unwrap();
+
+    assert_eq!(scheduler.stop_and_clear(Duration::from_secs(1)).await, SchedulerShutdownSummary::default());
+    assert!(!scheduler.exists(&key).await);
+
+    // Stopping an already stopped (and cleared) scheduler is a no-op
+    assert_eq!(scheduler.stop_and_clear(Duration::from_secs(1)).await, SchedulerShutdownSummary::default());
+    assert!(!scheduler.has_started().await);
+}
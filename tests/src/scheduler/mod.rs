@@ -0,0 +1,3 @@
+mod engine_test;
+mod isolating_dispatcher_test;
+mod live_test;
@@ -0,0 +1,84 @@
+use std::time::Duration;
+
+use chronographer::errors::TaskBarrierBroken;
+use chronographer::prelude::*;
+use chronographer::task::{ErasedTask, TaskBarrier, TaskFrameContext, TaskScheduleImmediate};
+
+fn task(fails: bool) -> ErasedTask<String> {
+    Task::new(
+        DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| async move {
+            if fails {
+                return Err("failed".to_owned());
+            }
+
+            Ok::<_, String>(())
+        }),
+        TaskScheduleImmediate,
+    )
+    .into_erased()
+}
+
+#[tokio::test]
+async fn test_resolves_once_every_task_ran() {
+    let (a, b) = (task(false), task(true));
+    let barrier = TaskBarrier::new();
+    barrier.add(&a).await;
+    barrier.add(&b).await;
+    assert_eq!(barrier.pending(), 2);
+
+    let waiter = tokio::spawn({
+        let barrier = barrier.clone();
+        async move { barrier.wait().await }
+    });
+
+    a.run().await.unwrap();
+    a.run().await.unwrap();
+    assert_eq!(barrier.pending(), 1, "Running a member twice should count once");
+    assert!(!waiter.is_finished());
+
+    let _ = b.run().await;
+    let result = tokio::time::timeout(Duration::from_secs(1), waiter)
+        .await
+        .expect("The barrier should resolve once every member ran")
+        .unwrap();
+
+    assert!(result.is_ok(), "Failed executions count as completed");
+    assert!(barrier.is_complete());
+}
+
+#[tokio::test]
+async fn test_empty_barrier_resolves_right_away() {
+    assert_eq!(TaskBarrier::new().wait().await, Ok(()));
+}
+
+#[tokio::test]
+async fn test_forget_releases_the_barrier() {
+    let (a, b) = (task(false), task(false));
+    let barrier = TaskBarrier::new();
+    barrier.add(&a).await;
+    barrier.add(&b).await;
+
+    a.run().await.unwrap();
+    barrier.forget(&b);
+
+    let result = tokio::time::timeout(Duration::from_secs(1), barrier.wait())
+        .await
+        .expect("Forgetting the last pending member should release the barrier");
+    assert_eq!(result, Ok(()));
+}
+
+#[tokio::test]
+async fn test_expired_task_breaks_the_barrier() {
+    let (a, b) = (task(false), task(false));
+    let barrier = TaskBarrier::new();
+    barrier.add(&a).await;
+    barrier.add(&b).await;
+
+    a.run().await.unwrap();
+    b.emit_hook_event::<OnTaskExpired>(&Duration::from_secs(60)).await;
+
+    let result = tokio::time::timeout(Duration::from_secs(1), barrier.wait())
+        .await
+        .expect("An expired member should settle the barrier");
+    assert!(matches!(result, Err(TaskBarrierBroken(ids)) if ids.len() == 1));
+}
@@ -1,4 +1,6 @@
 use std::num::NonZeroU16;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
 use chronographer::prelude::FrameDependency;
 use chronographer::task::{Task, TaskScheduleImmediate};
 use crate::task::utils::CountingTaskFrame;
@@ -320,4 +322,51 @@ async fn test_task_failed_run_dependency() -> Result<(), String> {
     );
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_dependency_observer_reports_transitions() -> Result<(), String> {
+    let frame = CountingTaskFrame::default();
+    let task = Task::new(frame.clone(), TaskScheduleImmediate);
+    let transitions = Arc::new(Mutex::new(Vec::new()));
+
+    let recorded = transitions.clone();
+    let dep = (FrameDependency::runs(&task, NonZeroU16::MIN).await & !FrameDependency::runs(&task, NonZeroU16::MAX).await)
+        .with_observer(move |resolved| recorded.lock().unwrap().push(resolved));
+
+    task.into_erased().run().await?;
+    assert_eq!(
+        *transitions.lock().unwrap(),
+        vec![true],
+        "The observer should be notified of the auto-resolution without any evaluation"
+    );
+
+    dep.disable();
+    dep.enable();
+    assert!(dep.is_resolved().await);
+    assert_eq!(*transitions.lock().unwrap(), vec![true, false, true]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dependency_observer_on_external_dependency() {
+    let state = Arc::new(AtomicBool::new(false));
+    let transitions = Arc::new(Mutex::new(Vec::new()));
+
+    let (source, recorded) = (state.clone(), transitions.clone());
+    let dep = FrameDependency::external(move || {
+        let source = source.clone();
+        async move { source.load(Ordering::SeqCst) }
+    })
+    .with_observer(move |resolved| recorded.lock().unwrap().push(resolved));
+
+    assert!(!dep.is_resolved().await);
+    assert!(transitions.lock().unwrap().is_empty(), "The initial state shouldn't be reported");
+
+    state.store(true, Ordering::SeqCst);
+    assert!(transitions.lock().unwrap().is_empty(), "External dependencies are only observed once evaluated");
+    assert!(dep.is_resolved().await);
+    assert!(dep.is_resolved().await);
+    assert_eq!(*transitions.lock().unwrap(), vec![true]);
+}
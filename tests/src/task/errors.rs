@@ -0,0 +1,57 @@
+use chronographer::prelude::*;
+use chronographer::errors::TimeoutTaskFrameError;
+use chronographer::task::{DependencyUnresolveFail, TaskFrame, TaskScheduleImmediate};
+use std::time::Duration;
+
+fn hanging_frame<E: TaskError>() -> impl TaskFrame<Args = (), Error = E> {
+    DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        Ok::<_, E>(())
+    })
+}
+
+#[tokio::test]
+async fn test_timeout_error_downcasts_to_canonical_kind() {
+    let frame = TimeoutTaskFrame::builder()
+        .frame(hanging_frame::<Box<dyn TaskError>>())
+        .duration(Duration::from_millis(20))
+        .build();
+
+    let err = Task::new(frame, TaskScheduleImmediate)
+        .into_erased()
+        .run()
+        .await
+        .unwrap_err();
+
+    let kind = err.as_ref().as_any().downcast_ref::<ChronographerErrors>();
+    assert_eq!(kind, Some(&ChronographerErrors::TimedOut));
+}
+
+#[tokio::test]
+async fn test_unresolved_dependency_is_distinguishable_from_timeout() {
+    let frame = DependencyTaskFrame::builder()
+        .frame(hanging_frame::<ChronographerErrors>())
+        .dependency(FrameDependency::external(|| async { false }))
+        .unresolve(DependencyUnresolveFail::default())
+        .build();
+
+    let err = Task::new(frame, TaskScheduleImmediate)
+        .into_erased()
+        .run()
+        .await
+        .unwrap_err();
+
+    match err {
+        ChronographerErrors::DependenciesUnresolved => {}
+        other => panic!("expected an unresolved dependency, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_timeout_frame_error_converts_into_canonical_kind() {
+    let timed_out = TimeoutTaskFrameError::<ChronographerErrors>::Timeout(Duration::from_millis(20));
+    assert_eq!(ChronographerErrors::from(timed_out), ChronographerErrors::TimedOut);
+
+    let inner = TimeoutTaskFrameError::Inner(ChronographerErrors::DependenciesUnresolved);
+    assert_eq!(ChronographerErrors::from(inner), ChronographerErrors::DependenciesUnresolved);
+}
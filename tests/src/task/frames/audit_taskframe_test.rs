@@ -0,0 +1,59 @@
+use chronographer::task::schedule::TaskScheduleImmediate;
+use chronographer::task::{AuditOutcome, AuditTaskFrame, MemoryAuditSink, Task};
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::task::frames::CountingFrame;
+
+#[tokio::test]
+async fn test_records_success_and_failure() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let sink = Arc::new(MemoryAuditSink::default());
+
+    let ok = AuditTaskFrame::new_with(CountingFrame { counter: counter.clone(), should_fail: false }, "ok", sink.clone());
+    let failing = AuditTaskFrame::new_with(CountingFrame { counter: counter.clone(), should_fail: true }, "failing", sink.clone());
+
+    let ok = Task::new(ok, TaskScheduleImmediate).into_erased();
+    let failing = Task::new(failing, TaskScheduleImmediate).into_erased();
+
+    assert!(ok.run().await.is_ok());
+    assert!(failing.run().await.is_err());
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+
+    let records = sink.records();
+    assert_eq!(records.len(), 2);
+
+    assert_eq!(records[0].label, "ok");
+    assert_eq!(records[0].outcome, AuditOutcome::Success);
+    assert!(records[0].started_at <= records[0].ended_at);
+
+    assert_eq!(records[1].label, "failing");
+    assert_eq!(records[1].outcome, AuditOutcome::Failure(format!("{:?}", "TaskFrame Failed")));
+    assert_ne!(records[0].task_id, records[1].task_id);
+}
+
+#[tokio::test]
+async fn test_memory_sink_evicts_oldest() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let sink = Arc::new(MemoryAuditSink::new(NonZeroUsize::new(2).unwrap()));
+    let frame = AuditTaskFrame::new_with(CountingFrame { counter, should_fail: false }, "bounded", sink.clone());
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+
+    for _ in 0..3 {
+        task.run().await.unwrap();
+    }
+
+    let records = sink.records();
+    assert_eq!(records.len(), 2);
+    assert!(records[0].started_at <= records[1].started_at);
+}
+
+#[tokio::test]
+async fn test_default_sink_is_queryable() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let frame = AuditTaskFrame::new(CountingFrame { counter, should_fail: false }, "default");
+    let sink = frame.sink().clone();
+
+    Task::new(frame, TaskScheduleImmediate).into_erased().run().await.unwrap();
+    assert_eq!(sink.len(), 1);
+}
@@ -0,0 +1,40 @@
+use chronographer::errors::BlockingTaskFrameError;
+use chronographer::prelude::*;
+use chronographer::task::TaskScheduleImmediate;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[tokio::test]
+async fn test_runs_closure_off_the_async_thread() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let calls = counter.clone();
+    let async_thread = std::thread::current().id();
+    let frame = BlockingTaskFrame::new(move || {
+        assert_ne!(std::thread::current().id(), async_thread);
+        calls.fetch_add(1, Ordering::SeqCst);
+        Ok::<_, String>(())
+    });
+
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+    task.run().await.unwrap();
+    task.run().await.unwrap();
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_propagates_inner_error() {
+    let frame = BlockingTaskFrame::new(|| Err::<(), _>("failed".to_string()));
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+
+    let err = task.run().await.unwrap_err();
+    assert!(matches!(err, BlockingTaskFrameError::Inner(ref msg) if msg == "failed"));
+}
+
+#[tokio::test]
+async fn test_panic_is_surfaced_as_error() {
+    let frame = BlockingTaskFrame::new(|| -> Result<(), String> { panic!("boom") });
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+
+    let err = task.run().await.unwrap_err();
+    assert!(matches!(err, BlockingTaskFrameError::Panicked(ref msg) if msg == "boom"));
+}
@@ -0,0 +1,65 @@
+use chronographer::scheduler::{DefaultLiveScheduler, Scheduler};
+use chronographer::task::{ChunkOutcome, ChunkedTaskFrame, Task, TaskFrameContext, TaskScheduleInterval, TaskScheduleImmediate, TaskScheduleStartup};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+type ChunkFuture = std::future::Ready<Result<ChunkOutcome<usize>, String>>;
+
+fn chunked_frame(
+    processed: &Arc<Mutex<Vec<usize>>>,
+    chunks: usize,
+) -> ChunkedTaskFrame<impl Fn(&TaskFrameContext, usize) -> ChunkFuture + Send + Sync + 'static, usize> {
+    let processed = processed.clone();
+    ChunkedTaskFrame::new(move |_ctx: &TaskFrameContext, chunk: usize| {
+        processed.lock().unwrap().push(chunk);
+        std::future::ready(Ok(match chunk + 1 < chunks {
+            true => ChunkOutcome::More(chunk + 1),
+            false => ChunkOutcome::Done,
+        }))
+    })
+}
+
+#[tokio::test]
+async fn chunk_progress_carries_across_executions() {
+    let processed = Arc::new(Mutex::new(Vec::new()));
+    let task = Task::new(chunked_frame(&processed, 3), TaskScheduleImmediate);
+
+    assert_eq!(task.frame().progress(), 0);
+    let task = task.into_erased();
+    for _ in 0..4 {
+        task.run().await.unwrap();
+    }
+
+    assert_eq!(*processed.lock().unwrap(), vec![0, 1, 2, 0], "the job should restart once done");
+}
+
+#[tokio::test]
+async fn resumed_frame_picks_up_from_persisted_progress() {
+    let processed = Arc::new(Mutex::new(Vec::new()));
+    let recorder = processed.clone();
+    let frame = ChunkedTaskFrame::resume(
+        move |_ctx: &TaskFrameContext, chunk: usize| {
+            recorder.lock().unwrap().push(chunk);
+            std::future::ready(Ok::<_, String>(ChunkOutcome::More(chunk + 1)))
+        },
+        7,
+    );
+
+    assert_eq!(frame.progress(), 7);
+    Task::new(frame, TaskScheduleImmediate).into_erased().run().await.unwrap();
+    assert_eq!(*processed.lock().unwrap(), vec![7]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn scheduled_chunks_refire_until_done() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    let processed = Arc::new(Mutex::new(Vec::new()));
+
+    let schedule = TaskScheduleStartup::new(TaskScheduleInterval::duration(Duration::from_secs(60)));
+    scheduler.schedule(Task::new(chunked_frame(&processed, 5), schedule)).await.unwrap();
+    scheduler.start().await;
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    scheduler.abort().await;
+
+    assert_eq!(*processed.lock().unwrap(), vec![0, 1, 2, 3, 4], "every chunk should run once, in order");
+}
@@ -0,0 +1,70 @@
+use chronographer::task::hooks::events::OnErrorBurst;
+use chronographer::task::{CoalesceErrorsTaskFrame, Task, TaskFrame, TaskFrameContext, TaskScheduleImmediate};
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+// Replays the scripted outcomes, one per execution
+#[derive(Clone, Default)]
+struct ScriptedFrame(Arc<Mutex<Vec<Result<(), String>>>>);
+
+impl ScriptedFrame {
+    fn new(outcomes: Vec<Result<(), &str>>) -> Self {
+        let mut outcomes = outcomes
+            .into_iter()
+            .map(|outcome| outcome.map_err(str::to_owned))
+            .collect::<Vec<_>>();
+        outcomes.reverse();
+
+        Self(Arc::new(Mutex::new(outcomes)))
+    }
+}
+
+impl TaskFrame for ScriptedFrame {
+    type Error = String;
+    type Args = ();
+    type Workflow = Self;
+
+    async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+        self.0.lock().unwrap().pop().unwrap_or(Ok(()))
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn repeated_errors_are_suppressed_until_the_interval_elapses() {
+    let frame = ScriptedFrame::new(vec![Err("down"); 4]);
+    let task = Task::new(CoalesceErrorsTaskFrame::new(frame, Duration::from_secs(60)), TaskScheduleImmediate).into_erased();
+
+    assert_eq!(task.run().await, Err("down".to_owned()));
+    assert!(task.run().await.is_ok());
+    assert!(task.run().await.is_ok());
+
+    tokio::time::advance(Duration::from_secs(61)).await;
+    let burst = task.next_emission::<OnErrorBurst>();
+    assert_eq!(task.run().await, Err("down".to_owned()));
+
+    let (count, error) = tokio::time::timeout(Duration::from_secs(1), burst)
+        .await
+        .expect("OnErrorBurst should have been emitted");
+    assert_eq!(count, 2);
+    assert_eq!(&*error, "\"down\"");
+}
+
+#[tokio::test(start_paused = true)]
+async fn different_errors_and_recovery_flush_the_count() {
+    let frame = ScriptedFrame::new(vec![Err("down"), Err("down"), Err("timeout"), Err("timeout"), Ok(())]);
+    let coalesce = CoalesceErrorsTaskFrame::new(frame, Duration::from_secs(60));
+    let task = Task::new(coalesce, TaskScheduleImmediate).into_erased();
+
+    assert!(task.run().await.is_err());
+    assert!(task.run().await.is_ok());
+
+    let burst = task.next_emission::<OnErrorBurst>();
+    assert_eq!(task.run().await, Err("timeout".to_owned()), "a different error should propagate right away");
+    assert_eq!(burst.await, (1, Arc::from("\"down\"")));
+
+    assert!(task.run().await.is_ok());
+    let burst = task.next_emission::<OnErrorBurst>();
+    assert!(task.run().await.is_ok());
+    assert_eq!(burst.await, (1, Arc::from("\"timeout\"")));
+}
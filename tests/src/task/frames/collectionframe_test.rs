@@ -1,14 +1,27 @@
 use async_trait::async_trait;
 use chronographer::prelude::*;
 use chronographer::task::{
-    CollectionTaskFrame, GroupedTaskFramesQuitOnFailure, GroupedTaskFramesQuitOnSuccess,
+    AggregateError, CollectionTaskError, CollectionTaskFrame, ErasedTaskFrame, GroupedTaskFramesAggregate, GroupedTaskFramesQuitOnFailure, GroupedTaskFramesQuitOnSuccess,
     GroupedTaskFramesSilent, ParallelExecStrategy, SelectFrameAccessor, SelectionExecStrategy,
-    SequentialExecStrategy, TaskScheduleImmediate,
+    SequentialExecStrategy, TaskFrame, TaskHookContext, TaskScheduleImmediate,
 };
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use crate::task::frames::{failing_frame, ok_frame};
 
+struct TimedOutFrame;
+
+impl TaskFrame for TimedOutFrame {
+    type Error = ChronographerErrors;
+    type Args = ();
+    type Workflow = Self;
+
+    async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+        Err(ChronographerErrors::TimedOut)
+    }
+}
+
 struct FixedSelectAccessor(usize);
 
 #[async_trait]
@@ -231,6 +244,57 @@ async fn parallel_quit_on_failure_returns_error_on_first_fail() {
     assert!(counter.load(Ordering::SeqCst) >= 1);
 }
 
+#[tokio::test]
+async fn parallel_aggregate_collects_every_failure() {
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    let frame = CollectionTaskFrame::parallel(
+        vec![
+            ok_frame(&counter),
+            failing_frame(&counter),
+            Arc::new(TimedOutFrame),
+            ok_frame(&counter),
+            failing_frame(&counter),
+        ],
+        GroupedTaskFramesAggregate,
+    );
+
+    let task = Task::new(frame, TaskScheduleImmediate);
+    let err = task.into_erased()
+        .run()
+        .await
+        .expect_err("Aggregate should return an error when any frame fails");
+
+    assert_eq!(counter.load(Ordering::SeqCst), 4, "every frame should have run");
+    assert_eq!(err.index(), 1);
+
+    let aggregate = err.inner().as_any().downcast_ref::<AggregateError>()
+        .expect("error should be AggregateError");
+    assert_eq!(aggregate.indices().collect::<Vec<_>>(), vec![1, 2, 4]);
+
+    let errors = aggregate.errors();
+    assert!(errors[0].inner().as_any().downcast_ref::<String>().is_some());
+    assert_eq!(
+        errors[1].inner().as_any().downcast_ref::<ChronographerErrors>(),
+        Some(&ChronographerErrors::TimedOut)
+    );
+    assert!(errors[2].inner().as_any().downcast_ref::<String>().is_some());
+}
+
+#[tokio::test]
+async fn sequential_aggregate_succeeds_without_failures() {
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    let frame = CollectionTaskFrame::new(
+        vec![ok_frame(&counter), ok_frame(&counter)],
+        SequentialExecStrategy::new(GroupedTaskFramesAggregate),
+    );
+
+    let task = Task::new(frame, TaskScheduleImmediate);
+    task.into_erased().run().await.unwrap();
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+}
+
 #[tokio::test]
 async fn selection_exec_selects_failing_frame_returns_error() {
     let counter = Arc::new(AtomicUsize::new(0));
@@ -266,3 +330,229 @@ async fn empty_sequential_collection_returns_ok() {
 
     assert_eq!(counter.load(Ordering::SeqCst), 0);
 }
+
+#[tokio::test]
+async fn quorum_reached_between_race_and_all() {
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    let frame = CollectionTaskFrame::quorum(
+        vec![
+            ok_frame(&counter),
+            failing_frame(&counter),
+            ok_frame(&counter),
+            failing_frame(&counter),
+            ok_frame(&counter),
+        ],
+        std::num::NonZeroUsize::new(3).unwrap(),
+    );
+
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+    let reached = task.next_emission::<OnQuorumReached>();
+    task.run().await.expect("3 out of 5 frames succeed, quorum of 3 should be reached");
+
+    assert_eq!(reached.await, 3);
+}
+
+#[tokio::test]
+async fn quorum_unreachable_returns_error() {
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    let frame = CollectionTaskFrame::quorum(
+        vec![
+            ok_frame(&counter),
+            failing_frame(&counter),
+            ok_frame(&counter),
+            failing_frame(&counter),
+            failing_frame(&counter),
+        ],
+        std::num::NonZeroUsize::new(3).unwrap(),
+    );
+
+    let task = Task::new(frame, TaskScheduleImmediate);
+    let err = task.into_erased()
+        .run()
+        .await
+        .expect_err("only 2 out of 5 frames succeed, quorum of 3 is unreachable");
+
+    let inner = err.inner().as_any().downcast_ref::<chronographer::errors::QuorumUnreachable>()
+        .expect("error should be QuorumUnreachable");
+    assert_eq!(inner.required, 3);
+    assert_eq!(inner.failed, 3);
+}
+
+#[tokio::test]
+async fn quorum_counts_panicked_frames_as_failed() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let panicking = || -> Arc<dyn ErasedTaskFrame<()>> {
+        Arc::new(DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async {
+            panic!("child frame panicked");
+            #[allow(unreachable_code)]
+            Ok::<(), String>(())
+        }))
+    };
+
+    let frame = CollectionTaskFrame::quorum(
+        vec![ok_frame(&counter), panicking(), panicking()],
+        NonZeroUsize::new(2).unwrap(),
+    );
+
+    let task = Task::new(frame, TaskScheduleImmediate);
+    let err = task.into_erased()
+        .run()
+        .await
+        .expect_err("both panicking frames fail, quorum of 2 is unreachable");
+
+    let inner = err.inner().as_any().downcast_ref::<chronographer::errors::QuorumUnreachable>()
+        .expect("error should be QuorumUnreachable");
+    assert_eq!(inner.failed, 2);
+    assert!(matches!(err.index(), 1 | 2), "got index {}", err.index());
+}
+
+#[tokio::test]
+async fn quorum_larger_than_collection_returns_error() {
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    let frame = CollectionTaskFrame::quorum(
+        vec![ok_frame(&counter), ok_frame(&counter)],
+        std::num::NonZeroUsize::new(3).unwrap(),
+    );
+
+    let task = Task::new(frame, TaskScheduleImmediate);
+    assert!(task.into_erased().run().await.is_err());
+    assert_eq!(counter.load(Ordering::SeqCst), 0);
+}
+
+#[derive(Default)]
+struct SelectionRecorder(std::sync::Mutex<Vec<(usize, f64)>>);
+
+#[async_trait]
+impl TaskHook<OnTaskFrameSelection> for SelectionRecorder {
+    async fn on_event(&self, _ctx: &TaskHookContext, payload: &<OnTaskFrameSelection as TaskHookEvent>::Payload<'_>) {
+        self.0.lock().unwrap().push(*payload);
+    }
+}
+
+#[tokio::test]
+async fn health_aware_fails_over_and_demotes_failing_child() {
+    let failing = Arc::new(AtomicUsize::new(0));
+    let healthy = Arc::new(AtomicUsize::new(0));
+
+    let frame = CollectionTaskFrame::health_aware(
+        vec![failing_frame(&failing), ok_frame(&healthy)],
+        NonZeroUsize::new(4).unwrap(),
+    );
+    let task = Task::new(frame, TaskScheduleImmediate);
+    let recorder = Arc::new(SelectionRecorder::default());
+    task.attach_hook::<OnTaskFrameSelection>(recorder.clone()).await;
+    let task = task.into_erased();
+
+    task.run().await.expect("the healthy child should have been failed over to");
+    task.run().await.expect("the healthy child should be preferred");
+
+    assert_eq!(failing.load(Ordering::SeqCst), 1);
+    assert_eq!(healthy.load(Ordering::SeqCst), 2);
+    assert_eq!(*recorder.0.lock().unwrap(), vec![(0, 1.0), (1, 1.0), (1, 1.0)]);
+}
+
+#[tokio::test]
+async fn health_aware_all_failing_returns_last_error() {
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    let frame = CollectionTaskFrame::health_aware(
+        vec![failing_frame(&counter), failing_frame(&counter)],
+        NonZeroUsize::new(4).unwrap(),
+    );
+    let task = Task::new(frame, TaskScheduleImmediate);
+    let err = task.into_erased().run().await.expect_err("every child fails");
+
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+    assert_eq!(err.index(), 1);
+}
+
+#[tokio::test]
+async fn health_aware_strategy_tracks_health() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let frame = Arc::new(CollectionTaskFrame::health_aware(
+        vec![failing_frame(&counter), ok_frame(&counter)],
+        NonZeroUsize::new(2).unwrap(),
+    ));
+
+    assert_eq!(frame.strategy().health(0), 1.0);
+    let task = Task::new(ArcFrame(frame.clone()), TaskScheduleImmediate).into_erased();
+    task.run().await.unwrap();
+
+    assert_eq!(frame.strategy().health(0), 0.0);
+    assert_eq!(frame.strategy().health(1), 1.0);
+}
+
+struct ArcFrame(Arc<CollectionTaskFrame<HealthSelectionExecStrategy>>);
+
+impl TaskFrame for ArcFrame {
+    type Error = CollectionTaskError;
+    type Args = ();
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, args: &()) -> Result<(), Self::Error> {
+        self.0.execute(ctx, args).await
+    }
+}
+
+#[tokio::test]
+async fn weighted_select_never_picks_zero_weight_frames() {
+    let picked = Arc::new(AtomicUsize::new(0));
+    let skipped = Arc::new(AtomicUsize::new(0));
+
+    let frame = SelectTaskFrame::weighted_builder()
+        .frame(ok_frame(&skipped), 0)
+        .frame(ok_frame(&picked), 3)
+        .frame(ok_frame(&skipped), 0)
+        .with_random_source(chronographer::utils::SeededRandomSource::new(7))
+        .build()
+        .expect("a positive total weight should build");
+
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+    for _ in 0..20 {
+        task.run().await.expect("weighted selection should succeed");
+    }
+
+    assert_eq!(picked.load(Ordering::SeqCst), 20);
+    assert_eq!(skipped.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn weighted_select_picks_proportionally() {
+    let light = Arc::new(AtomicUsize::new(0));
+    let heavy = Arc::new(AtomicUsize::new(0));
+
+    let frame = SelectTaskFrame::weighted_builder()
+        .frame(ok_frame(&light), 1)
+        .frame(ok_frame(&heavy), 9)
+        .with_random_source(chronographer::utils::SeededRandomSource::new(42))
+        .build()
+        .expect("a positive total weight should build");
+
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+    for _ in 0..1000 {
+        task.run().await.expect("weighted selection should succeed");
+    }
+
+    let heavy = heavy.load(Ordering::SeqCst);
+    assert_eq!(light.load(Ordering::SeqCst) + heavy, 1000);
+    assert!(heavy > 800, "heavy frame was picked {heavy} out of 1000 times");
+}
+
+#[test]
+fn weighted_select_rejects_all_zero_weights() {
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    let result = SelectTaskFrame::weighted_builder()
+        .frame(ok_frame(&counter), 0)
+        .frame(ok_frame(&counter), 0)
+        .build();
+
+    assert!(matches!(result, Err(chronographer::errors::SelectionWeightsAllZero)));
+    assert_eq!(
+        WeightedSelectFrameAccessor::new(Vec::new()).err(),
+        Some(chronographer::errors::SelectionWeightsAllZero)
+    );
+}
@@ -0,0 +1,85 @@
+use crate::task::frames::{failing_frame, ok_frame};
+use chronographer::errors::{ChronographerErrors, DeadlineTaskFrameError};
+use chronographer::prelude::DynamicTaskFrame;
+use chronographer::task::{CollectionTaskFrame, DeadlineTaskFrame, ErasedTaskFrame, Task, TaskFrameContext, TaskScheduleImmediate};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+fn sleeping_frame(duration: Duration) -> Arc<dyn ErasedTaskFrame<()>> {
+    Arc::new(DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| async move {
+        tokio::time::sleep(duration).await;
+        Ok::<_, String>(())
+    }))
+}
+
+// Overruns without ever yielding, so only the checks between the steps can notice the deadline
+fn blocking_frame(duration: Duration) -> Arc<dyn ErasedTaskFrame<()>> {
+    Arc::new(DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+        std::thread::sleep(duration);
+        async { Ok::<_, String>(()) }
+    }))
+}
+
+#[tokio::test(start_paused = true)]
+async fn chain_within_budget_succeeds() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let chain = CollectionTaskFrame::sequential(vec![
+        sleeping_frame(Duration::from_millis(10)),
+        ok_frame(&counter),
+    ]);
+
+    let frame = DeadlineTaskFrame::new(chain, Duration::from_millis(100));
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+
+    assert!(task.run().await.is_ok());
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn in_flight_step_is_cancelled_at_the_deadline() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let chain = CollectionTaskFrame::sequential(vec![
+        sleeping_frame(Duration::from_millis(50)),
+        ok_frame(&counter),
+    ]);
+
+    let frame = DeadlineTaskFrame::new(chain, Duration::from_millis(20));
+    let started = tokio::time::Instant::now();
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+
+    let err = task.run().await.unwrap_err();
+    assert!(matches!(err, DeadlineTaskFrameError::Chronographer(ChronographerErrors::DeadlineExceeded)));
+    assert_eq!(started.elapsed(), Duration::from_millis(20));
+    assert_eq!(counter.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn remaining_steps_are_skipped_once_the_deadline_passed() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let chain = CollectionTaskFrame::sequential(vec![
+        blocking_frame(Duration::from_millis(40)),
+        ok_frame(&counter),
+        ok_frame(&counter),
+    ]);
+
+    let frame = DeadlineTaskFrame::new(chain, Duration::from_millis(20));
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+
+    let err = task.run().await.unwrap_err();
+    assert!(matches!(err, DeadlineTaskFrameError::Chronographer(ChronographerErrors::DeadlineExceeded)));
+    assert_eq!(counter.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn errors_before_the_deadline_are_propagated() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let chain = CollectionTaskFrame::sequential(vec![failing_frame(&counter), ok_frame(&counter)]);
+
+    let frame = DeadlineTaskFrame::new(chain, Duration::from_secs(1));
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+
+    let err = task.run().await.unwrap_err();
+    assert!(matches!(err, DeadlineTaskFrameError::Inner(_)));
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+}
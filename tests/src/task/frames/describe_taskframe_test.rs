@@ -0,0 +1,48 @@
+use chronographer::task::{
+    FrameNode, NoOperationTaskFrame, Task, TaskFrame, TaskFrameBuilder, TaskFrameContext,
+    TaskScheduleImmediate,
+};
+use std::num::NonZeroU32;
+use std::sync::Arc;
+use std::time::Duration;
+
+struct ExecutionFrame;
+
+impl TaskFrame for ExecutionFrame {
+    type Error = String;
+    type Args = ();
+    type Workflow = Self;
+
+    async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[test]
+fn describe_defaults_to_leaf() {
+    let node = ExecutionFrame.describe();
+
+    assert_eq!(node, FrameNode::leaf("ExecutionFrame"));
+    assert!(node.children().is_empty());
+}
+
+#[test]
+fn describe_nested_wrappers() {
+    let frame = TaskFrameBuilder::new(ExecutionFrame)
+        .with_timeout(Duration::from_secs(1))
+        .with_instant_retry(NonZeroU32::new(3).unwrap())
+        .build();
+
+    assert_eq!(frame.describe().to_string(), "Retry(Timeout(ExecutionFrame))");
+}
+
+#[test]
+fn describe_collection_and_erased_task() {
+    let frame = TaskFrameBuilder::new(ExecutionFrame)
+        .with_sequential_after(vec![Arc::new(NoOperationTaskFrame::<String, ()>::default())])
+        .build();
+
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+
+    assert_eq!(task.describe().to_string(), "Collection(ExecutionFrame, NoOperation)");
+}
@@ -0,0 +1,56 @@
+use crate::task::frames::CountingFrame;
+use chronographer::prelude::DynamicTaskFrame;
+use chronographer::task::{FinalizerOutcome, FinalizerTaskFrame, Task, TaskFrameContext, TaskScheduleImmediate, TimeoutTaskFrame};
+use std::sync::atomic::AtomicUsize;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+fn recording(outcomes: &Arc<Mutex<Vec<FinalizerOutcome>>>) -> impl Fn(FinalizerOutcome) + Send + Sync + 'static {
+    let outcomes = outcomes.clone();
+    move |outcome| outcomes.lock().unwrap().push(outcome)
+}
+
+#[tokio::test]
+async fn finalizer_runs_after_success_and_failure() {
+    let outcomes = Arc::new(Mutex::new(Vec::new()));
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    let succeeding = FinalizerTaskFrame::new(
+        CountingFrame { counter: counter.clone(), should_fail: false },
+        recording(&outcomes),
+    );
+    let failing = FinalizerTaskFrame::new(
+        CountingFrame { counter: counter.clone(), should_fail: true },
+        recording(&outcomes),
+    );
+
+    assert!(Task::new(succeeding, TaskScheduleImmediate).into_erased().run().await.is_ok());
+    assert!(Task::new(failing, TaskScheduleImmediate).into_erased().run().await.is_err());
+
+    assert_eq!(
+        *outcomes.lock().unwrap(),
+        vec![FinalizerOutcome::Succeeded, FinalizerOutcome::Failed]
+    );
+}
+
+#[tokio::test]
+async fn finalizer_runs_when_cancelled_by_timeout() {
+    let outcomes = Arc::new(Mutex::new(Vec::new()));
+
+    let frame = FinalizerTaskFrame::new(
+        DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args| async move {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok::<_, String>(())
+        }),
+        recording(&outcomes),
+    );
+    let frame = TimeoutTaskFrame::builder()
+        .frame(frame)
+        .duration(Duration::from_millis(20))
+        .build();
+
+    let exec = Task::new(frame, TaskScheduleImmediate).into_erased().run().await;
+
+    assert!(exec.is_err(), "the inner frame should have been cancelled by the timeout");
+    assert_eq!(*outcomes.lock().unwrap(), vec![FinalizerOutcome::Cancelled]);
+}
@@ -0,0 +1,180 @@
+use chronographer::prelude::*;
+use chronographer::task::{
+    AggregateError, ErasedTaskFrame, GroupedTaskFramesAggregate, TaskFrame, TaskFrameBuilder,
+    TaskScheduleImmediate,
+};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+
+type ExecutionLog = Arc<Mutex<Vec<&'static str>>>;
+
+struct LabeledFrame {
+    label: &'static str,
+    log: ExecutionLog,
+    should_fail: bool,
+}
+
+impl TaskFrame for LabeledFrame {
+    type Error = String;
+    type Args = ();
+    type Workflow = Self;
+
+    async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+        self.log.lock().unwrap().push(self.label);
+        if self.should_fail {
+            return Err(format!("{} failed", self.label));
+        }
+
+        Ok(())
+    }
+}
+
+fn labeled(label: &'static str, log: &ExecutionLog, should_fail: bool) -> LabeledFrame {
+    LabeledFrame { label, log: log.clone(), should_fail }
+}
+
+fn erased(label: &'static str, log: &ExecutionLog, should_fail: bool) -> Arc<dyn ErasedTaskFrame<()>> {
+    Arc::new(labeled(label, log, should_fail))
+}
+
+#[tokio::test(start_paused = true)]
+async fn with_delay_waits_before_executing() {
+    let log = ExecutionLog::default();
+    let frame = TaskFrameBuilder::new(labeled("inner", &log, false))
+        .with_delay(Duration::from_secs(5))
+        .build();
+
+    let start = Instant::now();
+    Task::new(frame, TaskScheduleImmediate).into_erased().run().await.unwrap();
+
+    assert!(start.elapsed() >= Duration::from_secs(5));
+    assert_eq!(*log.lock().unwrap(), vec!["inner"]);
+}
+
+#[tokio::test]
+async fn with_sequential_after_runs_inner_frame_first() {
+    let log = ExecutionLog::default();
+    let frame = TaskFrameBuilder::new(labeled("inner", &log, false))
+        .with_sequential_after(vec![erased("first", &log, false), erased("second", &log, false)])
+        .build();
+
+    Task::new(frame, TaskScheduleImmediate).into_erased().run().await.unwrap();
+    assert_eq!(*log.lock().unwrap(), vec!["inner", "first", "second"]);
+}
+
+#[tokio::test]
+async fn with_sequential_after_stops_on_failure() {
+    let log = ExecutionLog::default();
+    let frame = TaskFrameBuilder::new(labeled("inner", &log, true))
+        .with_sequential_after(vec![erased("first", &log, false)])
+        .build();
+
+    let err = Task::new(frame, TaskScheduleImmediate)
+        .into_erased()
+        .run()
+        .await
+        .expect_err("the failing inner frame should stop the sequence");
+
+    assert_eq!(err.index(), 0);
+    assert_eq!(*log.lock().unwrap(), vec!["inner"]);
+
+    let log = ExecutionLog::default();
+    let frame = TaskFrameBuilder::new(labeled("inner", &log, false))
+        .with_sequential_after(vec![erased("first", &log, false), erased("second", &log, true)])
+        .build();
+
+    let err = Task::new(frame, TaskScheduleImmediate)
+        .into_erased()
+        .run()
+        .await
+        .expect_err("the failing supplied frame should fail the sequence");
+
+    assert_eq!(err.index(), 2, "the supplied frames follow the inner frame");
+    assert_eq!(*log.lock().unwrap(), vec!["inner", "first", "second"]);
+}
+
+#[tokio::test]
+async fn with_parallel_runs_every_frame() {
+    let log = ExecutionLog::default();
+    let frame = TaskFrameBuilder::new(labeled("inner", &log, false))
+        .with_parallel(vec![erased("first", &log, false), erased("second", &log, false)])
+        .build();
+
+    Task::new(frame, TaskScheduleImmediate).into_erased().run().await.unwrap();
+
+    let mut executed = log.lock().unwrap().clone();
+    executed.sort_unstable();
+    assert_eq!(executed, vec!["first", "inner", "second"]);
+}
+
+#[tokio::test]
+async fn with_parallel_places_inner_frame_at_index_zero() {
+    let log = ExecutionLog::default();
+    let frame = TaskFrameBuilder::new(labeled("inner", &log, true))
+        .with_parallel(vec![erased("first", &log, false)])
+        .build();
+
+    let err = Task::new(frame, TaskScheduleImmediate)
+        .into_erased()
+        .run()
+        .await
+        .expect_err("the failing inner frame should fail the collection");
+
+    assert_eq!(err.index(), 0);
+}
+
+#[tokio::test]
+async fn with_parallel_policy_uses_the_supplied_policy() {
+    let log = ExecutionLog::default();
+    let frame = TaskFrameBuilder::new(labeled("inner", &log, true))
+        .with_parallel_policy(
+            vec![erased("first", &log, false), erased("second", &log, true)],
+            GroupedTaskFramesAggregate,
+        )
+        .build();
+
+    let err = Task::new(frame, TaskScheduleImmediate)
+        .into_erased()
+        .run()
+        .await
+        .expect_err("the failing frames should fail the collection");
+
+    let aggregate = err.inner().as_any().downcast_ref::<AggregateError>()
+        .expect("error should be AggregateError");
+    assert_eq!(aggregate.indices().collect::<Vec<_>>(), vec![0, 2]);
+    assert_eq!(log.lock().unwrap().len(), 3, "every frame should have run");
+}
+
+#[tokio::test]
+async fn with_select_indexes_inner_frame_first() {
+    for (index, expected) in [(0, "inner"), (1, "first"), (2, "second")] {
+        let log = ExecutionLog::default();
+        let frame = TaskFrameBuilder::new(labeled("inner", &log, false))
+            .with_select(
+                vec![erased("first", &log, false), erased("second", &log, false)],
+                move |_ctx: &RestrictTaskFrameContext| async move { index },
+            )
+            .build();
+
+        Task::new(frame, TaskScheduleImmediate).into_erased().run().await.unwrap();
+        assert_eq!(*log.lock().unwrap(), vec![expected]);
+    }
+}
+
+#[tokio::test]
+async fn with_select_out_of_bounds_returns_error() {
+    let log = ExecutionLog::default();
+    let frame = TaskFrameBuilder::new(labeled("inner", &log, false))
+        .with_select(vec![erased("first", &log, false)], |_ctx: &RestrictTaskFrameContext| async { 2 })
+        .build();
+
+    let err = Task::new(frame, TaskScheduleImmediate)
+        .into_erased()
+        .run()
+        .await
+        .expect_err("selection should fail when the index is out of bounds");
+
+    assert_eq!(err.index(), 2);
+    assert!(log.lock().unwrap().is_empty());
+}
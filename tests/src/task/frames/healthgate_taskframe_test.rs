@@ -0,0 +1,68 @@
+use crate::task::frames::CountingFrame;
+use chronographer::errors::GateOnHealthTaskFrameError;
+use chronographer::task::hooks::RollingOutcomeHook;
+use chronographer::task::hooks::events::OnHealthGateOpen;
+use chronographer::task::{GateOnHealthTaskFrame, Task, TaskScheduleImmediate};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime};
+
+fn counting(counter: &Arc<AtomicUsize>) -> CountingFrame {
+    CountingFrame { counter: counter.clone(), should_fail: false }
+}
+
+#[tokio::test]
+async fn healthy_metric_runs_the_frame() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let outcomes = Arc::new(RollingOutcomeHook::default());
+    for success in [true, true, true, false] {
+        outcomes.record("ingest", SystemTime::now(), success);
+    }
+
+    let frame = GateOnHealthTaskFrame::new(counting(&counter), outcomes, "ingest", 0.75);
+    assert_eq!(frame.health(), Some(0.75));
+
+    assert!(Task::new(frame, TaskScheduleImmediate).into_erased().run().await.is_ok());
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn unhealthy_metric_short_circuits() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let outcomes = Arc::new(RollingOutcomeHook::default());
+    outcomes.record("ingest", SystemTime::now(), true);
+    outcomes.record("ingest", SystemTime::now(), false);
+
+    let frame = GateOnHealthTaskFrame::new(counting(&counter), outcomes, "ingest", 0.9);
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+    let opened = task.next_emission::<OnHealthGateOpen>();
+
+    match task.run().await {
+        Err(GateOnHealthTaskFrameError::HealthGateOpen { metric, health, threshold }) => {
+            assert_eq!(metric, "ingest");
+            assert_eq!(health, 0.5);
+            assert_eq!(threshold, 0.9);
+        }
+        other => panic!("expected the gate to be open, got {other:?}"),
+    }
+
+    let emitted = tokio::time::timeout(Duration::from_secs(1), opened)
+        .await
+        .expect("OnHealthGateOpen should have been emitted");
+    assert_eq!(emitted, 0.5);
+    assert_eq!(counter.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn unknown_metric_and_custom_sources() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let unknown = GateOnHealthTaskFrame::new(counting(&counter), Arc::new(RollingOutcomeHook::default()), "ingest", 0.9);
+    assert!(Task::new(unknown, TaskScheduleImmediate).into_erased().run().await.is_ok());
+
+    let source = Arc::new(|metric: &str| (metric == "replica lag").then_some(0.2));
+    let custom = GateOnHealthTaskFrame::new(counting(&counter), source, "replica lag", 0.5);
+    let result = Task::new(custom, TaskScheduleImmediate).into_erased().run().await;
+
+    assert!(matches!(result, Err(GateOnHealthTaskFrameError::HealthGateOpen { .. })));
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+}
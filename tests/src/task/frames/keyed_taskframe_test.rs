@@ -0,0 +1,68 @@
+use chronographer::task::{KeyedLocks, KeyedSerializeTaskFrame, Task, TaskFrame, TaskFrameContext, TaskScheduleImmediate};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+#[derive(Clone, Default)]
+struct ConcurrencyProbe {
+    active: Arc<AtomicUsize>,
+    peak: Arc<AtomicUsize>,
+}
+
+impl TaskFrame for ConcurrencyProbe {
+    type Error = String;
+    type Args = ();
+    type Workflow = Self;
+
+    async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+        let active = self.active.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(active, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        self.active.fetch_sub(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+fn keyed(probe: &ConcurrencyProbe, locks: &KeyedLocks, key: &'static str) -> KeyedSerializeTaskFrame<ConcurrencyProbe> {
+    KeyedSerializeTaskFrame::new(probe.clone(), move |_| Some(key.to_owned())).with_locks(locks.clone())
+}
+
+#[tokio::test(start_paused = true)]
+async fn same_key_runs_serially() {
+    let probe = ConcurrencyProbe::default();
+    let locks = KeyedLocks::new();
+
+    let first = Task::new(keyed(&probe, &locks, "account-1"), TaskScheduleImmediate).into_erased();
+    let second = Task::new(keyed(&probe, &locks, "account-1"), TaskScheduleImmediate).into_erased();
+
+    let (a, b) = tokio::join!(first.run(), second.run());
+    assert!(a.is_ok() && b.is_ok());
+    assert_eq!(probe.peak.load(Ordering::SeqCst), 1);
+    assert!(locks.is_empty(), "the key should be cleaned up once unused");
+}
+
+#[tokio::test(start_paused = true)]
+async fn different_keys_run_concurrently() {
+    let probe = ConcurrencyProbe::default();
+    let locks = KeyedLocks::new();
+
+    let first = Task::new(keyed(&probe, &locks, "account-1"), TaskScheduleImmediate).into_erased();
+    let second = Task::new(keyed(&probe, &locks, "account-2"), TaskScheduleImmediate).into_erased();
+
+    let (a, b) = tokio::join!(first.run(), second.run());
+    assert!(a.is_ok() && b.is_ok());
+    assert_eq!(probe.peak.load(Ordering::SeqCst), 2);
+    assert!(locks.is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn cancelled_execution_releases_its_key() {
+    let probe = ConcurrencyProbe::default();
+    let locks = KeyedLocks::new();
+    let task = Task::new(keyed(&probe, &locks, "account-1"), TaskScheduleImmediate).into_erased();
+
+    let cancelled = tokio::time::timeout(Duration::from_millis(10), task.run()).await;
+    assert!(cancelled.is_err());
+    assert!(locks.is_empty(), "a dropped execution shouldn't leave its key behind");
+    assert!(!locks.is_locked("account-1"));
+}
@@ -0,0 +1,61 @@
+use crate::task::frames::CountingFrame;
+use chronographer::errors::ChronographerErrors;
+use chronographer::prelude::DynamicTaskFrame;
+use chronographer::task::hooks::events::OnKillSwitchTripped;
+use chronographer::task::{KillSwitchTaskFrame, KillSwitches, Task, TaskFrameContext, TaskScheduleImmediate};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+#[tokio::test]
+async fn tripped_switch_skips_the_frame() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let frame = KillSwitchTaskFrame::builder()
+        .frame(CountingFrame { counter: counter.clone(), should_fail: false })
+        .switch("killswitch_test_skip")
+        .build();
+
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+    assert!(task.run().await.is_ok());
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+
+    KillSwitches::set("killswitch_test_skip", true);
+    assert!(KillSwitches::is_tripped("killswitch_test_skip"));
+
+    let tripped = task.next_emission::<OnKillSwitchTripped>();
+    assert!(task.run().await.is_ok(), "a tripped switch should succeed without running by default");
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+
+    let name = tokio::time::timeout(Duration::from_secs(1), tripped)
+        .await
+        .expect("OnKillSwitchTripped should have been emitted");
+    assert_eq!(&*name, "killswitch_test_skip");
+
+    KillSwitches::set("killswitch_test_skip", false);
+    assert!(task.run().await.is_ok());
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn tripped_switch_fails_with_supplied_error() {
+    KillSwitches::set("killswitch_test_error", true);
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let runs = counter.clone();
+    let frame = KillSwitchTaskFrame::builder()
+        .frame(DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+            let runs = runs.clone();
+            async move {
+                runs.fetch_add(1, Ordering::SeqCst);
+                Ok::<_, ChronographerErrors>(())
+            }
+        }))
+        .switch("killswitch_test_error")
+        .on_tripped(|| ChronographerErrors::Disabled)
+        .build();
+
+    let result = Task::new(frame, TaskScheduleImmediate).into_erased().run().await;
+
+    assert_eq!(result.unwrap_err(), ChronographerErrors::Disabled);
+    assert_eq!(counter.load(Ordering::SeqCst), 0);
+}
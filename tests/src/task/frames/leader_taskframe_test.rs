@@ -0,0 +1,44 @@
+use crate::task::frames::CountingFrame;
+use chronographer::task::hooks::events::OnLeadershipSkipped;
+use chronographer::task::{LeaderOnlyTaskFrame, Task, TaskScheduleImmediate};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+
+#[tokio::test]
+async fn runs_by_default_as_single_node_leader() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let frame = LeaderOnlyTaskFrame::new(CountingFrame { counter: counter.clone(), should_fail: false });
+
+    Task::new(frame, TaskScheduleImmediate).into_erased().run().await.unwrap();
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn skips_without_leadership() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let leader = Arc::new(AtomicBool::new(false));
+
+    let flag = leader.clone();
+    let elector = Arc::new(move || {
+        let flag = flag.clone();
+        async move { flag.load(Ordering::SeqCst) }
+    });
+
+    let frame = LeaderOnlyTaskFrame::new_with(
+        CountingFrame { counter: counter.clone(), should_fail: true },
+        elector,
+    );
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+
+    let skipped = task.next_emission::<OnLeadershipSkipped>();
+    assert!(task.run().await.is_ok(), "a follower should succeed without running");
+    assert_eq!(counter.load(Ordering::SeqCst), 0);
+    tokio::time::timeout(Duration::from_secs(1), skipped)
+        .await
+        .expect("OnLeadershipSkipped should have been emitted");
+
+    leader.store(true, Ordering::SeqCst);
+    assert!(task.run().await.is_err(), "the leader should run the inner frame");
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+}
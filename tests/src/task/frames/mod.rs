@@ -2,16 +2,36 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use chronographer::task::{ErasedTaskFrame, TaskFrame, TaskFrameContext};
 
+mod audit_taskframe_test;
+mod blocking_taskframe_test;
+mod chunked_taskframe_test;
+mod coalesce_taskframe_test;
 mod collectionframe_test;
 mod condition_taskframe_test;
+mod deadline_taskframe_test;
 mod delay_taskframe_test;
+mod describe_taskframe_test;
 mod dependency_taskframe_test;
 mod dynamic_taskframe_test;
 mod fallback_taskframe_test;
+mod frame_builder_test;
+mod healthgate_taskframe_test;
+mod finalizer_taskframe_test;
+mod keyed_taskframe_test;
+mod killswitch_taskframe_test;
+mod leader_taskframe_test;
 mod noop_operation_taskframe_test;
+mod ordered_taskframe_test;
+mod precondition_taskframe_test;
+mod resilience_taskframe_test;
 mod threshold_taskframe_test;
 mod timeout_taskframe_test;
+mod trace_taskframe_test;
 mod retry_taskframe_test;
+mod rollout_taskframe_test;
+mod shedding_taskframe_test;
+mod switch_taskframe_test;
+mod tagbudget_taskframe_test;
 
 fn ok_frame(
     counter: &Arc<AtomicUsize>,
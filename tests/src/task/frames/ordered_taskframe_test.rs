@@ -0,0 +1,73 @@
+use chronographer::prelude::*;
+use chronographer::task::{ErasedTask, TaskFrameContext, TaskScheduleImmediate};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+const DISPATCHES: usize = 5;
+
+fn ordered_task(started: Arc<AtomicUsize>) -> Arc<ErasedTask<String>> {
+    let inner = DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+        let started = started.clone();
+        async move {
+            /*
+                Earlier executions sleep the longest, without ordering the completion
+                order would be the reverse of the dispatch order
+            */
+            let idx = started.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(((DISPATCHES - idx) * 15) as u64)).await;
+            Ok::<_, String>(())
+        }
+    });
+
+    Arc::new(Task::new(OrderedTaskFrame::new(inner), TaskScheduleImmediate).into_erased())
+}
+
+#[tokio::test]
+async fn test_completion_order_matches_dispatch_order() {
+    let started = Arc::new(AtomicUsize::new(0));
+    let task = ordered_task(started.clone());
+    let completed = Arc::new(Mutex::new(Vec::new()));
+
+    let mut handles = Vec::new();
+    for i in 0..DISPATCHES {
+        let task = task.clone();
+        let completed = completed.clone();
+        handles.push(tokio::spawn(async move {
+            task.run().await.unwrap();
+            completed.lock().unwrap().push(i);
+        }));
+
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+
+    for handle in handles {
+        handle.await.unwrap();
+    }
+
+    assert_eq!(started.load(Ordering::SeqCst), DISPATCHES);
+    assert_eq!(*completed.lock().unwrap(), (0..DISPATCHES).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn test_dropped_waiter_does_not_block_successors() {
+    let started = Arc::new(AtomicUsize::new(0));
+    let task = ordered_task(started.clone());
+
+    let first = tokio::spawn({
+        let task = task.clone();
+        async move { task.run().await }
+    });
+    tokio::time::sleep(Duration::from_millis(5)).await;
+
+    let abandoned = tokio::time::timeout(Duration::from_millis(5), task.run()).await;
+    assert!(abandoned.is_err());
+
+    tokio::time::timeout(Duration::from_secs(1), task.run())
+        .await
+        .expect("the execution after an abandoned ticket should still run")
+        .unwrap();
+
+    first.await.unwrap().unwrap();
+    assert_eq!(started.load(Ordering::SeqCst), 2);
+}
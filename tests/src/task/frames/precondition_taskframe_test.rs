@@ -0,0 +1,62 @@
+use crate::task::frames::CountingFrame;
+use chronographer::errors::PreconditionTaskFrameError;
+use chronographer::task::hooks::events::OnPreconditionFailed;
+use chronographer::task::{PreconditionTaskFrame, RestrictTaskFrameContext, Task, TaskScheduleImmediate};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+#[tokio::test]
+async fn met_preconditions_run_the_frame() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let frame = PreconditionTaskFrame::new(CountingFrame { counter: counter.clone(), should_fail: false })
+        .precondition("has owner", |ctx: &RestrictTaskFrameContext| {
+            let owned = !ctx.metadata().owner.is_empty();
+            async move { owned }
+        });
+
+    let task = Task::new(frame, TaskScheduleImmediate).with_owner("ops").into_erased();
+
+    assert!(task.run().await.is_ok());
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn failed_preconditions_are_named_and_skip_the_frame() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let frame = PreconditionTaskFrame::new(CountingFrame { counter: counter.clone(), should_fail: false })
+        .precondition("always", |_ctx: &RestrictTaskFrameContext| async { true })
+        .precondition("warehouse reachable", |_ctx: &RestrictTaskFrameContext| async { false })
+        .precondition("has owner", |ctx: &RestrictTaskFrameContext| {
+            let owned = !ctx.metadata().owner.is_empty();
+            async move { owned }
+        });
+
+    assert_eq!(frame.names(), vec!["always", "warehouse reachable", "has owner"]);
+
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+    let failure = task.next_emission::<OnPreconditionFailed>();
+
+    match task.run().await {
+        Err(PreconditionTaskFrameError::PreconditionFailed(failed)) => {
+            assert_eq!(failed, vec!["warehouse reachable", "has owner"]);
+        }
+        other => panic!("expected failed preconditions, got {other:?}"),
+    }
+
+    let first = tokio::time::timeout(Duration::from_secs(1), failure)
+        .await
+        .expect("OnPreconditionFailed should have been emitted");
+    assert_eq!(first, "warehouse reachable");
+    assert_eq!(counter.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn inner_errors_are_wrapped() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let frame = PreconditionTaskFrame::new(CountingFrame { counter: counter.clone(), should_fail: true });
+
+    let result = Task::new(frame, TaskScheduleImmediate).into_erased().run().await;
+
+    assert!(matches!(result, Err(PreconditionTaskFrameError::Inner(_))));
+}
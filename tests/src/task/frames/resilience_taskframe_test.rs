@@ -0,0 +1,79 @@
+use crate::task::frames::CountingFrame;
+use chronographer::task::{
+    ResilienceTaskFrame, Task, TaskFrame, TaskFrameContext, TaskScheduleImmediate,
+};
+use std::num::{NonZeroU32, NonZeroUsize};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct DegradedFrame {
+    counter: Arc<AtomicUsize>,
+    should_fail: bool,
+}
+
+impl TaskFrame for DegradedFrame {
+    type Error = String;
+    type Args = String;
+    type Workflow = Self;
+
+    async fn execute(&self, _ctx: &TaskFrameContext, args: &Self::Args) -> Result<(), Self::Error> {
+        self.counter.fetch_add(1, Ordering::SeqCst);
+        if self.should_fail {
+            return Err(format!("degraded after: {args}"));
+        }
+
+        Ok(())
+    }
+}
+
+fn resilience(
+    primary: &Arc<AtomicUsize>,
+    degraded: &Arc<AtomicUsize>,
+    degraded_fails: bool,
+) -> ResilienceTaskFrame<CountingFrame, DegradedFrame> {
+    ResilienceTaskFrame::builder()
+        .primary(CountingFrame { counter: primary.clone(), should_fail: true })
+        .retries(NonZeroU32::new(2).unwrap())
+        .degraded(DegradedFrame { counter: degraded.clone(), should_fail: degraded_fails })
+        .failure_threshold(NonZeroUsize::new(2).unwrap())
+        .on_open(|| "circuit open".to_owned())
+        .build()
+}
+
+#[tokio::test]
+async fn retries_then_falls_back_to_degraded() {
+    let primary = Arc::new(AtomicUsize::new(0));
+    let degraded = Arc::new(AtomicUsize::new(0));
+
+    let task = Task::new(resilience(&primary, &degraded, false), TaskScheduleImmediate).into_erased();
+    assert!(task.run().await.is_ok());
+    assert_eq!(primary.load(Ordering::SeqCst), 3, "the first attempt plus two retries");
+    assert_eq!(degraded.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn opens_the_circuit_once_degraded_keeps_failing() {
+    let primary = Arc::new(AtomicUsize::new(0));
+    let degraded = Arc::new(AtomicUsize::new(0));
+
+    let task = Task::new(resilience(&primary, &degraded, true), TaskScheduleImmediate).into_erased();
+    for _ in 0..2 {
+        let err = task.run().await.expect_err("both the primary and degraded frames fail");
+        assert_eq!(err, "degraded after: TaskFrame Failed");
+    }
+
+    let err = task.run().await.expect_err("the circuit should be open");
+    assert_eq!(err, "circuit open");
+    assert_eq!(primary.load(Ordering::SeqCst), 6);
+    assert_eq!(degraded.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn describes_the_generated_chain() {
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    assert_eq!(
+        resilience(&counter, &counter, false).describe().to_string(),
+        "Resilience(Threshold(Fallback(Retry(CountingFrame), DegradedFrame)))"
+    );
+}
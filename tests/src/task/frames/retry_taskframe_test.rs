@@ -1,7 +1,8 @@
 use chronographer::task::{
     ConstantBackoffStrategy, ExponentialBackoffStrategy, JitterBackoffStrategy,
-    LinearBackoffStrategy, RetriableTaskFrame, Task, TaskFrame,
-    TaskFrameContext, TaskScheduleImmediate,
+    LinearBackoffStrategy, OnRetryBudgetExhausted, RetriableTaskFrame, RetryBudgetSnapshot,
+    SharedRetryBudget, StagedBackoffStrategy, Task, TaskFrame, TaskFrameContext, TaskScheduleImmediate,
+    TimeoutTaskFrame,
 };
 use std::num::NonZeroU32;
 use std::sync::Arc;
@@ -426,3 +427,289 @@ async fn jitter_decorrelated_delay_within_max() {
 
     assert!(handle.await.unwrap().is_err());
 }
+
+#[test]
+fn jitter_seeded_source_is_reproducible() {
+    use chronographer::task::RetryBackoffStrategy;
+    use chronographer::utils::SeededRandomSource;
+
+    let first = JitterBackoffStrategy::new_full(ConstantBackoffStrategy::new(Duration::from_secs(10)), 1.0)
+        .with_random_source(Box::leak(Box::new(SeededRandomSource::new(42))));
+    let second = JitterBackoffStrategy::new_full(ConstantBackoffStrategy::new(Duration::from_secs(10)), 1.0)
+        .with_random_source(Box::leak(Box::new(SeededRandomSource::new(42))));
+
+    for retry in 0..8 {
+        let delay = first.compute(retry);
+        assert_eq!(delay, second.compute(retry));
+        assert!(delay <= Duration::from_secs(10));
+    }
+}
+
+struct HangNTimesFrame {
+    counter: Arc<AtomicUsize>,
+    hang_times: usize,
+}
+
+impl TaskFrame for HangNTimesFrame {
+    type Error = String;
+    type Args = ();
+    type Workflow = Self;
+
+    async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+        let attempt = self.counter.fetch_add(1, Ordering::SeqCst);
+        if attempt < self.hang_times {
+            std::future::pending::<()>().await;
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn per_attempt_timeout_retries_hanging_attempt() {
+    tokio::time::pause();
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    let frame = RetriableTaskFrame::builder()
+        .frame(HangNTimesFrame { counter: counter.clone(), hang_times: 1 })
+        .retries(NonZeroU32::new(2).unwrap())
+        .constant(Duration::ZERO)
+        .per_attempt_timeout(Duration::from_millis(100), || "attempt timed out".to_string())
+        .build();
+
+    let result = tokio::time::timeout(
+        Duration::from_secs(1),
+        Task::new(frame, TaskScheduleImmediate).into_erased().run(),
+    )
+    .await
+    .expect("hanging attempt should have been timed out");
+
+    assert!(result.is_ok(), "should succeed on the attempt after the timeout");
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn per_attempt_timeout_exhausts_retries() {
+    tokio::time::pause();
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    let frame = RetriableTaskFrame::builder()
+        .frame(HangNTimesFrame { counter: counter.clone(), hang_times: usize::MAX })
+        .retries(NonZeroU32::new(2).unwrap())
+        .constant(Duration::ZERO)
+        .per_attempt_timeout(Duration::from_millis(100), || "attempt timed out".to_string())
+        .build();
+
+    let result = Task::new(frame, TaskScheduleImmediate).into_erased().run().await;
+
+    assert!(result.is_err(), "every attempt timing out should return the error");
+    assert_eq!(counter.load(Ordering::SeqCst), 3);
+}
+
+fn budgeted_frame(counter: &Arc<AtomicUsize>, budget: &SharedRetryBudget) -> RetriableTaskFrame<FailNTimesFrame> {
+    RetriableTaskFrame::builder()
+        .frame(FailNTimesFrame { counter: counter.clone(), fail_times: usize::MAX })
+        .retries(NonZeroU32::new(3).unwrap())
+        .constant(Duration::ZERO)
+        .budget(budget.clone())
+        .build()
+}
+
+#[tokio::test]
+async fn retry_budget_persists_across_runs() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let budget = SharedRetryBudget::new(5);
+    let task = Task::new(budgeted_frame(&counter, &budget), TaskScheduleImmediate).into_erased();
+
+    let exhausted = task.next_emission::<OnRetryBudgetExhausted>();
+    assert!(task.run().await.is_err());
+    assert_eq!(counter.load(Ordering::SeqCst), 4, "the first run retries in full");
+    assert_eq!(budget.remaining(), 2);
+
+    assert!(task.run().await.is_err());
+    assert_eq!(counter.load(Ordering::SeqCst), 7, "the second run only retries with what is left");
+    assert!(budget.is_exhausted());
+
+    let snapshot = tokio::time::timeout(Duration::from_secs(1), exhausted)
+        .await
+        .expect("OnRetryBudgetExhausted should have been emitted");
+    assert_eq!(snapshot, RetryBudgetSnapshot { total: 5, consumed: 5 });
+
+    assert!(task.run().await.is_err());
+    assert_eq!(counter.load(Ordering::SeqCst), 8, "an exhausted budget fails fast");
+}
+
+#[tokio::test]
+async fn retry_budget_shared_between_frames() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let budget = SharedRetryBudget::new(4);
+
+    let first = Task::new(budgeted_frame(&counter, &budget), TaskScheduleImmediate).into_erased();
+    let second = Task::new(budgeted_frame(&counter, &budget), TaskScheduleImmediate).into_erased();
+
+    assert!(first.run().await.is_err());
+    assert!(second.run().await.is_err());
+    assert_eq!(counter.load(Ordering::SeqCst), 4 + 2);
+    assert!(budget.is_exhausted());
+}
+
+#[test]
+fn retry_budget_round_trips_through_serde() {
+    let budget = SharedRetryBudget::new(10);
+    assert!(budget.try_consume());
+    assert!(budget.try_consume());
+
+    let encoded = toml::to_string(&budget).unwrap();
+    let decoded: SharedRetryBudget = toml::from_str(&encoded).unwrap();
+
+    assert_eq!(decoded.snapshot(), RetryBudgetSnapshot { total: 10, consumed: 2 });
+    assert_eq!(decoded.remaining(), 8);
+}
+
+#[test]
+fn staged_backoff_switches_at_threshold() {
+    use chronographer::task::RetryBackoffStrategy;
+
+    let staged = StagedBackoffStrategy::default()
+        .then(0, ConstantBackoffStrategy::new(Duration::ZERO))
+        .then(3, ExponentialBackoffStrategy::new(2.0));
+
+    assert_eq!(staged.thresholds(), vec![0, 3]);
+    for retry in 0..3 {
+        assert_eq!(staged.compute(retry), Duration::ZERO, "retry {retry} should be instant");
+    }
+
+    assert_eq!(staged.compute(3), Duration::from_secs(1));
+    assert_eq!(staged.compute(4), Duration::from_secs(2));
+    assert_eq!(staged.compute(5), Duration::from_secs(4));
+}
+
+#[test]
+fn staged_backoff_without_early_stage_is_instant() {
+    use chronographer::task::RetryBackoffStrategy;
+
+    let constant: Arc<dyn RetryBackoffStrategy> = Arc::new(ConstantBackoffStrategy::new(Duration::from_secs(5)));
+    let staged = StagedBackoffStrategy::new(vec![(2, constant)]);
+
+    assert_eq!(staged.compute(0), Duration::ZERO);
+    assert_eq!(staged.compute(1), Duration::ZERO);
+    assert_eq!(staged.compute(2), Duration::from_secs(5));
+    assert_eq!(staged.compute(10), Duration::from_secs(5));
+}
+
+struct SingleUseFrame {
+    body: std::sync::Mutex<Option<String>>,
+    built: Arc<AtomicUsize>,
+    fail_times: usize,
+}
+
+impl TaskFrame for SingleUseFrame {
+    type Error = String;
+    type Args = ();
+    type Workflow = Self;
+
+    async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+        self.body.lock().unwrap().take().ok_or("body already consumed")?;
+        if self.built.load(Ordering::SeqCst) <= self.fail_times {
+            return Err("frame failed".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn retry_from_factory_builds_fresh_frame_per_attempt() {
+    let built = Arc::new(AtomicUsize::new(0));
+    let counter = built.clone();
+
+    let frame = RetriableTaskFrame::from_factory(
+        move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            SingleUseFrame {
+                body: std::sync::Mutex::new(Some("payload".to_string())),
+                built: counter.clone(),
+                fail_times: 2,
+            }
+        },
+        NonZeroU32::new(3).unwrap(),
+        ConstantBackoffStrategy::new(Duration::ZERO),
+    );
+
+    let result = Task::new(frame, TaskScheduleImmediate).into_erased().run().await;
+
+    assert!(result.is_ok(), "every retry should start with an unconsumed body");
+    assert_eq!(built.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn retry_from_factory_exhausts_retries() {
+    let built = Arc::new(AtomicUsize::new(0));
+    let counter = built.clone();
+
+    let frame = RetriableTaskFrame::from_factory(
+        move || FailNTimesFrame { counter: counter.clone(), fail_times: usize::MAX },
+        NonZeroU32::new(2).unwrap(),
+        ConstantBackoffStrategy::new(Duration::ZERO),
+    );
+
+    let result = Task::new(frame, TaskScheduleImmediate).into_erased().run().await;
+
+    assert!(result.is_err());
+    assert_eq!(built.load(Ordering::SeqCst), 3);
+}
+
+fn deadline_bound_frame(counter: &Arc<AtomicUsize>, respect_deadline: bool) -> TimeoutTaskFrame<RetriableTaskFrame<FailNTimesFrame>> {
+    let retry = RetriableTaskFrame::builder()
+        .frame(FailNTimesFrame { counter: counter.clone(), fail_times: usize::MAX })
+        .retries(NonZeroU32::new(10).unwrap())
+        .constant(Duration::from_millis(100))
+        .respect_deadline(respect_deadline)
+        .build();
+
+    TimeoutTaskFrame::builder()
+        .frame(retry)
+        .duration(Duration::from_millis(250))
+        .build()
+}
+
+#[tokio::test(start_paused = true)]
+async fn retry_respecting_deadline_stops_before_overshooting() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let task = Task::new(deadline_bound_frame(&counter, true), TaskScheduleImmediate).into_erased();
+
+    let error = task.run().await.unwrap_err();
+
+    assert_eq!(error, "frame failed", "the last attempt's error should be returned, not a timeout");
+    assert_eq!(counter.load(Ordering::SeqCst), 3, "attempts at 0ms, 100ms and 200ms fit the deadline");
+}
+
+#[tokio::test(start_paused = true)]
+async fn retry_ignoring_deadline_is_cut_off_by_timeout() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let task = Task::new(deadline_bound_frame(&counter, false), TaskScheduleImmediate).into_erased();
+
+    let error = task.run().await.unwrap_err();
+
+    assert_eq!(error, "Timeout Occurred");
+    assert_eq!(counter.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn jitter_strategy_is_copy() {
+    use chronographer::task::RetryBackoffStrategy;
+
+    let strategy = JitterBackoffStrategy::new_equal(ConstantBackoffStrategy::new(Duration::from_secs(10)), 1.0);
+    let copied = strategy;
+
+    for retry in 0..8 {
+        assert!(strategy.compute(retry) >= Duration::from_secs(5));
+        assert!(copied.compute(retry) <= Duration::from_secs(10));
+    }
+}
+
+#[test]
+fn random_sources_clamp_zero_bound() {
+    use chronographer::utils::{RandomSource, SeededRandomSource, ThreadRandomSource};
+
+    assert_eq!(ThreadRandomSource.u64_below(0), 0);
+    assert_eq!(SeededRandomSource::new(7).u64_below(0), 0);
+}
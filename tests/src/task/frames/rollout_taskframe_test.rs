@@ -0,0 +1,75 @@
+use crate::task::frames::CountingFrame;
+use chronographer::task::{RolloutPercentage, RolloutTaskFrame, Task, TaskScheduleImmediate};
+use chronographer::utils::SeededRandomSource;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+fn counting(counter: &Arc<AtomicUsize>) -> CountingFrame {
+    CountingFrame {
+        counter: counter.clone(),
+        should_fail: false,
+    }
+}
+
+#[test]
+fn percentage_is_clamped() {
+    let percentage = RolloutPercentage::new(150.0);
+    assert_eq!(percentage.get(), 100.0);
+
+    percentage.set(-5.0);
+    assert_eq!(percentage.get(), 0.0);
+
+    percentage.set(f64::NAN);
+    assert_eq!(percentage.get(), 0.0);
+}
+
+#[tokio::test]
+async fn extremes_route_every_execution() {
+    let new = Arc::new(AtomicUsize::new(0));
+    let old = Arc::new(AtomicUsize::new(0));
+    let percentage = RolloutPercentage::new(0.0);
+
+    let frame = RolloutTaskFrame::builder()
+        .new(counting(&new))
+        .old(counting(&old))
+        .percentage(percentage.clone())
+        .build()
+        .with_random_source(SeededRandomSource::new(7));
+
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+    for _ in 0..10 {
+        assert!(task.run().await.is_ok());
+    }
+
+    assert_eq!((new.load(Ordering::SeqCst), old.load(Ordering::SeqCst)), (0, 10));
+
+    // Takes effect from the next execution, without rebuilding the frame
+    percentage.set(100.0);
+    for _ in 0..10 {
+        assert!(task.run().await.is_ok());
+    }
+
+    assert_eq!((new.load(Ordering::SeqCst), old.load(Ordering::SeqCst)), (10, 10));
+}
+
+#[tokio::test]
+async fn partial_rollout_splits_executions() {
+    let new = Arc::new(AtomicUsize::new(0));
+    let old = Arc::new(AtomicUsize::new(0));
+
+    let frame = RolloutTaskFrame::builder()
+        .new(counting(&new))
+        .old(counting(&old))
+        .percentage(RolloutPercentage::new(30.0))
+        .build()
+        .with_random_source(SeededRandomSource::new(42));
+
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+    for _ in 0..1000 {
+        assert!(task.run().await.is_ok());
+    }
+
+    let routed_new = new.load(Ordering::SeqCst);
+    assert_eq!(routed_new + old.load(Ordering::SeqCst), 1000);
+    assert!((200..400).contains(&routed_new), "routed {routed_new} out of 1000 executions to the new frame");
+}
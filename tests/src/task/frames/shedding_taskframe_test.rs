@@ -0,0 +1,59 @@
+use crate::task::frames::CountingFrame;
+use chronographer::task::{AdaptiveSheddingTaskFrame, Task, TaskScheduleImmediate};
+use chronographer::utils::SeededRandomSource;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+fn counting(counter: &Arc<AtomicUsize>, should_fail: bool) -> CountingFrame {
+    CountingFrame {
+        counter: counter.clone(),
+        should_fail,
+    }
+}
+
+#[tokio::test]
+async fn shedding_inactive_while_healthy() {
+    let primary = Arc::new(AtomicUsize::new(0));
+    let fallback = Arc::new(AtomicUsize::new(0));
+
+    let frame = AdaptiveSheddingTaskFrame::builder()
+        .frame(counting(&primary, false))
+        .fallback(counting(&fallback, false))
+        .window(NonZeroUsize::new(4).unwrap())
+        .build();
+
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+    for _ in 0..10 {
+        assert!(task.run().await.is_ok());
+    }
+
+    assert_eq!(primary.load(Ordering::SeqCst), 10);
+    assert_eq!(fallback.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn shedding_routes_to_fallback_when_failing() {
+    let primary = Arc::new(AtomicUsize::new(0));
+    let fallback = Arc::new(AtomicUsize::new(0));
+
+    let frame = AdaptiveSheddingTaskFrame::builder()
+        .frame(counting(&primary, true))
+        .fallback(counting(&fallback, false))
+        .window(NonZeroUsize::new(4).unwrap())
+        .max_shed_probability(1.0)
+        .build()
+        .with_random_source(SeededRandomSource::new(7));
+
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+    for _ in 0..4 {
+        assert!(task.run().await.is_err());
+    }
+
+    for _ in 0..6 {
+        assert!(task.run().await.is_ok());
+    }
+
+    assert_eq!(primary.load(Ordering::SeqCst), 4);
+    assert_eq!(fallback.load(Ordering::SeqCst), 6);
+}
@@ -0,0 +1,89 @@
+use crate::task::frames::CountingFrame;
+use chronographer::task::hooks::events::OnSwitchBranch;
+use chronographer::task::{SwitchKey, SwitchTaskFrame, Task, TaskFrame, TaskScheduleImmediate};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+fn counting(counter: &Arc<AtomicUsize>, should_fail: bool) -> CountingFrame {
+    CountingFrame {
+        counter: counter.clone(),
+        should_fail,
+    }
+}
+
+#[tokio::test]
+async fn executes_the_branch_matching_the_discriminant() {
+    let pending = Arc::new(AtomicUsize::new(0));
+    let shipped = Arc::new(AtomicUsize::new(0));
+    let fallback = Arc::new(AtomicUsize::new(0));
+    let status: Arc<Mutex<Option<SwitchKey>>> = Arc::new(Mutex::new(Some("shipped".into())));
+
+    let source = status.clone();
+    let frame = SwitchTaskFrame::new(move |_| source.lock().unwrap().clone(), counting(&fallback, false))
+        .case("pending", counting(&pending, false))
+        .case("shipped", counting(&shipped, false));
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+
+    let chosen = task.next_emission::<OnSwitchBranch>();
+    task.run().await.unwrap();
+    assert_eq!(shipped.load(Ordering::SeqCst), 1);
+    assert_eq!(pending.load(Ordering::SeqCst), 0);
+    assert_eq!(
+        tokio::time::timeout(Duration::from_secs(1), chosen).await.unwrap(),
+        Some(SwitchKey::from("shipped"))
+    );
+
+    *status.lock().unwrap() = Some("pending".into());
+    task.run().await.unwrap();
+    assert_eq!(pending.load(Ordering::SeqCst), 1);
+    assert_eq!(fallback.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn falls_back_to_the_default_branch() {
+    let matched = Arc::new(AtomicUsize::new(0));
+    let fallback = Arc::new(AtomicUsize::new(0));
+    let status: Arc<Mutex<Option<SwitchKey>>> = Arc::new(Mutex::new(Some(SwitchKey::from("1"))));
+
+    let source = status.clone();
+    let frame = SwitchTaskFrame::new(move |_| source.lock().unwrap().clone(), counting(&fallback, true))
+        .case(1, counting(&matched, false));
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+
+    let chosen = task.next_emission::<OnSwitchBranch>();
+    assert!(task.run().await.is_err(), "the default branch's error should be returned");
+    assert_eq!(
+        tokio::time::timeout(Duration::from_secs(1), chosen).await.unwrap(),
+        None,
+        "a string key shouldn't match an integer case"
+    );
+
+    *status.lock().unwrap() = None;
+    assert!(task.run().await.is_err());
+    assert_eq!(fallback.load(Ordering::SeqCst), 2);
+    assert_eq!(matched.load(Ordering::SeqCst), 0);
+
+    *status.lock().unwrap() = Some(SwitchKey::from(1));
+    task.run().await.unwrap();
+    assert_eq!(matched.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn later_case_replaces_earlier_one() {
+    let first = Arc::new(AtomicUsize::new(0));
+    let second = Arc::new(AtomicUsize::new(0));
+    let fallback = Arc::new(AtomicUsize::new(0));
+
+    let frame = SwitchTaskFrame::new(|_| Some("a".into()), counting(&fallback, false))
+        .case("a", counting(&first, false))
+        .case("b", counting(&first, false))
+        .case("a", counting(&second, false));
+
+    assert_eq!(frame.keys().cloned().collect::<Vec<_>>(), vec![SwitchKey::from("a"), SwitchKey::from("b")]);
+    assert_eq!(frame.describe().to_string(), "Switch(CountingFrame, CountingFrame, CountingFrame)");
+
+    Task::new(frame, TaskScheduleImmediate).into_erased().run().await.unwrap();
+    assert_eq!(first.load(Ordering::SeqCst), 0);
+    assert_eq!(second.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,75 @@
+use chronographer::task::{TagBudgetTaskFrame, TagBudgets, Task, TaskFrame, TaskFrameContext, TaskScheduleImmediate};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+#[derive(Clone, Default)]
+struct ConcurrencyProbe {
+    active: Arc<AtomicUsize>,
+    peak: Arc<AtomicUsize>,
+}
+
+impl TaskFrame for ConcurrencyProbe {
+    type Error = String;
+    type Args = ();
+    type Workflow = Self;
+
+    async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+        let active = self.active.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(active, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        self.active.fetch_sub(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn budget_caps_concurrency_across_tasks() {
+    TagBudgets::set("tag-budget-test-cap", 2);
+    let probe = ConcurrencyProbe::default();
+
+    let mut executions = tokio::task::JoinSet::new();
+    for _ in 0..5 {
+        let frame = TagBudgetTaskFrame::new(probe.clone(), ["tag-budget-test-cap"]);
+        let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+        executions.spawn(async move { task.run().await.is_ok() });
+    }
+
+    while let Some(succeeded) = executions.join_next().await {
+        assert!(succeeded.unwrap());
+    }
+
+    assert_eq!(probe.peak.load(Ordering::SeqCst), 2);
+    assert_eq!(TagBudgets::available("tag-budget-test-cap"), Some(2));
+}
+
+#[tokio::test(start_paused = true)]
+async fn unbudgeted_tags_are_unlimited() {
+    let probe = ConcurrencyProbe::default();
+    let first = Task::new(TagBudgetTaskFrame::new(probe.clone(), ["tag-budget-test-none"]), TaskScheduleImmediate).into_erased();
+    let second = Task::new(TagBudgetTaskFrame::new(probe.clone(), ["tag-budget-test-none"]), TaskScheduleImmediate).into_erased();
+
+    let (a, b) = tokio::join!(first.run(), second.run());
+    assert!(a.is_ok() && b.is_ok());
+    assert_eq!(probe.peak.load(Ordering::SeqCst), 2);
+    assert_eq!(TagBudgets::permits("tag-budget-test-none"), None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn multiple_tags_acquire_in_fixed_order() {
+    TagBudgets::set("tag-budget-test-a", 1);
+    TagBudgets::set("tag-budget-test-b", 1);
+    let probe = ConcurrencyProbe::default();
+
+    let forward = TagBudgetTaskFrame::new(probe.clone(), ["tag-budget-test-a", "tag-budget-test-b"]);
+    let backward = TagBudgetTaskFrame::new(probe.clone(), ["tag-budget-test-b", "tag-budget-test-a", "tag-budget-test-b"]);
+    assert_eq!(forward.tags(), backward.tags());
+
+    let first = Task::new(forward, TaskScheduleImmediate).into_erased();
+    let second = Task::new(backward, TaskScheduleImmediate).into_erased();
+
+    let joined = tokio::time::timeout(Duration::from_secs(1), async { tokio::join!(first.run(), second.run()) }).await;
+    let (a, b) = joined.expect("opposite tag orders shouldn't deadlock");
+    assert!(a.is_ok() && b.is_ok());
+    assert_eq!(probe.peak.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,84 @@
+use chronographer::task::{
+    FrameNode, Task, TaskFrame, TaskFrameContext, TaskScheduleImmediate, TraceContext, TraceContextTaskFrame,
+};
+use chronographer::utils::SeededRandomSource;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Default)]
+struct RecordingFrame(Arc<Mutex<Vec<Option<TraceContext>>>>);
+
+impl TaskFrame for RecordingFrame {
+    type Error = String;
+    type Args = ();
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+        self.0.lock().unwrap().push(ctx.trace_context());
+        Ok(())
+    }
+
+    fn describe(&self) -> FrameNode {
+        FrameNode::leaf("Recording")
+    }
+}
+
+#[tokio::test]
+async fn generates_a_fresh_trace_per_execution() {
+    let recording = RecordingFrame::default();
+    let task = Task::new(TraceContextTaskFrame::new(recording.clone()), TaskScheduleImmediate).into_erased();
+
+    task.run().await.unwrap();
+    task.run().await.unwrap();
+
+    let seen = recording.0.lock().unwrap().clone();
+    let (first, second) = (seen[0].unwrap(), seen[1].unwrap());
+    assert_eq!(first.parent_span_id(), None);
+    assert_ne!(first.trace_id(), second.trace_id());
+}
+
+#[tokio::test]
+async fn nested_frames_continue_the_outer_trace() {
+    let recording = RecordingFrame::default();
+    let frame = TraceContextTaskFrame::new(TraceContextTaskFrame::new(recording.clone()))
+        .with_random_source(SeededRandomSource::new(7));
+
+    Task::new(frame, TaskScheduleImmediate).into_erased().run().await.unwrap();
+
+    let inner = recording.0.lock().unwrap()[0].unwrap();
+    assert!(inner.parent_span_id().is_some());
+    assert_ne!(inner.parent_span_id(), Some(inner.span_id()));
+}
+
+#[tokio::test]
+async fn inherits_the_trace_from_the_input() {
+    let incoming = TraceContext::from_traceparent("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01").unwrap();
+    let recording = RecordingFrame::default();
+    let task = Task::new(TraceContextTaskFrame::new(recording.clone()), TaskScheduleImmediate)
+        .with_input(incoming)
+        .into_erased();
+
+    task.run().await.unwrap();
+
+    let trace = recording.0.lock().unwrap()[0].unwrap();
+    assert_eq!(trace.trace_id(), 0x4bf92f3577b34da6a3ce929d0e0e4736);
+    assert_eq!(trace.parent_span_id(), Some(0x00f067aa0ba902b7));
+    assert!(trace.traceparent().starts_with("00-4bf92f3577b34da6a3ce929d0e0e4736-"));
+}
+
+#[tokio::test]
+async fn absent_outside_the_frame() {
+    let recording = RecordingFrame::default();
+    Task::new(recording.clone(), TaskScheduleImmediate).into_erased().run().await.unwrap();
+
+    assert_eq!(recording.0.lock().unwrap()[0], None);
+}
+
+#[test]
+fn traceparent_round_trips() {
+    let value = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+    assert_eq!(TraceContext::from_traceparent(value).unwrap().to_string(), value);
+
+    assert!(TraceContext::from_traceparent("00-00000000000000000000000000000000-00f067aa0ba902b7-01").is_none());
+    assert!(TraceContext::from_traceparent("00-4bf92f3577b34da6a3ce929d0e0e4736-01").is_none());
+    assert!(TraceContext::from_traceparent("01-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01").is_none());
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use chronographer::prelude::*;
+use chronographer::task::{ErasedTask, TaskFrameContext, TaskScheduleImmediate};
+
+fn alternating_task(history: usize) -> ErasedTask<String> {
+    let runs = Arc::new(AtomicUsize::new(0));
+    Task::new(
+        DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+            let run = runs.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if run % 2 == 1 {
+                    return Err(format!("run {run} failed"));
+                }
+
+                Ok(())
+            }
+        }),
+        TaskScheduleImmediate,
+    )
+    .with_history(history)
+    .into_erased()
+}
+
+#[tokio::test]
+async fn test_history_records_outcomes() {
+    let task = alternating_task(5);
+    let _ = task.run().await;
+    let _ = task.run().await;
+
+    let history = task.history();
+    assert_eq!(history.len(), 2);
+    assert!(history[0].is_success());
+    assert_eq!(history[1].error.as_deref(), Some("\"run 1 failed\""));
+    assert!(history[0].started_at <= history[1].started_at);
+}
+
+#[tokio::test]
+async fn test_history_keeps_most_recent() {
+    let task = alternating_task(3);
+    for _ in 0..7 {
+        let _ = task.run().await;
+    }
+
+    let outcomes: Vec<bool> = task.history().iter().map(|record| record.is_success()).collect();
+    assert_eq!(outcomes, vec![true, false, true]);
+}
+
+#[tokio::test]
+async fn test_history_disabled_by_default() {
+    let task = alternating_task(0);
+    let _ = task.run().await;
+
+    assert!(task.history().is_empty());
+}
@@ -1,2 +1,8 @@
 mod taskhook_shared_data_test;
 mod taskhook_test;
+mod taskhook_next_emission_test;
+mod taskhook_mismatch_test;
+mod taskhook_define_event_test;
+mod taskhook_forwarding_test;
+mod taskhook_rolling_outcome_test;
+mod taskhook_duration_ema_test;
@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+
+use chronographer::prelude::*;
+use chronographer::task::{TaskFrame, TaskHookContext, TaskScheduleImmediate};
+
+define_hook_event!(
+    /// A user-defined event carrying a borrowed payload
+    OnCacheEviction, &'a str
+);
+
+define_hook_event!(OnBatchFlushed, usize);
+
+#[derive(Default)]
+struct EvictionRecorder(Mutex<Vec<String>>);
+
+#[async_trait]
+impl TaskHook<OnCacheEviction> for EvictionRecorder {
+    async fn on_event(&self, _ctx: &TaskHookContext, payload: &<OnCacheEviction as TaskHookEvent>::Payload<'_>) {
+        self.0.lock().unwrap().push(payload.to_string());
+    }
+}
+
+fn task() -> Task<impl TaskFrame<Args = (), Error = String>> {
+    Task::new(
+        DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }),
+        TaskScheduleImmediate,
+    )
+}
+
+#[tokio::test]
+async fn test_defined_event_with_borrowed_payload() {
+    let task = task();
+    let recorder = Arc::new(EvictionRecorder::default());
+    task.attach_hook::<OnCacheEviction>(recorder.clone()).await;
+
+    let key = String::from("session:42");
+    task.emit_hook_event::<OnCacheEviction>(&key.as_str()).await;
+
+    assert_eq!(*recorder.0.lock().unwrap(), vec!["session:42".to_owned()]);
+}
+
+#[tokio::test]
+async fn test_defined_event_with_owned_payload() {
+    let task = task().into_erased();
+    let next = task.next_emission::<OnBatchFlushed>();
+
+    task.emit_hook_event::<OnBatchFlushed>(&12).await;
+    assert_eq!(next.await, 12);
+}
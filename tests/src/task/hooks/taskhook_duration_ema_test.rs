@@ -0,0 +1,66 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use chronographer::prelude::*;
+use chronographer::errors::EmaAlphaOutOfRange;
+use chronographer::task::hooks::{DurationEmaHook, DURATION_EMA_KEY};
+use chronographer::task::hooks::events::{OnTaskEnd, OnTaskStart};
+use chronographer::task::TaskScheduleImmediate;
+
+fn assert_close(actual: Duration, expected: Duration) {
+    assert!(actual.abs_diff(expected) < Duration::from_millis(5), "expected ~{expected:?}, got {actual:?}");
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_ema_tracks_execution_duration() {
+    let hook = Arc::new(DurationEmaHook::new(0.5).unwrap());
+    let sleep_ms = Arc::new(AtomicU64::new(100));
+
+    let millis = sleep_ms.clone();
+    let task = Task::new(
+        DynamicTaskFrame::new(move |_ctx: &TaskFrameContext, _args: &()| {
+            let millis = millis.load(Ordering::SeqCst);
+            async move {
+                tokio::time::sleep(Duration::from_millis(millis)).await;
+                Ok::<_, String>(())
+            }
+        }),
+        TaskScheduleImmediate,
+    )
+    .into_erased();
+
+    task.attach_hook::<OnTaskStart>(hook.clone()).await;
+    task.attach_hook::<OnTaskEnd>(hook.clone()).await;
+    assert_eq!(hook.ema(&task), None);
+
+    task.run().await.unwrap();
+    assert_close(hook.ema(&task).unwrap(), Duration::from_millis(100));
+
+    sleep_ms.store(300, Ordering::SeqCst);
+    task.run().await.unwrap();
+    assert_close(hook.ema(&task).unwrap(), Duration::from_millis(200));
+}
+
+#[test]
+fn test_record_and_reset() {
+    let hook = DurationEmaHook::new(0.25).unwrap();
+    hook.record(7, Duration::from_secs(4));
+    hook.record(7, Duration::from_secs(8));
+
+    assert_eq!(hook.ema_of(7), Some(Duration::from_secs(5)));
+    assert_eq!(hook.ema_of(8), None);
+
+    hook.reset(7);
+    assert_eq!(hook.ema_of(7), None);
+}
+
+#[test]
+fn test_rejects_invalid_alpha() {
+    for alpha in [0.0, -0.5, 1.5, f64::NAN] {
+        assert_eq!(DurationEmaHook::new(alpha).err(), Some(EmaAlphaOutOfRange));
+    }
+
+    assert_eq!(DurationEmaHook::new(1.0).unwrap().alpha(), 1.0);
+    assert_eq!(DURATION_EMA_KEY, "duration_ema");
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use chronographer::prelude::*;
+use chronographer::task::hooks::{ChannelForwardingHook, ForwardedEvent};
+use chronographer::task::{TaskFrame, TaskScheduleImmediate};
+
+define_hook_event!(OnJobProgress, u32);
+
+fn task() -> Task<impl TaskFrame<Args = (), Error = String>> {
+    Task::new(
+        DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }),
+        TaskScheduleImmediate,
+    )
+}
+
+#[tokio::test]
+async fn test_events_are_forwarded_down_the_channel() {
+    let task = task();
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(8);
+    let hook = Arc::new(ChannelForwardingHook::<OnJobProgress>::new(sender));
+    task.attach_hook::<OnJobProgress>(hook.clone()).await;
+
+    task.emit_hook_event::<OnJobProgress>(&25).await;
+    task.emit_hook_event::<OnJobProgress>(&50).await;
+
+    let first: ForwardedEvent<u32> = receiver.recv().await.unwrap();
+    let second = receiver.recv().await.unwrap();
+
+    assert_eq!(first.payload, 25);
+    assert_eq!(second.payload, 50);
+    assert_eq!(first.task_id, second.task_id);
+    assert!(first.event.ends_with("OnJobProgress"));
+    assert_eq!(hook.dropped(), 0);
+}
+
+#[tokio::test]
+async fn test_full_or_closed_channel_drops_and_counts() {
+    let task = task();
+    let (sender, receiver) = tokio::sync::mpsc::channel(1);
+    let hook = Arc::new(ChannelForwardingHook::<OnJobProgress>::new(sender));
+    task.attach_hook::<OnJobProgress>(hook.clone()).await;
+
+    task.emit_hook_event::<OnJobProgress>(&1).await;
+    task.emit_hook_event::<OnJobProgress>(&2).await;
+    assert_eq!(hook.dropped(), 1, "the second event should not fit the channel");
+
+    drop(receiver);
+    task.emit_hook_event::<OnJobProgress>(&3).await;
+    assert_eq!(hook.dropped(), 2, "a closed channel should drop the event as well");
+}
+
+#[test]
+fn test_forwarded_event_serializes() {
+    let event = ForwardedEvent {
+        task_id: 7,
+        event: "OnJobProgress".into(),
+        payload: 75u32,
+    };
+
+    let encoded = toml::to_string(&event).unwrap();
+    let decoded: ForwardedEvent<u32> = toml::from_str(&encoded).unwrap();
+    assert_eq!(decoded, event);
+}
@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use chronographer::prelude::*;
+use chronographer::task::{ErasedTask, TaskFrameContext, TaskHookContext, TaskScheduleImmediate};
+
+struct CountingHook(Arc<AtomicUsize>);
+
+#[async_trait]
+impl TaskHook<OnTaskStart> for CountingHook {
+    async fn on_event(&self, _ctx: &TaskHookContext, _payload: &<OnTaskStart as TaskHookEvent>::Payload<'_>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[async_trait]
+impl TaskHook<OnTaskEnd> for CountingHook {}
+
+struct OtherHook;
+
+#[async_trait]
+impl TaskHook<OnTaskStart> for OtherHook {}
+
+#[async_trait]
+impl TaskHook<OnTaskEnd> for OtherHook {}
+
+fn noop_task() -> ErasedTask<String> {
+    Task::new(
+        DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }),
+        TaskScheduleImmediate,
+    )
+    .into_erased()
+}
+
+#[tokio::test]
+async fn test_detach_mismatched_hook_type_keeps_attached_hook() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let task = noop_task();
+
+    task.attach_hook::<OnTaskStart>(Arc::new(CountingHook(count.clone()))).await;
+    task.detach_hook::<OnTaskStart, OtherHook>().await;
+    task.run().await.unwrap();
+
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_detach_mismatched_event_keeps_attached_hook() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let task = noop_task();
+
+    task.attach_hook::<OnTaskStart>(Arc::new(CountingHook(count.clone()))).await;
+    task.detach_hook::<OnTaskEnd, CountingHook>().await;
+    task.run().await.unwrap();
+
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_emit_after_partial_detach_does_not_panic() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let task = noop_task();
+
+    task.attach_hook::<OnTaskStart>(Arc::new(OtherHook)).await;
+    task.attach_hook::<OnTaskStart>(Arc::new(CountingHook(count.clone()))).await;
+    task.detach_hook::<OnTaskStart, OtherHook>().await;
+    task.run().await.unwrap();
+
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert!(task.get_hook::<OnTaskStart, OtherHook>().is_none());
+}
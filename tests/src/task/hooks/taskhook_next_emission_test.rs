@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use chronographer::prelude::*;
+use chronographer::task::{FallbackTaskFrame, TaskFrameContext, TaskScheduleImmediate};
+
+#[tokio::test]
+async fn test_next_emission_resolves_on_emit() {
+    let task = Task::new(DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }), TaskScheduleImmediate)
+        .into_erased();
+
+    let next = task.next_emission::<OnTaskStart>();
+    task.run().await.unwrap();
+
+    tokio::time::timeout(Duration::from_secs(1), next)
+        .await
+        .expect("OnTaskStart should have been emitted");
+}
+
+#[tokio::test]
+async fn test_next_emission_with_maps_borrowed_payload() {
+    let frame = FallbackTaskFrame::new(
+        DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async {
+            Err::<(), _>("primary failed".to_owned())
+        }),
+        DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _err: &String| async { Ok::<_, String>(()) }),
+    );
+    let task = Task::new(frame, TaskScheduleImmediate).into_erased();
+
+    let fallback = task.next_emission_with::<OnFallbackEvent, _, _>(|err| err.to_string());
+    task.run().await.unwrap();
+
+    let message = tokio::time::timeout(Duration::from_secs(1), fallback)
+        .await
+        .expect("the fallback should have fired");
+    assert_eq!(message, "primary failed");
+}
+
+#[tokio::test]
+async fn test_next_emission_dropped_before_emit() {
+    let task = Task::new(DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }), TaskScheduleImmediate)
+        .into_erased();
+
+    drop(task.next_emission::<OnTaskStart>());
+    task.run().await.unwrap();
+
+    let next = task.next_emission::<OnTaskStart>();
+    task.run().await.unwrap();
+
+    tokio::time::timeout(Duration::from_secs(1), next)
+        .await
+        .expect("OnTaskStart should have been emitted");
+}
+
+#[tokio::test]
+async fn test_next_emission_detaches_once_resolved() {
+    let task = Task::new(DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }), TaskScheduleImmediate)
+        .into_erased();
+
+    let next = task.next_emission::<OnTaskStart>();
+    assert_eq!(task.hook_count::<OnTaskStart>(), 1);
+
+    task.run().await.unwrap();
+    tokio::time::timeout(Duration::from_secs(1), next)
+        .await
+        .expect("OnTaskStart should have been emitted");
+    assert_eq!(task.hook_count::<OnTaskStart>(), 0);
+
+    let next = task.next_emission::<OnTaskStart>();
+    assert_eq!(task.hook_count::<OnTaskStart>(), 1);
+    task.run().await.unwrap();
+    tokio::time::timeout(Duration::from_secs(1), next)
+        .await
+        .expect("OnTaskStart should have been emitted again");
+    assert_eq!(task.hook_count::<OnTaskStart>(), 0);
+}
+
+#[tokio::test]
+async fn test_next_emission_detaches_once_dropped() {
+    let task = Task::new(DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }), TaskScheduleImmediate)
+        .into_erased();
+
+    let first = task.next_emission::<OnTaskStart>();
+    let second = task.next_emission::<OnTaskStart>();
+    assert_eq!(task.hook_count::<OnTaskStart>(), 1);
+
+    drop(first);
+    assert_eq!(task.hook_count::<OnTaskStart>(), 1);
+
+    drop(second);
+    assert_eq!(task.hook_count::<OnTaskStart>(), 0);
+}
@@ -0,0 +1,80 @@
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::time::{Duration, UNIX_EPOCH};
+
+use chronographer::prelude::*;
+use chronographer::task::hooks::RollingOutcomeHook;
+use chronographer::task::hooks::events::OnTaskEnd;
+use chronographer::task::TaskScheduleImmediate;
+
+#[tokio::test]
+async fn test_outcomes_are_keyed_by_description() {
+    let hook = Arc::new(RollingOutcomeHook::default());
+
+    let healthy = Task::new(
+        DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }),
+        TaskScheduleImmediate,
+    )
+    .with_description("healthy")
+    .into_erased();
+
+    let failing = Task::new(
+        DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Err::<(), _>("boom".to_string()) }),
+        TaskScheduleImmediate,
+    )
+    .with_description("failing")
+    .into_erased();
+
+    healthy.attach_hook::<OnTaskEnd>(hook.clone()).await;
+    failing.attach_hook::<OnTaskEnd>(hook.clone()).await;
+
+    for _ in 0..3 {
+        let _ = healthy.run().await;
+        let _ = failing.run().await;
+    }
+
+    let snapshot = hook.snapshot("healthy");
+    assert_eq!(snapshot.successes(), 3);
+    assert_eq!(snapshot.failures(), 0);
+    assert_eq!(snapshot.success_rate(), Some(1.0));
+
+    let snapshot = hook.snapshot("failing");
+    assert_eq!(snapshot.successes(), 0);
+    assert_eq!(snapshot.failures(), 3);
+
+    assert_eq!(hook.snapshot("unknown").success_rate(), None);
+}
+
+#[test]
+fn test_outcomes_are_bucketed_into_windows() {
+    let hook = RollingOutcomeHook::new(Duration::from_secs(60), NonZeroUsize::new(3).unwrap());
+    let base = UNIX_EPOCH + Duration::from_secs(600);
+
+    hook.record("job", base, true);
+    hook.record("job", base + Duration::from_secs(30), false);
+    hook.record("job", base + Duration::from_secs(60), true);
+
+    let snapshot = hook.snapshot_at("job", base + Duration::from_secs(60));
+    assert_eq!(snapshot.buckets.len(), 2);
+    assert_eq!((snapshot.buckets[0].successes, snapshot.buckets[0].failures), (1, 1));
+    assert_eq!((snapshot.buckets[1].successes, snapshot.buckets[1].failures), (1, 0));
+    assert_eq!(snapshot.buckets[1].start, base + Duration::from_secs(60));
+}
+
+#[test]
+fn test_old_buckets_are_evicted() {
+    let hook = RollingOutcomeHook::new(Duration::from_secs(60), NonZeroUsize::new(3).unwrap());
+    let base = UNIX_EPOCH + Duration::from_secs(600);
+
+    for minute in 0..5 {
+        hook.record("job", base + Duration::from_secs(60 * minute), true);
+    }
+
+    let newest = base + Duration::from_secs(60 * 4);
+    let snapshot = hook.snapshot_at("job", newest);
+    assert_eq!(snapshot.buckets.len(), 3, "only the latest 3 windows should be kept");
+    assert_eq!(snapshot.successes(), 3);
+
+    let later = hook.snapshot_at("job", newest + Duration::from_secs(120));
+    assert_eq!(later.buckets.len(), 1, "windows which fell out of range are excluded");
+}
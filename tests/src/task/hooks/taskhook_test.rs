@@ -157,3 +157,63 @@ async fn test_get_hook() {
         "Retrieved hook should work"
     );
 }
+
+#[tokio::test]
+async fn test_hook_count() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let frame = SimpleTaskFrame {
+        should_succeed: Arc::new(AtomicBool::new(true)),
+    };
+
+    let task = Task::new(frame, TaskScheduleImmediate);
+    assert_eq!(task.hook_count::<OnTaskStart>(), 0);
+
+    task.attach_hook::<OnTaskStart>(Arc::new(OnStartCountingHook { count: count.clone() })).await;
+    task.attach_hook::<OnTaskStart>(Arc::new(OnStartCountingHook { count: count.clone() })).await;
+    task.attach_hook::<OnTaskEnd>(Arc::new(OnEndCountingHook { count: count.clone() })).await;
+
+    assert_eq!(task.hook_count::<OnTaskStart>(), 2);
+    assert_eq!(task.hook_count::<OnTaskEnd>(), 1);
+
+    task.detach_hook::<OnTaskStart, OnStartCountingHook>().await;
+    assert_eq!(task.hook_count::<OnTaskStart>(), 1);
+
+    task.detach_hook::<OnTaskStart, OnStartCountingHook>().await;
+    task.detach_hook::<OnTaskStart, OnStartCountingHook>().await;
+    assert_eq!(task.hook_count::<OnTaskStart>(), 0, "Detaching nothing shouldn't underflow");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_hook_count_concurrent_attach_detach() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let frame = SimpleTaskFrame {
+        should_succeed: Arc::new(AtomicBool::new(true)),
+    };
+
+    let task = Arc::new(Task::new(frame, TaskScheduleImmediate));
+    let mut handles = Vec::new();
+    for _ in 0..32 {
+        let task = task.clone();
+        let count = count.clone();
+        handles.push(tokio::spawn(async move {
+            task.attach_hook::<OnTaskStart>(Arc::new(OnStartCountingHook { count })).await;
+        }));
+    }
+
+    for handle in handles.drain(..) {
+        handle.await.unwrap();
+    }
+    assert_eq!(task.hook_count::<OnTaskStart>(), 32);
+
+    for _ in 0..20 {
+        let task = task.clone();
+        handles.push(tokio::spawn(async move {
+            task.detach_hook::<OnTaskStart, OnStartCountingHook>().await;
+        }));
+    }
+
+    for handle in handles {
+        handle.await.unwrap();
+    }
+    assert_eq!(task.hook_count::<OnTaskStart>(), 12);
+}
@@ -0,0 +1,82 @@
+use std::sync::{Arc, Mutex};
+
+use chronographer::prelude::*;
+use chronographer::task::{TaskFrame, TaskFrameContext, TaskScheduleImmediate};
+
+#[derive(Debug, PartialEq)]
+struct JobPayload {
+    user: String,
+    retries: u32,
+}
+
+struct PayloadReader(Arc<Mutex<Option<(String, u32)>>>);
+
+impl TaskFrame for PayloadReader {
+    type Error = String;
+    type Args = ();
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, _args: &()) -> Result<(), String> {
+        let payload = ctx.input::<JobPayload>().ok_or("missing input")?;
+        *self.0.lock().unwrap() = Some((payload.user.clone(), payload.retries));
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_frame_reads_typed_input() {
+    let seen = Arc::new(Mutex::new(None));
+    let task = Task::new(PayloadReader(seen.clone()), TaskScheduleImmediate)
+        .with_input(JobPayload { user: "alice".to_owned(), retries: 3 })
+        .into_erased();
+
+    task.run().await.unwrap();
+    assert_eq!(*seen.lock().unwrap(), Some(("alice".to_owned(), 3)));
+}
+
+#[tokio::test]
+async fn test_missing_input_is_none() {
+    let seen = Arc::new(Mutex::new(None));
+    let task = Task::new(PayloadReader(seen.clone()), TaskScheduleImmediate).into_erased();
+
+    assert_eq!(task.run().await, Err("missing input".to_owned()));
+    assert!(task.input::<JobPayload>().is_none());
+}
+
+#[test]
+fn test_input_requires_exact_type() {
+    let task = Task::new(
+        DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }),
+        TaskScheduleImmediate,
+    )
+    .with_input("payload");
+
+    assert!(task.input::<String>().is_none());
+    assert_eq!(task.input::<&str>().as_deref(), Some(&"payload"));
+}
+
+#[test]
+fn test_input_released_with_task() {
+    let probe = Arc::new(());
+    let task = Task::new(
+        DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }),
+        TaskScheduleImmediate,
+    )
+    .with_input(probe.clone());
+
+    assert_eq!(Arc::strong_count(&probe), 2);
+    drop(task);
+    assert_eq!(Arc::strong_count(&probe), 1);
+}
+
+#[test]
+fn test_replacing_input_keeps_latest() {
+    let task = Task::new(
+        DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }),
+        TaskScheduleImmediate,
+    )
+    .with_input(1u32)
+    .with_input(2u32);
+
+    assert_eq!(task.input::<u32>().as_deref(), Some(&2));
+}
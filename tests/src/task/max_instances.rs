@@ -0,0 +1,77 @@
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chronographer::prelude::*;
+use chronographer::task::{ErasedTask, TaskFrameContext, TaskRunStatus, TaskScheduleImmediate};
+
+fn slow_task(max: usize) -> Arc<ErasedTask<String>> {
+    let task = Task::new(
+        DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Ok::<_, String>(())
+        }),
+        TaskScheduleImmediate,
+    )
+    .with_max_concurrent_instances(NonZeroUsize::new(max).unwrap());
+
+    Arc::new(task.into_erased())
+}
+
+#[tokio::test]
+async fn test_fire_skipped_when_cap_reached() {
+    let task = slow_task(1);
+    let running = tokio::spawn({
+        let task = task.clone();
+        async move { task.run().await }
+    });
+
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert_eq!(task.running_instances(), 1);
+
+    let skipped = task.next_emission::<OnTaskSkipped>();
+    assert_eq!(task.run().await, Ok(TaskRunStatus::Skipped { running: 1 }));
+    let in_flight = tokio::time::timeout(Duration::from_secs(1), skipped)
+        .await
+        .expect("OnTaskSkipped should have been emitted");
+
+    assert_eq!(in_flight, 1);
+    assert_eq!(running.await.unwrap(), Ok(TaskRunStatus::Completed));
+    assert_eq!(task.running_instances(), 0);
+}
+
+#[tokio::test]
+async fn test_fires_below_cap_run_concurrently() {
+    let task = slow_task(2);
+    let first = tokio::spawn({
+        let task = task.clone();
+        async move { task.run().await }
+    });
+
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    let started = task.next_emission::<OnTaskStart>();
+    let second = tokio::spawn({
+        let task = task.clone();
+        async move { task.run().await }
+    });
+
+    tokio::time::timeout(Duration::from_secs(1), started)
+        .await
+        .expect("the second instance should have started");
+    assert_eq!(task.running_instances(), 2);
+
+    first.await.unwrap().unwrap();
+    second.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn test_slot_released_after_run() {
+    let task = slow_task(1);
+    task.run().await.unwrap();
+
+    let started = task.next_emission::<OnTaskStart>();
+    task.run().await.unwrap();
+    tokio::time::timeout(Duration::from_secs(1), started)
+        .await
+        .expect("the task should run again once the previous instance finished");
+}
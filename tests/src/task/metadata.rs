@@ -0,0 +1,92 @@
+use chronographer::prelude::*;
+use chronographer::scheduler::{DefaultLiveScheduler, Scheduler};
+use chronographer::task::{TaskFrameContext, TaskMetadata, TaskScheduleImmediate, TaskScheduleInterval};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_metadata_defaults_to_empty() {
+    let seen = Arc::new(Mutex::new(None));
+    let recorded = seen.clone();
+
+    let task = Task::new(
+        DynamicTaskFrame::new(move |ctx: &TaskFrameContext, _args: &()| {
+            *recorded.lock().unwrap() = Some(ctx.metadata());
+            async { Ok::<_, String>(()) }
+        }),
+        TaskScheduleImmediate,
+    );
+
+    assert_eq!(task.description(), "");
+    assert_eq!(task.owner(), "");
+
+    task.into_erased().run().await.unwrap();
+    assert_eq!(*seen.lock().unwrap().take().unwrap(), TaskMetadata::default());
+}
+
+#[tokio::test]
+async fn test_metadata_is_readable_from_context() {
+    let seen = Arc::new(Mutex::new(None));
+    let recorded = seen.clone();
+
+    let task = Task::new(
+        DynamicTaskFrame::new(move |ctx: &TaskFrameContext, _args: &()| {
+            *recorded.lock().unwrap() = Some(ctx.metadata());
+            async { Ok::<_, String>(()) }
+        }),
+        TaskScheduleImmediate,
+    )
+    .with_description("Syncs the billing ledger")
+    .with_owner("payments-team");
+
+    task.into_erased().run().await.unwrap();
+
+    let metadata = seen.lock().unwrap().take().unwrap();
+    assert_eq!(metadata.description, "Syncs the billing ledger");
+    assert_eq!(metadata.owner, "payments-team");
+}
+
+#[tokio::test]
+async fn test_scheduler_list_surfaces_metadata() {
+    let scheduler = DefaultLiveScheduler::<String>::default();
+    let task = Task::new(
+        DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }),
+        TaskScheduleInterval::duration(Duration::from_secs(60)),
+    )
+    .with_owner("infra");
+
+    let key = scheduler.schedule(task).await.unwrap();
+    let listed = scheduler.list().await;
+
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].0, key);
+    assert_eq!(listed[0].1.owner(), "infra");
+    assert_eq!(listed[0].1.description(), "");
+}
+
+#[tokio::test]
+async fn test_metadata_persists_and_restores() {
+    let task = Task::new(
+        DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }),
+        TaskScheduleImmediate,
+    )
+    .with_description("Syncs the billing ledger")
+    .with_owner("payments-team");
+
+    let persisted = toml::to_string(task.metadata().as_ref()).unwrap();
+    let restored: TaskMetadata = toml::from_str(&persisted).unwrap();
+    assert_eq!(&restored, task.metadata().as_ref());
+
+    let restarted = Task::new(
+        DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) }),
+        TaskScheduleImmediate,
+    )
+    .with_metadata(restored);
+
+    assert_eq!(restarted.description(), "Syncs the billing ledger");
+    assert_eq!(restarted.owner(), "payments-team");
+
+    let partial: TaskMetadata = toml::from_str(r#"owner = "ops""#).unwrap();
+    assert_eq!(partial.description, "");
+    assert_eq!(partial.owner, "ops");
+}
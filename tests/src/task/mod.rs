@@ -2,3 +2,12 @@ mod dependency;
 mod frames;
 mod hooks;
 mod utils;
+mod spec;
+mod max_instances;
+mod input;
+mod history;
+mod metadata;
+mod errors;
+mod validation;
+mod barrier;
+mod sandbox;
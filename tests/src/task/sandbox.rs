@@ -0,0 +1,68 @@
+use chronographer::prelude::*;
+use chronographer::task::{NonObserverTaskHook, TaskFrame, TaskFrameContext, TaskMetadata, TaskScheduleImmediate};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+struct SharedCounter(AtomicUsize);
+
+impl NonObserverTaskHook for SharedCounter {}
+
+#[derive(Default)]
+struct Observed {
+    metadata: Option<Arc<TaskMetadata>>,
+    saw_parent_counter: bool,
+}
+
+// Reads the state visible to it, then attaches state of its own
+struct Plugin(Arc<Mutex<Observed>>);
+
+impl TaskFrame for Plugin {
+    type Error = String;
+    type Args = ();
+    type Workflow = Self;
+
+    async fn execute(&self, ctx: &TaskFrameContext, _args: &()) -> Result<(), String> {
+        {
+            let mut observed = self.0.lock().unwrap();
+            observed.metadata = Some(ctx.metadata());
+            observed.saw_parent_counter = ctx.get_shared::<SharedCounter>().is_some();
+        }
+
+        ctx.shared(|| SharedCounter(AtomicUsize::new(100))).await;
+        Err("plugin failed".to_owned())
+    }
+}
+
+#[tokio::test]
+async fn test_sandbox_isolates_child_from_parent_state() {
+    let observed = Arc::new(Mutex::new(Observed::default()));
+    let plugin = Arc::new(Plugin(observed.clone()));
+    let after_sandbox = Arc::new(AtomicUsize::new(0));
+
+    let (plugin_clone, after_clone) = (plugin.clone(), after_sandbox.clone());
+    let task = Task::new(
+        DynamicTaskFrame::new(move |ctx: &TaskFrameContext, _args: &()| {
+            let (plugin, after) = (plugin_clone.clone(), after_clone.clone());
+            let ctx = *ctx;
+            async move {
+                ctx.shared(|| SharedCounter(AtomicUsize::new(7))).await;
+
+                let result = ctx.sandbox(plugin.as_ref(), &()).await;
+                assert_eq!(result, Err("plugin failed".to_owned()));
+
+                let counter = ctx.get_shared::<SharedCounter>().unwrap();
+                after.store(counter.0.load(Ordering::SeqCst), Ordering::SeqCst);
+                Ok::<_, String>(())
+            }
+        }),
+        TaskScheduleImmediate,
+    )
+    .with_owner("payments-team");
+
+    task.into_erased().run().await.unwrap();
+
+    let observed = observed.lock().unwrap();
+    assert_eq!(*observed.metadata.as_ref().unwrap().as_ref(), TaskMetadata::default());
+    assert!(!observed.saw_parent_counter, "the sandbox shouldn't see the hooks of the parent");
+    assert_eq!(after_sandbox.load(Ordering::SeqCst), 7, "the sandbox shouldn't replace the state of the parent");
+}
@@ -0,0 +1,141 @@
+use async_trait::async_trait;
+use std::error::Error;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chronographer::errors::TaskSpecError;
+use chronographer::task::spec::{SpecParams, TaskFrameFactoryRegistry, TaskScheduleFactoryRegistry, TaskSpec};
+use chronographer::task::{ErasedTaskFrame, TaskFrame, TaskFrameContext, TaskPriority, TaskSchedule};
+
+const SPEC: &str = r#"
+name = "cleanup"
+priority = 5
+max_runs = 2
+tags = ["maintenance"]
+description = "Purges stale cache entries"
+owner = "platform"
+
+[schedule]
+type = "interval"
+seconds = 30
+
+[[frames]]
+factory = "count"
+params = { step = 2 }
+
+[[frames]]
+factory = "count"
+"#;
+
+struct StepFrame(Arc<AtomicUsize>, usize);
+
+impl TaskFrame for StepFrame {
+    type Error = String;
+    type Args = ();
+    type Workflow = Self;
+
+    async fn execute(&self, _ctx: &TaskFrameContext, _args: &Self::Args) -> Result<(), Self::Error> {
+        self.0.fetch_add(self.1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+fn registry(counter: &Arc<AtomicUsize>) -> TaskFrameFactoryRegistry {
+    let counter = counter.clone();
+    let mut registry = TaskFrameFactoryRegistry::new();
+    registry.register("count", move |params: &SpecParams| {
+        let step = params.get("step").and_then(|x| x.as_integer()).unwrap_or(1);
+        Ok(Arc::new(StepFrame(counter.clone(), step as usize)) as Arc<dyn ErasedTaskFrame<()>>)
+    });
+
+    registry
+}
+
+#[tokio::test]
+async fn test_spec_loads_and_runs_chain() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let spec: TaskSpec = toml::from_str(SPEC).unwrap();
+    let loaded = registry(&counter).load(&spec).unwrap();
+
+    assert_eq!(loaded.name, "cleanup");
+    assert_eq!(loaded.task.priority(), TaskPriority(5));
+    assert_eq!(loaded.tags, vec!["maintenance".to_owned()]);
+    assert_eq!(loaded.task.description(), "Purges stale cache entries");
+    assert_eq!(loaded.task.owner(), "platform");
+
+    for _ in 0..3 {
+        loaded.task.run().await.unwrap();
+    }
+
+    assert_eq!(counter.load(Ordering::SeqCst), 6, "max_runs should cap the chain at 2 runs");
+}
+
+#[tokio::test]
+async fn test_spec_unknown_factory() {
+    let spec: TaskSpec = toml::from_str(&SPEC.replace("\"count\"", "\"missing\"")).unwrap();
+    let err = TaskFrameFactoryRegistry::new().load(&spec).err().unwrap();
+
+    assert!(matches!(err, TaskSpecError::UnknownFactory(name) if name == "missing"));
+}
+
+#[tokio::test]
+async fn test_spec_invalid_cron_schedule() {
+    let spec: TaskSpec = toml::from_str(
+        r#"
+        name = "broken"
+        schedule = { type = "cron", expression = "not a cron" }
+        frames = [{ factory = "count" }]
+        "#,
+    )
+    .unwrap();
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let err = registry(&counter).load(&spec).err().unwrap();
+    assert!(matches!(err, TaskSpecError::InvalidSchedule(_)));
+}
+
+struct EverySecs(u64);
+
+#[async_trait]
+impl TaskSchedule for EverySecs {
+    async fn schedule(&self, now: SystemTime) -> Result<SystemTime, Box<dyn Error + Send + Sync>> {
+        Ok(now + Duration::from_secs(self.0))
+    }
+}
+
+const CUSTOM_SPEC: &str = r#"
+name = "custom"
+schedule = { type = "custom", factory = "every", params = { secs = 7 } }
+frames = [{ factory = "count" }]
+"#;
+
+#[tokio::test]
+async fn test_spec_restores_custom_schedule() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let mut registry = registry(&counter);
+    registry.register_schedule("every", |params: &SpecParams| {
+        let secs = params.get("secs").and_then(|x| x.as_integer()).ok_or("missing secs")?;
+        Ok(Box::new(EverySecs(secs as u64)) as Box<dyn TaskSchedule>)
+    });
+
+    let spec: TaskSpec = toml::from_str(CUSTOM_SPEC).unwrap();
+    let loaded = registry.load(&spec).unwrap();
+
+    let next = loaded.task.schedule().schedule(UNIX_EPOCH).await.unwrap();
+    assert_eq!(next, UNIX_EPOCH + Duration::from_secs(7));
+}
+
+#[tokio::test]
+async fn test_spec_custom_schedule_errors() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let spec: TaskSpec = toml::from_str(CUSTOM_SPEC).unwrap();
+
+    let err = registry(&counter).load(&spec).err().unwrap();
+    assert!(matches!(err, TaskSpecError::UnknownScheduleFactory(name) if name == "every"));
+
+    let mut schedules = TaskScheduleFactoryRegistry::new();
+    schedules.register("every", |_: &SpecParams| Err("invalid params".into()));
+    let err = registry(&counter).with_schedules(schedules).load(&spec).err().unwrap();
+    assert!(matches!(err, TaskSpecError::InvalidSchedule(_)));
+}
@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use chronographer::prelude::*;
+use chronographer::task::{TaskFrame, TaskScheduleImmediate};
+use std::error::Error;
+use std::time::{Duration, SystemTime};
+
+struct NeverMatchingSchedule;
+
+#[async_trait]
+impl TaskSchedule for NeverMatchingSchedule {
+    async fn schedule(&self, _time: SystemTime) -> Result<SystemTime, Box<dyn Error + Send + Sync>> {
+        Err("schedule never matches".into())
+    }
+}
+
+fn frame() -> impl TaskFrame<Args = (), Error = String> {
+    DynamicTaskFrame::new(|_ctx: &TaskFrameContext, _args: &()| async { Ok::<_, String>(()) })
+}
+
+#[tokio::test]
+async fn test_valid_schedule_passes_validation() {
+    let task = Task::new(frame(), TaskScheduleImmediate).validated().await;
+    assert!(task.is_ok());
+
+    let task = Task::new(frame(), TaskScheduleInterval::duration(Duration::from_secs(5)))
+        .validated()
+        .await;
+    assert!(task.is_ok());
+}
+
+#[tokio::test]
+async fn test_invalid_schedule_fails_at_build_time() {
+    let err = Task::new(frame(), NeverMatchingSchedule)
+        .validated()
+        .await
+        .err()
+        .expect("validation should surface the schedule error");
+
+    assert_eq!(err.to_string(), "schedule never matches");
+}